@@ -1,15 +1,19 @@
-use std::{io::Error, path::PathBuf};
+use std::{
+    fs::File,
+    io::{Error, Write},
+    path::PathBuf,
+};
 
 use auto_palette::{color::Color, Algorithm, FloatNumber, Swatch, Theme};
 use clap::{crate_authors, crate_description, crate_version, Parser, ValueEnum, ValueHint};
 
 use crate::{
     context::Context,
-    output::{JsonPrinter, Printer, TablePrinter, TextPrinter},
+    output::{JsonPrinter, PngPrinter, Printer, TablePrinter, TailwindPrinter, TextPrinter},
 };
 
 /// The command line options for the `auto-palette` command.
-#[derive(Debug, PartialEq, Eq, Parser)]
+#[derive(Debug, PartialEq, Parser)]
 #[command(
     name = "auto-palette",
     bin_name = "auto-palette",
@@ -23,10 +27,11 @@ pub struct Options {
         value_name = "PATH",
         help = "Path to the image file.",
         long_help = "Path to the image file. Supported formats include PNG, JPEG, GIF, BMP, ICO, and TIFF.",
-        required = true,
         value_hint = ValueHint::FilePath,
     )]
-    pub path: PathBuf,
+    #[cfg_attr(not(feature = "watch"), arg(required = true))]
+    #[cfg_attr(feature = "watch", arg(required_unless_present = "watch"))]
+    pub path: Option<PathBuf>,
 
     #[arg(
         long,
@@ -69,6 +74,16 @@ pub struct Options {
     )]
     pub color: ColorFormat,
 
+    #[arg(
+        long,
+        short = 'p',
+        value_name = "digits",
+        help = "Number of decimal places for float components.",
+        long_help = "Number of decimal places for float components of the output color. This applies to color formats with float components such as Lab, HSL, and Oklch.",
+        default_value = "2"
+    )]
+    pub precision: usize,
+
     #[arg(
         long,
         short = 'o',
@@ -80,12 +95,61 @@ pub struct Options {
     )]
     pub output: OutputFormat,
 
+    #[arg(
+        long,
+        short = 'f',
+        value_name = "PATH",
+        help = "Path to the output file.",
+        long_help = "Path to the output file. The output is written to the standard output if not specified. This is required for the png output format.",
+        value_hint = ValueHint::FilePath,
+        required_if_eq("output", "png")
+    )]
+    pub file: Option<PathBuf>,
+
     #[arg(
         long,
         help = "Disable image resizing before extracting the color palette.",
         long_help = "Disable image resizing before extracting the color palette. This potentially improve the accuracy of the results by preserving the original image resolution."
     )]
     pub no_resize: bool,
+
+    #[arg(
+        long,
+        short = 's',
+        value_name = "factor",
+        help = "Scale factor for resizing the image before extracting the color palette.",
+        long_help = "Scale factor for resizing the image before extracting the color palette. The image is resized to the given fraction of the original size instead of fitting within 360x360 pixels. Smaller factors are faster but potentially less accurate.",
+        conflicts_with = "no_resize"
+    )]
+    pub scale: Option<f64>,
+
+    #[arg(
+        long,
+        value_name = "number",
+        help = "Seed for the random number generator of the algorithm.",
+        long_help = "Seed for the random number generator of the algorithm. The same image yields the same color palette on every run with the same seed. This only affects the kmeans algorithm, as the other algorithms are deterministic."
+    )]
+    pub seed: Option<u64>,
+
+    #[arg(
+        long,
+        help = "Print only the dominant color.",
+        long_help = "Print only the color of the swatch with the highest population in the output color format, followed by a newline. This is useful for shell scripts such as `BG=$(auto-palette image.png --dominant)`.",
+        conflicts_with_all = ["theme", "output", "file"]
+    )]
+    pub dominant: bool,
+
+    #[cfg(feature = "watch")]
+    #[arg(
+        long,
+        short = 'w',
+        value_name = "DIR",
+        help = "Directory to watch for new image files.",
+        long_help = "Directory to watch for new image files. The color palette is extracted from each image file added to the directory until the process is terminated. The output is written to the standard output, so this cannot be used with --file or the png output format.",
+        value_hint = ValueHint::DirPath,
+        conflicts_with_all = ["path", "file"]
+    )]
+    pub watch: Option<PathBuf>,
 }
 
 /// The algorithm options for extracting the color palette from the image.
@@ -107,6 +171,21 @@ pub enum AlgorithmOption {
         help = "Fast speed but potentially less accurate. Ideal for performance over precision."
     )]
     KMeans,
+    #[clap(
+        name = "mediancut",
+        help = "Fast and deterministic but less accurate. Ideal for lightweight quantization."
+    )]
+    MedianCut,
+    #[clap(
+        name = "octree",
+        help = "Fast and deterministic with good quality. Ideal for large numbers of colors."
+    )]
+    Octree,
+    #[clap(
+        name = "uniformgrid",
+        help = "Fastest and deterministic but coarse. Ideal as a baseline or a fallback."
+    )]
+    UniformGrid,
 }
 
 impl From<AlgorithmOption> for Algorithm {
@@ -115,6 +194,11 @@ impl From<AlgorithmOption> for Algorithm {
             AlgorithmOption::Dbscan => Algorithm::DBSCAN,
             AlgorithmOption::DbscanPlusPlus => Algorithm::DBSCANpp,
             AlgorithmOption::KMeans => Algorithm::KMeans,
+            AlgorithmOption::MedianCut => Algorithm::MedianCut { max_colors: 32 },
+            AlgorithmOption::Octree => Algorithm::Octree { max_colors: 256 },
+            AlgorithmOption::UniformGrid => Algorithm::UniformGrid {
+                bits_per_channel: 4,
+            },
         }
     }
 }
@@ -187,27 +271,28 @@ impl ColorFormat {
     ///
     /// # Arguments
     /// * `color` - The color to convert.
+    /// * `precision` - The number of decimal places for float components.
     ///
     /// # Returns
     /// The string representation of the color space.
     #[must_use]
-    pub fn fmt<T>(&self, color: &Color<T>) -> String
+    pub fn fmt<T>(&self, color: &Color<T>, precision: usize) -> String
     where
         T: FloatNumber,
     {
         match *self {
             Self::Hex => color.to_hex_string(),
             Self::Rgb => color.to_rgb().to_string(),
-            Self::Cmyk => color.to_cmyk().to_string(),
-            Self::Hsl => color.to_hsl().to_string(),
-            Self::Hsv => color.to_hsv().to_string(),
-            Self::Lab => color.to_lab().to_string(),
-            Self::Luv => color.to_luv().to_string(),
-            Self::LCHab => color.to_lchab().to_string(),
-            Self::LCHuv => color.to_lchuv().to_string(),
-            Self::Oklab => color.to_oklab().to_string(),
-            Self::Oklch => color.to_oklch().to_string(),
-            Self::Xyz => color.to_xyz().to_string(),
+            Self::Cmyk => format!("{:.*}", precision, color.to_cmyk()),
+            Self::Hsl => format!("{:.*}", precision, color.to_hsl()),
+            Self::Hsv => format!("{:.*}", precision, color.to_hsv()),
+            Self::Lab => format!("{:.*}", precision, color.to_lab()),
+            Self::Luv => format!("{:.*}", precision, color.to_luv()),
+            Self::LCHab => format!("{:.*}", precision, color.to_lchab()),
+            Self::LCHuv => format!("{:.*}", precision, color.to_lchuv()),
+            Self::Oklab => format!("{:.*}", precision, color.to_oklab()),
+            Self::Oklch => format!("{:.*}", precision, color.to_oklch()),
+            Self::Xyz => format!("{:.*}", precision, color.to_xyz()),
         }
     }
 }
@@ -221,11 +306,17 @@ pub enum OutputFormat {
     Text,
     #[clap(name = "table", help = "Table output format")]
     Table,
+    #[clap(name = "tailwind", help = "Tailwind CSS config output format")]
+    Tailwind,
+    #[clap(name = "png", help = "PNG swatch sheet output format")]
+    Png,
 }
 
 impl OutputFormat {
     /// Prints the swatches in the given output format.
     ///
+    /// The swatches are written to the output file if specified, otherwise to the standard output.
+    ///
     /// # Arguments
     /// * `context` - The context for the command line application.
     /// * `swatches` - The swatches to print.
@@ -236,12 +327,16 @@ impl OutputFormat {
     where
         T: FloatNumber,
     {
+        let mut output: Box<dyn Write> = match &context.args().file {
+            Some(path) => Box::new(File::create(path)?),
+            None => Box::new(std::io::stdout()),
+        };
         match *self {
-            OutputFormat::Json => JsonPrinter::new(context).print(swatches, &mut std::io::stdout()),
-            OutputFormat::Text => TextPrinter::new(context).print(swatches, &mut std::io::stdout()),
-            OutputFormat::Table => {
-                TablePrinter::new(context).print(swatches, &mut std::io::stdout())
-            }
+            OutputFormat::Json => JsonPrinter::new(context).print(swatches, &mut output),
+            OutputFormat::Text => TextPrinter::new(context).print(swatches, &mut output),
+            OutputFormat::Table => TablePrinter::new(context).print(swatches, &mut output),
+            OutputFormat::Tailwind => TailwindPrinter::new().print(swatches, &mut output),
+            OutputFormat::Png => PngPrinter::new().print(swatches, &mut output),
         }
     }
 }
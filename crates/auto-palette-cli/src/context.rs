@@ -1,7 +1,7 @@
 use crate::{args::Options, env::Env};
 
 /// The context for the command line application.
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, PartialEq)]
 pub struct Context {
     args: Options,
     env: Env,
@@ -1,10 +1,14 @@
-use std::{process, time::Instant};
+use std::{path::Path, process, time::Instant};
 
-use auto_palette::{Algorithm, ImageData, Palette, Theme};
+use auto_palette::{Algorithm, ImageData, PaletteBuilder, Theme};
 use clap::Parser;
-use image::{self, imageops::FilterType};
+use image::{self, imageops::FilterType, DynamicImage};
 
-use crate::{args::Options, context::Context, env::Env};
+use crate::{
+    args::{Options, OutputFormat},
+    context::Context,
+    env::Env,
+};
 
 mod args;
 mod color;
@@ -12,6 +16,8 @@ mod context;
 mod env;
 mod output;
 mod style;
+#[cfg(feature = "watch")]
+mod watch;
 
 const MAX_IMAGE_WIDTH: f64 = 360.0;
 const MAX_IMAGE_HEIGHT: f64 = 360.0;
@@ -19,46 +25,113 @@ const MAX_IMAGE_HEIGHT: f64 = 360.0;
 // The entry point of the CLI application.
 fn main() {
     let context = Context::new(Options::parse(), Env::init());
-    let Ok(image) = image::open(&context.args().path) else {
-        eprintln!("Failed to open the image file {:?}", context.args().path);
+
+    let scale = context.args().scale;
+    if scale.is_some_and(|scale| !(scale > 0.0 && scale <= 1.0)) {
+        eprintln!(
+            "error: invalid value '{}' for '--scale <factor>': must be in the range (0, 1]",
+            scale.unwrap_or_default()
+        );
+        process::exit(1);
+    }
+
+    let count = context.args().count;
+    if count < 1 {
+        eprintln!(
+            "error: invalid value '{}' for '--count <count>': must be a positive integer",
+            count
+        );
+        process::exit(1);
+    }
+
+    #[cfg(feature = "watch")]
+    if let Some(directory) = &context.args().watch {
+        if let Err(cause) = watch::watch(&context, directory) {
+            eprintln!("Failed to watch the directory {:?}: {}", directory, cause);
+            process::exit(1);
+        }
+        return;
+    }
+
+    let Some(path) = &context.args().path else {
         process::exit(1);
     };
+    if let Err(message) = extract(&context, path) {
+        eprintln!("{}", message);
+        process::exit(1);
+    }
+}
 
-    let image_width = image.width() as f64;
-    let image_height = image.height() as f64;
-    let scale = f64::min(
-        MAX_IMAGE_WIDTH / image_width,
-        MAX_IMAGE_HEIGHT / image_height,
-    );
+/// Extracts the color palette from the image file and prints the swatches.
+///
+/// # Arguments
+/// * `context` - The context for the command line application.
+/// * `path` - The path to the image file.
+///
+/// # Returns
+/// The result of the operation.
+///
+/// # Errors
+/// Returns an error message if the image file cannot be opened or the color palette cannot be extracted.
+fn extract(context: &Context, path: &Path) -> Result<(), String> {
+    let Ok(image) = image::open(path) else {
+        return Err(format!("Failed to open the image file {:?}", path));
+    };
+    extract_image(context, path, image)
+}
 
+/// Extracts the color palette from the decoded image and prints the swatches.
+///
+/// # Arguments
+/// * `context` - The context for the command line application.
+/// * `path` - The path to the image file, used in the error messages.
+/// * `image` - The decoded image.
+///
+/// # Returns
+/// The result of the operation.
+///
+/// # Errors
+/// Returns an error message if the color palette cannot be extracted.
+fn extract_image(context: &Context, path: &Path, image: DynamicImage) -> Result<(), String> {
     let resized = if context.args().no_resize {
         image
     } else {
-        image.resize_exact(
-            (image_width * scale) as u32,
-            (image_height * scale) as u32,
-            FilterType::Lanczos3,
-        )
+        let (width, height) =
+            resize_dimensions(image.width(), image.height(), context.args().scale);
+        image.resize_exact(width, height, FilterType::Lanczos3)
     };
 
     let Ok(image_data) = ImageData::try_from(&resized) else {
-        process::exit(1);
+        return Err(format!("Unsupported image file {:?}", path));
     };
 
     let instant = Instant::now();
     let algorithm = Algorithm::from(context.args().algorithm);
-    let Ok(palette) = Palette::<f32>::extract_with_algorithm(&image_data, algorithm) else {
-        process::exit(1);
+    let mut builder = PaletteBuilder::<f32>::new().algorithm(algorithm);
+    if let Some(seed) = context.args().seed {
+        builder = builder.seed(seed);
+    }
+    let Ok(palette) = builder.build(&image_data) else {
+        return Err(format!(
+            "Failed to extract the color palette from {:?}",
+            path
+        ));
     };
 
-    let count = context.args().count;
-    if count < 1 {
-        eprintln!(
-            "error: invalid value '{}' for '--count <count>': must be a positive integer",
-            count
+    if context.args().dominant {
+        let Some(swatch) = palette.dominant_color() else {
+            return Err(format!("No dominant color found in {:?}", path));
+        };
+        println!(
+            "{}",
+            context
+                .args()
+                .color
+                .fmt(swatch.color(), context.args().precision)
         );
-        process::exit(1);
+        return Ok(());
     }
+
     let swatches = context.args().theme.map_or_else(
         || palette.find_swatches(context.args().count),
         |option| {
@@ -66,12 +139,75 @@ fn main() {
             palette.find_swatches_with_theme(context.args().count, theme)
         },
     );
-    context.args().output.print(&context, &swatches).unwrap();
+    context
+        .args()
+        .output
+        .print(context, &swatches)
+        .map_err(|cause| cause.to_string())?;
 
-    println!(
+    let summary = format!(
         "Extracted {} swatch(es) in {}.{:03} seconds",
         palette.len(),
         instant.elapsed().as_secs(),
         instant.elapsed().subsec_millis()
     );
+    // Keep the Tailwind CSS config on stdout valid so that it can be redirected to a file.
+    if context.args().output == OutputFormat::Tailwind {
+        eprintln!("{}", summary);
+    } else {
+        println!("{}", summary);
+    }
+    Ok(())
+}
+
+/// Returns the dimensions to resize the image to before extracting the color palette.
+///
+/// # Arguments
+/// * `width` - The width of the original image.
+/// * `height` - The height of the original image.
+/// * `scale` - The scale factor. If `None`, the image is fitted within the maximum dimensions.
+///
+/// # Returns
+/// The width and height of the resized image.
+#[must_use]
+fn resize_dimensions(width: u32, height: u32, scale: Option<f64>) -> (u32, u32) {
+    let image_width = width as f64;
+    let image_height = height as f64;
+    let scale = scale.unwrap_or_else(|| {
+        f64::min(
+            MAX_IMAGE_WIDTH / image_width,
+            MAX_IMAGE_HEIGHT / image_height,
+        )
+    });
+    (
+        ((image_width * scale) as u32).max(1),
+        ((image_height * scale) as u32).max(1),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case::fit_landscape(720, 480, None, (360, 240))]
+    #[case::fit_portrait(480, 720, None, (240, 360))]
+    #[case::half(720, 480, Some(0.5), (360, 240))]
+    #[case::half_small(100, 50, Some(0.5), (50, 25))]
+    #[case::original(100, 50, Some(1.0), (100, 50))]
+    #[case::tiny(100, 50, Some(0.001), (1, 1))]
+    fn test_resize_dimensions(
+        #[case] width: u32,
+        #[case] height: u32,
+        #[case] scale: Option<f64>,
+        #[case] expected: (u32, u32),
+    ) {
+        // Act
+        let actual = resize_dimensions(width, height, scale);
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
 }
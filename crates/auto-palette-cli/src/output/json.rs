@@ -39,7 +39,8 @@ impl<'a> JsonPrinter<'a> {
         let mut swatch_map = Map::with_capacity(3);
 
         let color_format = self.context.args().color;
-        let color = color_format.fmt(swatch.color());
+        let precision = self.context.args().precision;
+        let color = color_format.fmt(swatch.color(), precision);
         swatch_map.insert(KEY_COLOR.into(), Value::String(color));
 
         swatch_map.insert(KEY_POSITION.into(), {
@@ -1,9 +1,13 @@
 mod json;
+mod png;
 mod printer;
 mod table;
+mod tailwind;
 mod text;
 
 pub use json::JsonPrinter;
+pub use png::PngPrinter;
 pub use printer::Printer;
 pub use table::TablePrinter;
+pub use tailwind::TailwindPrinter;
 pub use text::TextPrinter;
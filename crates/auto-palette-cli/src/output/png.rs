@@ -0,0 +1,155 @@
+use std::io::{Error, Write};
+
+use auto_palette::{FloatNumber, Palette, Swatch};
+use image::{codecs::png::PngEncoder, Rgb, RgbImage};
+
+use crate::output::Printer;
+
+const SWATCH_WIDTH: u32 = 160;
+const SWATCH_HEIGHT: u32 = 120;
+const LABEL_MARGIN: u32 = 8;
+const GLYPH_SCALE: u32 = 3;
+const GLYPH_WIDTH: u32 = 3;
+const GLYPH_HEIGHT: u32 = 5;
+
+/// The PNG printer for printing the swatches.
+///
+/// This printer renders the swatches as a PNG swatch sheet labeled with the hexadecimal color codes without `#`.
+#[derive(Debug, Default)]
+pub struct PngPrinter;
+
+impl PngPrinter {
+    /// Creates a new `PngPrinter` instance.
+    ///
+    /// # Returns
+    /// A new `PngPrinter` instance.
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Printer for PngPrinter {
+    fn print<T, W>(&self, swatches: &[Swatch<T>], output: &mut W) -> Result<(), Error>
+    where
+        T: FloatNumber,
+        W: Write,
+    {
+        let palette = Palette::new(swatches.to_vec());
+        let mut image = palette.to_image(SWATCH_WIDTH, SWATCH_HEIGHT);
+        for (index, swatch) in swatches.iter().enumerate() {
+            let color = swatch.color();
+            let text = color.black_or_white_text().to_rgb();
+            let x = index as u32 * SWATCH_WIDTH + LABEL_MARGIN;
+            let y = SWATCH_HEIGHT - LABEL_MARGIN - GLYPH_HEIGHT * GLYPH_SCALE;
+            draw_text(
+                &mut image,
+                color.to_hex_string().trim_start_matches('#'),
+                (x, y),
+                Rgb([text.r, text.g, text.b]),
+            );
+        }
+        image
+            .write_with_encoder(PngEncoder::new(output))
+            .map_err(Error::other)
+    }
+}
+
+/// Returns the glyph of the given character in a 3x5 bitmap font.
+///
+/// Each row is represented by the lower 3 bits, where the most significant bit is the leftmost pixel.
+///
+/// # Arguments
+/// * `c` - The character.
+///
+/// # Returns
+/// The glyph of the character, or `None` if the character is not supported.
+#[must_use]
+fn glyph(c: char) -> Option<[u8; 5]> {
+    let glyph = match c.to_ascii_uppercase() {
+        '0' => [0b111, 0b101, 0b101, 0b101, 0b111],
+        '1' => [0b010, 0b110, 0b010, 0b010, 0b111],
+        '2' => [0b111, 0b001, 0b111, 0b100, 0b111],
+        '3' => [0b111, 0b001, 0b111, 0b001, 0b111],
+        '4' => [0b101, 0b101, 0b111, 0b001, 0b001],
+        '5' => [0b111, 0b100, 0b111, 0b001, 0b111],
+        '6' => [0b111, 0b100, 0b111, 0b101, 0b111],
+        '7' => [0b111, 0b001, 0b001, 0b001, 0b001],
+        '8' => [0b111, 0b101, 0b111, 0b101, 0b111],
+        '9' => [0b111, 0b101, 0b111, 0b001, 0b111],
+        'A' => [0b010, 0b101, 0b111, 0b101, 0b101],
+        'B' => [0b110, 0b101, 0b110, 0b101, 0b110],
+        'C' => [0b111, 0b100, 0b100, 0b100, 0b111],
+        'D' => [0b110, 0b101, 0b101, 0b101, 0b110],
+        'E' => [0b111, 0b100, 0b111, 0b100, 0b111],
+        'F' => [0b111, 0b100, 0b111, 0b100, 0b100],
+        _ => return None,
+    };
+    Some(glyph)
+}
+
+/// Draws the given text on the image with the 3x5 bitmap font.
+///
+/// Unsupported characters are drawn as spaces, and pixels outside of the image are skipped.
+///
+/// # Arguments
+/// * `image` - The image to draw on.
+/// * `text` - The text to draw.
+/// * `position` - The top-left position of the text.
+/// * `color` - The color of the text.
+fn draw_text(image: &mut RgbImage, text: &str, position: (u32, u32), color: Rgb<u8>) {
+    let advance = (GLYPH_WIDTH + 1) * GLYPH_SCALE;
+    for (index, c) in text.chars().enumerate() {
+        let Some(glyph) = glyph(c) else {
+            continue;
+        };
+
+        let left = position.0 + index as u32 * advance;
+        for (row, bits) in glyph.iter().enumerate() {
+            for column in 0..GLYPH_WIDTH {
+                if bits & (1 << (GLYPH_WIDTH - 1 - column)) == 0 {
+                    continue;
+                }
+                for dy in 0..GLYPH_SCALE {
+                    for dx in 0..GLYPH_SCALE {
+                        let x = left + column * GLYPH_SCALE + dx;
+                        let y = position.1 + row as u32 * GLYPH_SCALE + dy;
+                        if x < image.width() && y < image.height() {
+                            image.put_pixel(x, y, color);
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_glyph() {
+        // Act & Assert
+        assert_eq!(glyph('0'), Some([0b111, 0b101, 0b101, 0b101, 0b111]));
+        assert_eq!(glyph('a'), glyph('A'));
+        assert_eq!(glyph('G'), None);
+    }
+
+    #[test]
+    fn test_draw_text() {
+        // Arrange
+        let mut image = RgbImage::new(32, 16);
+
+        // Act
+        draw_text(&mut image, "1", (1, 1), Rgb([255, 255, 255]));
+
+        // Assert
+        let lit = image
+            .pixels()
+            .filter(|pixel| pixel.0 == [255, 255, 255])
+            .count();
+        assert_eq!(lit, 8 * (GLYPH_SCALE * GLYPH_SCALE) as usize);
+        assert_eq!(image.get_pixel(1, 1), &Rgb([0, 0, 0]));
+        assert_eq!(image.get_pixel(1 + GLYPH_SCALE, 1), &Rgb([255, 255, 255]));
+    }
+}
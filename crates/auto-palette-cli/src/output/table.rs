@@ -36,6 +36,7 @@ impl<'a> Printer for TablePrinter<'a> {
         let mut writer = BufWriter::new(output);
 
         let color_format = self.context.args().color;
+        let precision = self.context.args().precision;
         let initial_widths = [
             HEADINGS[0].len(),
             HEADINGS[1].len(),
@@ -47,7 +48,7 @@ impl<'a> Printer for TablePrinter<'a> {
             .enumerate()
             .fold(initial_widths, |acc, (i, swatch)| {
                 let number_width = (i + 1).to_string().len();
-                let color_width = color_format.fmt(swatch.color()).len();
+                let color_width = color_format.fmt(swatch.color(), precision).len();
 
                 let (x, y) = swatch.position();
                 let position_width = format!("({}, {})", x, y).len();
@@ -73,7 +74,7 @@ impl<'a> Printer for TablePrinter<'a> {
         for (i, swatch) in swatches.iter().enumerate() {
             write!(writer, "| {:>width$} ", i + 1, width = widths[0])?;
 
-            let color = color_format.fmt(swatch.color());
+            let color = color_format.fmt(swatch.color(), precision);
             write!(writer, "| {:<width$} ", color, width = widths[1])?;
 
             let position = format!("{:?}", swatch.position());
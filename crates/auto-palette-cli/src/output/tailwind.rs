@@ -0,0 +1,37 @@
+use std::io::{BufWriter, Error, Write};
+
+use auto_palette::{FloatNumber, Palette, Swatch};
+
+use crate::output::Printer;
+
+const COLOR_PREFIX: &str = "palette";
+
+/// The Tailwind CSS printer for printing the swatches.
+///
+/// This printer prints the swatches as a Tailwind CSS config that can be used as `tailwind.config.js`.
+#[derive(Debug, Default)]
+pub struct TailwindPrinter;
+
+impl TailwindPrinter {
+    /// Creates a new `TailwindPrinter` instance.
+    ///
+    /// # Returns
+    /// A new `TailwindPrinter` instance.
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Printer for TailwindPrinter {
+    fn print<T, W>(&self, swatches: &[Swatch<T>], output: &mut W) -> Result<(), Error>
+    where
+        T: FloatNumber,
+        W: Write,
+    {
+        let palette = Palette::new(swatches.to_vec());
+        let mut writer = BufWriter::new(output);
+        write!(writer, "{}", palette.to_tailwind(COLOR_PREFIX))?;
+        writer.flush()?;
+        Ok(())
+    }
+}
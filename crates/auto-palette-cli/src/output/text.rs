@@ -39,7 +39,8 @@ impl<'a> TextPrinter<'a> {
         };
 
         let color_format = self.context.args().color;
-        let color = color_format.fmt(swatch.color());
+        let precision = self.context.args().precision;
+        let color = color_format.fmt(swatch.color(), precision);
         let color_str = format!("{:<width$}", color, width = widths[0]);
 
         let (x, y) = swatch.position();
@@ -91,8 +92,9 @@ impl<'a> Printer for TextPrinter<'a> {
         let mut writer = BufWriter::new(output);
 
         let color_format = self.context.args().color;
+        let precision = self.context.args().precision;
         let widths = swatches.iter().fold([0, 0, 0], |acc, swatch| {
-            let color_width = color_format.fmt(swatch.color()).len();
+            let color_width = color_format.fmt(swatch.color(), precision).len();
 
             let (x, y) = swatch.position();
             let position_width = format!("({}, {})", x, y).len();
@@ -0,0 +1,110 @@
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+    sync::mpsc,
+    time::SystemTime,
+};
+
+use notify::{
+    event::{AccessKind, AccessMode, CreateKind, ModifyKind, RenameMode},
+    Event,
+    EventKind,
+    RecursiveMode,
+    Watcher,
+};
+
+use crate::{context::Context, extract_image};
+
+/// Watches the given directory and extracts the color palette from each image file added to it.
+///
+/// This function blocks until the watcher is disconnected.
+///
+/// # Arguments
+/// * `context` - The context for the command line application.
+/// * `directory` - The directory to watch.
+///
+/// # Returns
+/// The result of the operation.
+///
+/// # Errors
+/// Returns an error if the directory cannot be watched.
+pub fn watch(context: &Context, directory: &Path) -> notify::Result<()> {
+    let (sender, receiver) = mpsc::channel();
+    let mut watcher = notify::recommended_watcher(sender)?;
+    watcher.watch(directory, RecursiveMode::NonRecursive)?;
+    println!("Watching {:?} for new image files", directory);
+
+    let mut processed: HashMap<PathBuf, SystemTime> = HashMap::new();
+    for event in receiver {
+        let event = event?;
+        if !is_file_added(&event) {
+            continue;
+        }
+
+        for path in event.paths {
+            let Ok(modified) = path.metadata().and_then(|metadata| metadata.modified()) else {
+                continue;
+            };
+            if processed.get(&path) == Some(&modified) {
+                continue;
+            }
+            // The file may be partially written when the event is received, so it is retried on the next event.
+            if image::ImageFormat::from_path(&path).is_err() {
+                continue;
+            }
+            let Ok(image) = image::open(&path) else {
+                continue;
+            };
+
+            processed.insert(path.clone(), modified);
+            println!("{}", path.display());
+            if let Err(message) = extract_image(context, &path, image) {
+                eprintln!("{}", message);
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Returns whether the given event indicates that a file was added to the directory.
+///
+/// # Arguments
+/// * `event` - The file system event.
+///
+/// # Returns
+/// `true` if a file was created, written, or moved into the directory, otherwise `false`.
+#[must_use]
+fn is_file_added(event: &Event) -> bool {
+    matches!(
+        event.kind,
+        EventKind::Create(CreateKind::File | CreateKind::Any)
+            | EventKind::Modify(ModifyKind::Name(RenameMode::To | RenameMode::Any))
+            | EventKind::Access(AccessKind::Close(AccessMode::Write))
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use notify::event::RemoveKind;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case::create(EventKind::Create(CreateKind::File), true)]
+    #[case::close_write(EventKind::Access(AccessKind::Close(AccessMode::Write)), true)]
+    #[case::rename_to(EventKind::Modify(ModifyKind::Name(RenameMode::To)), true)]
+    #[case::create_folder(EventKind::Create(CreateKind::Folder), false)]
+    #[case::rename_from(EventKind::Modify(ModifyKind::Name(RenameMode::From)), false)]
+    #[case::remove(EventKind::Remove(RemoveKind::File), false)]
+    fn test_is_file_added(#[case] kind: EventKind, #[case] expected: bool) {
+        // Arrange
+        let event = Event::new(kind);
+
+        // Act
+        let actual = is_file_added(&event);
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+}
@@ -111,6 +111,248 @@ fn test_invalid_count() {
     assert.failure();
 }
 
+#[test]
+fn test_precision() {
+    let assert = auto_palette()
+        .arg("../../gfx/olympic_logo.png")
+        .arg("--color")
+        .arg("lab")
+        .arg("--precision")
+        .arg("0")
+        .assert()
+        .stdout(
+            predicate::str::is_match(r"Lab\(-?\d+, -?\d+, -?\d+\)")
+                .unwrap()
+                .and(predicate::str::is_match(r"Lab\([^)]*\.").unwrap().not()),
+        );
+    assert.success();
+}
+
+#[test]
+fn test_tailwind_output() {
+    let assert = auto_palette()
+        .arg("../../gfx/olympic_logo.png")
+        .arg("--count")
+        .arg("3")
+        .arg("--output")
+        .arg("tailwind")
+        .assert()
+        .stdout(
+            predicate::str::starts_with("module.exports = {")
+                .and(predicate::str::contains("colors: {"))
+                .and(predicate::str::is_match(r"'palette-1': '#[0-9A-F]{6}',").unwrap())
+                .and(predicate::str::is_match(r"'palette-3': '#[0-9A-F]{6}',").unwrap())
+                .and(predicate::str::contains("'palette-4'").not())
+                .and(predicate::str::ends_with("};\n"))
+                .and(predicate::str::contains("Extracted").not()),
+        )
+        .stderr(predicate::str::is_match(r"^Extracted \d+ swatch\(es\) in").unwrap());
+    assert.success();
+}
+
+#[test]
+fn test_scale() {
+    let assert = auto_palette()
+        .arg("../../gfx/olympic_logo.png")
+        .arg("--scale")
+        .arg("0.5")
+        .assert();
+    assert.success();
+}
+
+#[test]
+fn test_invalid_scale() {
+    let assert = auto_palette()
+        .arg("../../gfx/olympic_logo.png")
+        .arg("--scale")
+        .arg("1.5")
+        .assert()
+        .stderr(predicate::str::contains(
+            "invalid value '1.5' for '--scale <factor>': must be in the range (0, 1]",
+        ));
+    assert.failure();
+}
+
+#[test]
+fn test_seed() {
+    // Act
+    let output = |seed: &str| {
+        auto_palette()
+            .arg("../../gfx/olympic_logo.png")
+            .arg("--algorithm")
+            .arg("kmeans")
+            .arg("--seed")
+            .arg(seed)
+            .arg("--output")
+            .arg("json")
+            .output()
+            .unwrap()
+    };
+    let actual1 = output("42");
+    let actual2 = output("42");
+
+    // Assert
+    assert!(actual1.status.success());
+    // Ignore the last line reporting the elapsed time.
+    let palette = |stdout: Vec<u8>| {
+        let stdout = String::from_utf8(stdout).unwrap();
+        stdout
+            .lines()
+            .filter(|line| !line.starts_with("Extracted"))
+            .map(str::to_string)
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(palette(actual1.stdout), palette(actual2.stdout));
+}
+
+#[test]
+fn test_png_output() {
+    // Arrange
+    let path = std::env::temp_dir().join(format!("auto-palette-sheet-{}.png", std::process::id()));
+
+    // Act
+    let assert = auto_palette()
+        .arg("../../gfx/olympic_logo.png")
+        .arg("--count")
+        .arg("3")
+        .arg("--output")
+        .arg("png")
+        .arg("--file")
+        .arg(&path)
+        .assert();
+
+    // Assert
+    assert.success();
+    let image = image::open(&path).unwrap().to_rgb8();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(image.dimensions(), (480, 120));
+    assert_eq!(image.get_pixel(80, 40), &image::Rgb([255, 255, 255]));
+}
+
+#[test]
+fn test_png_output_without_file() {
+    let assert = auto_palette()
+        .arg("../../gfx/olympic_logo.png")
+        .arg("--output")
+        .arg("png")
+        .assert()
+        .stderr(predicate::str::contains("--file <PATH>"));
+    assert.failure();
+}
+
+#[test]
+#[cfg(feature = "watch")]
+fn test_watch() {
+    use std::{
+        io::{BufRead, BufReader},
+        process::Stdio,
+        sync::mpsc,
+        thread,
+        time::Duration,
+    };
+
+    // Arrange
+    let directory = std::env::temp_dir().join(format!("auto-palette-watch-{}", std::process::id()));
+    std::fs::create_dir_all(&directory).unwrap();
+    let mut child = std::process::Command::new(assert_cmd::cargo::cargo_bin("auto-palette-cli"))
+        .arg("--watch")
+        .arg(&directory)
+        .arg("--output")
+        .arg("json")
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+
+    let (sender, receiver) = mpsc::channel();
+    let stdout = child.stdout.take().unwrap();
+    thread::spawn(move || {
+        for line in BufReader::new(stdout).lines() {
+            let Ok(line) = line else {
+                break;
+            };
+            if sender.send(line).is_err() {
+                break;
+            }
+        }
+    });
+
+    // Act
+    let watching = receiver.recv_timeout(Duration::from_secs(10)).unwrap();
+    std::fs::copy(
+        "../../gfx/olympic_logo.png",
+        directory.join("olympic_logo.png"),
+    )
+    .unwrap();
+    let mut lines = Vec::new();
+    while let Ok(line) = receiver.recv_timeout(Duration::from_secs(30)) {
+        let extracted = line.starts_with("Extracted");
+        lines.push(line);
+        if extracted {
+            break;
+        }
+    }
+
+    // Assert
+    child.kill().unwrap();
+    child.wait().unwrap();
+    std::fs::remove_dir_all(&directory).unwrap();
+    assert!(watching.starts_with("Watching"));
+    assert!(lines.iter().any(|line| line.ends_with("olympic_logo.png")));
+    assert!(lines.iter().any(|line| line.contains("\"color\"")));
+    assert!(lines.last().unwrap().starts_with("Extracted"));
+}
+
+#[test]
+#[cfg(feature = "watch")]
+fn test_watch_with_file() {
+    let assert = auto_palette()
+        .arg("--watch")
+        .arg(std::env::temp_dir())
+        .arg("--file")
+        .arg("palette.json")
+        .assert()
+        .stderr(predicate::str::contains("cannot be used with"));
+    assert.failure();
+}
+
+#[test]
+#[cfg(feature = "watch")]
+fn test_watch_with_png_output() {
+    let assert = auto_palette()
+        .arg("--watch")
+        .arg(std::env::temp_dir())
+        .arg("--output")
+        .arg("png")
+        .assert()
+        .stderr(predicate::str::contains("--file <PATH>"));
+    assert.failure();
+}
+
+#[test]
+fn test_dominant() {
+    let assert = auto_palette()
+        .arg("../../gfx/olympic_logo.png")
+        .arg("--dominant")
+        .arg("--no-resize")
+        .assert()
+        .stdout("#FFFFFF\n");
+    assert.success();
+}
+
+#[test]
+fn test_dominant_with_theme() {
+    let assert = auto_palette()
+        .arg("../../gfx/olympic_logo.png")
+        .arg("--dominant")
+        .arg("--theme")
+        .arg("vivid")
+        .assert()
+        .stderr(predicate::str::contains(
+            "the argument '--dominant' cannot be used with '--theme <name>'",
+        ));
+    assert.failure();
+}
+
 #[test]
 fn test_invalid_output() {
     let assert = auto_palette()
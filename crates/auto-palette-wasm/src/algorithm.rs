@@ -23,6 +23,29 @@ impl AlgorithmWrapper {
             .map(AlgorithmWrapper)
             .map_err(|_| JsValue::from_str(format!("Unknown algorithm name: {}", s).as_str()))
     }
+
+    /// Creates an `AlgorithmWrapper` for the median cut quantization algorithm.
+    ///
+    /// # Arguments
+    /// * `max_colors` - The maximum number of colors. The value must be greater than zero.
+    ///
+    /// # Returns
+    /// The `AlgorithmWrapper` for the median cut algorithm.
+    #[wasm_bindgen(js_name = medianCut)]
+    pub fn median_cut(max_colors: usize) -> AlgorithmWrapper {
+        AlgorithmWrapper(Algorithm::MedianCut { max_colors })
+    }
+
+    /// Creates an `AlgorithmWrapper` for the octree quantization algorithm.
+    ///
+    /// # Arguments
+    /// * `max_colors` - The maximum number of colors.
+    ///
+    /// # Returns
+    /// The `AlgorithmWrapper` for the octree algorithm.
+    pub fn octree(max_colors: usize) -> AlgorithmWrapper {
+        AlgorithmWrapper(Algorithm::Octree { max_colors })
+    }
 }
 
 #[cfg(test)]
@@ -38,6 +61,8 @@ mod tests {
     #[case::kmeans("kmeans", Algorithm::KMeans)]
     #[case::dbscan("dbscan", Algorithm::DBSCAN)]
     #[case::dbscanpp("dbscan++", Algorithm::DBSCANpp)]
+    #[case::median_cut("mediancut", Algorithm::MedianCut { max_colors: 32 })]
+    #[case::octree("octree", Algorithm::Octree { max_colors: 256 })]
     fn test_from_string(#[case] s: &str, #[case] expected: Algorithm) {
         // Act
         let actual = AlgorithmWrapper::from_string(s).unwrap();
@@ -46,6 +71,24 @@ mod tests {
         assert_eq!(actual.0, expected);
     }
 
+    #[test]
+    fn test_median_cut() {
+        // Act
+        let actual = AlgorithmWrapper::median_cut(16);
+
+        // Assert
+        assert_eq!(actual.0, Algorithm::MedianCut { max_colors: 16 });
+    }
+
+    #[test]
+    fn test_octree() {
+        // Act
+        let actual = AlgorithmWrapper::octree(16);
+
+        // Assert
+        assert_eq!(actual.0, Algorithm::Octree { max_colors: 16 });
+    }
+
     #[wasm_bindgen_test]
     fn test_from_string_unknown() {
         // Act
@@ -0,0 +1,13 @@
+use wasm_bindgen::prelude::wasm_bindgen;
+
+/// Struct representing a HWB color.
+#[wasm_bindgen(js_name = HWB)]
+#[derive(Debug, PartialEq)]
+pub struct Hwb {
+    /// The hue component of the color in degrees.
+    pub h: f32,
+    /// The whiteness component of the color.
+    pub w: f32,
+    /// The blackness component of the color.
+    pub b: f32,
+}
@@ -1,3 +1,4 @@
+mod hwb;
 mod lab;
 mod rgb;
 mod xyz;
@@ -5,6 +6,7 @@ mod xyz;
 use std::str::FromStr;
 
 use auto_palette::color::Color;
+pub use hwb::Hwb;
 pub use lab::Lab;
 pub use rgb::Rgb;
 use wasm_bindgen::{prelude::wasm_bindgen, JsValue};
@@ -105,6 +107,20 @@ impl ColorWrapper {
         }
     }
 
+    /// Returns the HWB representation of this color.
+    ///
+    /// # Returns
+    /// The HWB representation of this color.
+    #[wasm_bindgen(js_name = toHWB)]
+    pub fn to_hwb(&self) -> Hwb {
+        let hwb = self.0.to_hwb();
+        Hwb {
+            h: hwb.h.to_degrees(),
+            w: hwb.w,
+            b: hwb.b,
+        }
+    }
+
     /// Returns the hex string representation of this color.
     ///
     /// # Returns
@@ -138,7 +154,7 @@ mod tests {
         let wrapper = ColorWrapper(color);
 
         // Assert
-        assert_eq!(wrapper.is_light(), true);
+        assert!(wrapper.is_light());
     }
 
     #[test]
@@ -148,14 +164,14 @@ mod tests {
         let wrapper = ColorWrapper(color);
 
         // Assert
-        assert_eq!(wrapper.is_dark(), false);
+        assert!(!wrapper.is_dark());
     }
 
     #[test]
     fn test_lightness() {
         // Act
         let color = Color::from_str("#149972").unwrap();
-        let wrapper = ColorWrapper(color.clone());
+        let wrapper = ColorWrapper(color);
         let actual = wrapper.lightness();
 
         // Assert
@@ -166,7 +182,7 @@ mod tests {
     fn test_chroma() {
         // Act
         let color = Color::from_str("#149972").unwrap();
-        let wrapper = ColorWrapper(color.clone());
+        let wrapper = ColorWrapper(color);
         let actual = wrapper.chroma();
 
         // Assert
@@ -177,7 +193,7 @@ mod tests {
     fn test_hue() {
         // Act
         let color = Color::from_str("#149972").unwrap();
-        let wrapper = ColorWrapper(color.clone());
+        let wrapper = ColorWrapper(color);
         let actual = wrapper.hue();
 
         // Assert
@@ -244,6 +260,21 @@ mod tests {
         );
     }
 
+    #[wasm_bindgen_test]
+    fn test_to_hwb() {
+        // Arrange
+        let color = Color::from_str("#149972").unwrap();
+        let wrapper = ColorWrapper(color);
+
+        // Act
+        let actual = wrapper.to_hwb();
+
+        // Assert
+        assert!((actual.h - 162.406).abs() < 1e-3);
+        assert!((actual.w - 0.078_431_375).abs() < 1e-6);
+        assert!((actual.b - 0.4).abs() < 1e-6);
+    }
+
     #[test]
     fn test_to_hex_string() {
         // Act
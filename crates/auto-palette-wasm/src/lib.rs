@@ -1,13 +1,15 @@
 mod algorithm;
 mod color;
+mod options;
 mod palette;
 mod position;
 mod swatch;
 mod theme;
 
 pub use algorithm::AlgorithmWrapper;
-use auto_palette::{ImageData, Palette};
+use auto_palette::{ImageData, Palette, PaletteBuilder};
 pub use color::ColorWrapper;
+pub use options::ExtractOptions;
 use wasm_bindgen::{prelude::wasm_bindgen, Clamped, JsValue};
 
 use crate::palette::PaletteWrapper;
@@ -38,13 +40,64 @@ pub fn extract(
     Ok(PaletteWrapper(palette))
 }
 
+/// Extracts a palette from the given image data using the specified options.
+///
+/// # Arguments
+/// * `width` - The width of the image.
+/// * `height` - The height of the image.
+/// * `data` - The image data to extract a palette from.
+/// * `options` - The options to use for extracting the palette.
+///
+/// # Returns
+/// The extracted `Palette` if successful, otherwise an error.
+#[wasm_bindgen(js_name = extractWithOptions)]
+pub fn extract_with_options(
+    width: u32,
+    height: u32,
+    data: Clamped<Vec<u8>>,
+    options: &ExtractOptions,
+) -> Result<PaletteWrapper, JsValue> {
+    console_error_panic_hook::set_once();
+
+    let mut pixels = data.0;
+    if let Some(threshold) = options.filter_alpha_threshold {
+        pixels
+            .chunks_mut(4)
+            .filter(|pixel| pixel.len() == 4 && pixel[3] < threshold)
+            .for_each(|pixel| pixel[3] = 0);
+    }
+    let image_data =
+        ImageData::new(width, height, &pixels).map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    let mut builder = PaletteBuilder::new().algorithm(options.algorithm.clone());
+    if let Some(merge_epsilon) = options.merge_epsilon {
+        builder = builder.merge_epsilon(merge_epsilon);
+    }
+    if let Some(seed) = options.seed {
+        builder = builder.seed(seed);
+    }
+    let palette = builder
+        .build(&image_data)
+        .map_err(|e| JsValue::from_str(&e.to_string()))?;
+    let palette = match options.max_swatches {
+        Some(max_swatches) if max_swatches < palette.len() => {
+            Palette::new(palette.swatches()[..max_swatches].to_vec())
+        }
+        _ => palette,
+    };
+    Ok(PaletteWrapper(palette))
+}
+
 #[cfg(test)]
 mod tests {
     use auto_palette::Algorithm;
     use image::GenericImageView;
+    use wasm_bindgen_test::wasm_bindgen_test;
 
     use super::*;
 
+    wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
     #[test]
     fn test_extract() {
         // Arrange
@@ -64,4 +117,50 @@ mod tests {
         assert!(!actual.is_empty());
         assert_eq!(actual.length(), 6);
     }
+
+    #[test]
+    fn test_extract_with_options() {
+        // Arrange
+        let image = image::open("../../gfx/olympic_logo.png").unwrap();
+        let (width, height) = image.dimensions();
+        let pixels = image.to_rgba8().into_raw();
+
+        // Act
+        let data = Clamped(pixels);
+        let options = ExtractOptions {
+            algorithm: Algorithm::UniformGrid {
+                bits_per_channel: 4,
+            },
+            max_swatches: Some(3),
+            filter_alpha_threshold: Some(128),
+            ..ExtractOptions::default()
+        };
+        let actual = extract_with_options(width, height, data, &options).unwrap();
+
+        // Assert
+        assert_eq!(actual.length(), 3);
+    }
+
+    #[wasm_bindgen_test]
+    fn test_extract_with_options_alpha_threshold() {
+        // Arrange
+        let data: Vec<u8> = (0..16 * 16)
+            .flat_map(|index| {
+                if index % 2 == 0 {
+                    [255, 0, 0, 255]
+                } else {
+                    [0, 0, 255, 64]
+                }
+            })
+            .collect();
+        let mut options = ExtractOptions::default();
+        options.set_max_swatches(Some(4));
+        options.set_filter_alpha_threshold(Some(128));
+
+        // Act
+        let actual = extract_with_options(16, 16, Clamped(data), &options).unwrap();
+
+        // Assert
+        assert_eq!(actual.length(), 1);
+    }
 }
@@ -0,0 +1,330 @@
+use std::str::FromStr;
+
+use auto_palette::Algorithm;
+use wasm_bindgen::{prelude::wasm_bindgen, JsValue};
+
+use crate::algorithm::AlgorithmWrapper;
+
+#[wasm_bindgen(typescript_custom_section)]
+const EXTRACT_OPTIONS_INIT: &'static str = r#"
+/**
+ * The options to create `ExtractOptions` from.
+ */
+export interface ExtractOptionsInit {
+  /** The name of the algorithm to use. Defaults to `"dbscan"`. */
+  algorithm?: string;
+  /** The maximum number of swatches in the palette, as a non-negative integer. */
+  maxSwatches?: number;
+  /** The epsilon used when merging similar colors. */
+  mergeEpsilon?: number;
+  /** The alpha value below which pixels are ignored, as an integer in the range [0, 255]. */
+  filterAlphaThreshold?: number;
+  /** The seed of the random number generator. */
+  seed?: bigint;
+}
+"#;
+
+#[wasm_bindgen]
+extern "C" {
+    /// The plain object type to create `ExtractOptions` from.
+    #[wasm_bindgen(typescript_type = "ExtractOptionsInit")]
+    pub type ExtractOptionsInit;
+}
+
+/// Struct for the options of extracting a palette.
+///
+/// This struct is used to pass the options to `extractWithOptions` instead of a growing list of arguments.
+#[wasm_bindgen]
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExtractOptions {
+    pub(super) algorithm: Algorithm,
+    pub(super) max_swatches: Option<usize>,
+    pub(super) merge_epsilon: Option<f32>,
+    pub(super) filter_alpha_threshold: Option<u8>,
+    pub(super) seed: Option<u64>,
+}
+
+impl Default for ExtractOptions {
+    fn default() -> Self {
+        Self {
+            algorithm: Algorithm::DBSCAN,
+            max_swatches: None,
+            merge_epsilon: None,
+            filter_alpha_threshold: None,
+            seed: None,
+        }
+    }
+}
+
+#[wasm_bindgen]
+impl ExtractOptions {
+    /// Creates a new `ExtractOptions` from the given plain object.
+    ///
+    /// # Arguments
+    /// * `init` - The plain object with the options. The missing options are set to their defaults.
+    ///
+    /// # Returns
+    /// The `ExtractOptions` if successful, otherwise an error.
+    #[wasm_bindgen(constructor)]
+    pub fn new(init: Option<ExtractOptionsInit>) -> Result<ExtractOptions, JsValue> {
+        let mut options = ExtractOptions::default();
+        let Some(init) = init else {
+            return Ok(options);
+        };
+
+        if let Some(value) = get_property(&init, "algorithm")? {
+            let name = value
+                .as_string()
+                .ok_or_else(|| JsValue::from_str("The algorithm must be a string."))?;
+            options.algorithm = Algorithm::from_str(&name).map_err(|_| {
+                JsValue::from_str(format!("Unknown algorithm name: {}", name).as_str())
+            })?;
+        }
+        if let Some(value) = get_property(&init, "maxSwatches")? {
+            let max_swatches = get_integer(&value, "maxSwatches", usize::MAX as f64)?;
+            options.max_swatches = Some(max_swatches as usize);
+        }
+        if let Some(value) = get_property(&init, "mergeEpsilon")? {
+            let merge_epsilon = value
+                .as_f64()
+                .ok_or_else(|| JsValue::from_str("The mergeEpsilon must be a number."))?;
+            options.merge_epsilon = Some(merge_epsilon as f32);
+        }
+        if let Some(value) = get_property(&init, "filterAlphaThreshold")? {
+            let threshold = get_integer(&value, "filterAlphaThreshold", f64::from(u8::MAX))?;
+            options.filter_alpha_threshold = Some(threshold as u8);
+        }
+        if let Some(value) = get_property(&init, "seed")? {
+            let seed = u64::try_from(value)
+                .map_err(|_| JsValue::from_str("The seed must be a non-negative bigint."))?;
+            options.seed = Some(seed);
+        }
+        Ok(options)
+    }
+
+    /// Returns the algorithm to use for extracting the palette.
+    ///
+    /// # Returns
+    /// The algorithm to use.
+    #[wasm_bindgen(getter)]
+    pub fn algorithm(&self) -> AlgorithmWrapper {
+        AlgorithmWrapper(self.algorithm.clone())
+    }
+
+    /// Sets the algorithm to use for extracting the palette.
+    ///
+    /// # Arguments
+    /// * `algorithm` - The algorithm to use.
+    #[wasm_bindgen(setter)]
+    pub fn set_algorithm(&mut self, algorithm: AlgorithmWrapper) {
+        self.algorithm = algorithm.0;
+    }
+
+    /// Returns the maximum number of swatches in the palette.
+    ///
+    /// # Returns
+    /// The maximum number of swatches, or `undefined` if the number is not limited.
+    #[wasm_bindgen(getter = maxSwatches)]
+    pub fn max_swatches(&self) -> Option<usize> {
+        self.max_swatches
+    }
+
+    /// Sets the maximum number of swatches in the palette.
+    ///
+    /// The most populous swatches are kept when the palette has more swatches.
+    ///
+    /// # Arguments
+    /// * `max_swatches` - The maximum number of swatches, or `undefined` not to limit the number.
+    #[wasm_bindgen(setter = maxSwatches)]
+    pub fn set_max_swatches(&mut self, max_swatches: Option<usize>) {
+        self.max_swatches = max_swatches;
+    }
+
+    /// Returns the epsilon used when merging similar colors.
+    ///
+    /// # Returns
+    /// The merge epsilon, or `undefined` if the default is used.
+    #[wasm_bindgen(getter = mergeEpsilon)]
+    pub fn merge_epsilon(&self) -> Option<f32> {
+        self.merge_epsilon
+    }
+
+    /// Sets the epsilon used when merging similar colors.
+    ///
+    /// # Arguments
+    /// * `merge_epsilon` - The merge epsilon, or `undefined` to use the default.
+    #[wasm_bindgen(setter = mergeEpsilon)]
+    pub fn set_merge_epsilon(&mut self, merge_epsilon: Option<f32>) {
+        self.merge_epsilon = merge_epsilon;
+    }
+
+    /// Returns the alpha value below which pixels are ignored.
+    ///
+    /// # Returns
+    /// The alpha threshold, or `undefined` if only fully transparent pixels are ignored.
+    #[wasm_bindgen(getter = filterAlphaThreshold)]
+    pub fn filter_alpha_threshold(&self) -> Option<u8> {
+        self.filter_alpha_threshold
+    }
+
+    /// Sets the alpha value below which pixels are ignored.
+    ///
+    /// # Arguments
+    /// * `threshold` - The alpha threshold, or `undefined` to ignore only fully transparent pixels.
+    #[wasm_bindgen(setter = filterAlphaThreshold)]
+    pub fn set_filter_alpha_threshold(&mut self, threshold: Option<u8>) {
+        self.filter_alpha_threshold = threshold;
+    }
+
+    /// Returns the seed of the random number generator.
+    ///
+    /// # Returns
+    /// The seed, or `undefined` if no seed is set.
+    #[wasm_bindgen(getter)]
+    pub fn seed(&self) -> Option<u64> {
+        self.seed
+    }
+
+    /// Sets the seed of the random number generator.
+    ///
+    /// With a fixed seed, the `kmeans` algorithm yields the same palette for the same image on every run.
+    /// The other algorithms are deterministic and ignore the seed.
+    ///
+    /// # Arguments
+    /// * `seed` - The seed, or `undefined` not to fix the seed.
+    #[wasm_bindgen(setter)]
+    pub fn set_seed(&mut self, seed: Option<u64>) {
+        self.seed = seed;
+    }
+}
+
+/// Returns the value of the given property of the object.
+///
+/// # Arguments
+/// * `object` - The object to get the property from.
+/// * `name` - The name of the property.
+///
+/// # Returns
+/// The value of the property, or `None` if the property is `undefined` or `null`.
+fn get_property(object: &JsValue, name: &str) -> Result<Option<JsValue>, JsValue> {
+    let value = js_sys::Reflect::get(object, &JsValue::from_str(name))?;
+    if value.is_undefined() || value.is_null() {
+        Ok(None)
+    } else {
+        Ok(Some(value))
+    }
+}
+
+/// Returns the value as a non-negative integer.
+///
+/// # Arguments
+/// * `value` - The value to convert.
+/// * `name` - The name of the property, used in the error message.
+/// * `max` - The maximum allowed value.
+///
+/// # Returns
+/// The integer value, or an error if the value is not an integer in the range [0, `max`].
+fn get_integer(value: &JsValue, name: &str, max: f64) -> Result<f64, JsValue> {
+    let number = value
+        .as_f64()
+        .ok_or_else(|| JsValue::from_str(format!("The {} must be a number.", name).as_str()))?;
+    if !number.is_finite() || number < 0.0 || number.fract() != 0.0 || number > max {
+        return Err(JsValue::from_str(
+            format!("The {} must be an integer in the range [0, {}].", name, max).as_str(),
+        ));
+    }
+    Ok(number)
+}
+
+#[cfg(test)]
+mod tests {
+    use js_sys::{BigInt, Object, Reflect};
+    use wasm_bindgen::JsCast;
+    use wasm_bindgen_test::wasm_bindgen_test;
+
+    use super::*;
+
+    wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+    #[wasm_bindgen_test]
+    fn test_new() {
+        // Arrange
+        let init = Object::new();
+        Reflect::set(&init, &"algorithm".into(), &"kmeans".into()).unwrap();
+        Reflect::set(&init, &"maxSwatches".into(), &5.into()).unwrap();
+        Reflect::set(&init, &"seed".into(), &BigInt::from(42_u64).into()).unwrap();
+
+        // Act
+        let actual = ExtractOptions::new(Some(init.unchecked_into())).unwrap();
+
+        // Assert
+        assert_eq!(
+            actual,
+            ExtractOptions {
+                algorithm: Algorithm::KMeans,
+                max_swatches: Some(5),
+                seed: Some(42),
+                ..ExtractOptions::default()
+            }
+        );
+    }
+
+    #[wasm_bindgen_test]
+    fn test_new_without_init() {
+        // Act
+        let actual = ExtractOptions::new(None).unwrap();
+
+        // Assert
+        assert_eq!(actual, ExtractOptions::default());
+    }
+
+    #[wasm_bindgen_test]
+    fn test_new_invalid_number() {
+        let cases: [(&str, JsValue); 7] = [
+            ("maxSwatches", (-1).into()),
+            ("maxSwatches", f64::NAN.into()),
+            ("maxSwatches", 2.7.into()),
+            ("maxSwatches", f64::INFINITY.into()),
+            ("filterAlphaThreshold", 300.into()),
+            ("filterAlphaThreshold", (-0.5).into()),
+            ("filterAlphaThreshold", "128".into()),
+        ];
+        for (name, value) in cases {
+            // Arrange
+            let init = Object::new();
+            Reflect::set(&init, &name.into(), &value).unwrap();
+
+            // Act
+            let actual = ExtractOptions::new(Some(init.unchecked_into()));
+
+            // Assert
+            assert!(actual.is_err(), "{} = {:?}", name, value);
+        }
+    }
+
+    #[wasm_bindgen_test]
+    fn test_algorithm() {
+        // Arrange
+        let mut options = ExtractOptions::default();
+
+        // Act
+        options.set_algorithm(AlgorithmWrapper::octree(16));
+        let actual = options.algorithm();
+
+        // Assert
+        assert_eq!(actual.0, Algorithm::Octree { max_colors: 16 });
+    }
+
+    #[wasm_bindgen_test]
+    fn test_new_unknown_algorithm() {
+        // Arrange
+        let init = Object::new();
+        Reflect::set(&init, &"algorithm".into(), &"unknown".into()).unwrap();
+
+        // Act
+        let actual = ExtractOptions::new(Some(init.unchecked_into()));
+
+        // Assert
+        assert!(actual.is_err());
+    }
+}
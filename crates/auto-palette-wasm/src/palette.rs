@@ -46,6 +46,18 @@ impl PaletteWrapper {
             .map(SwatchWrapper)
             .collect()
     }
+
+    /// Returns a CSS block defining the colors of the swatches as custom properties.
+    ///
+    /// # Arguments
+    /// * `prefix` - The prefix of the variable names.
+    ///
+    /// # Returns
+    /// The CSS block with one `--<prefix>-<n>` variable per swatch in `:root`, ordered by population.
+    #[wasm_bindgen(js_name = toCssVariables)]
+    pub fn to_css_variables(&self, prefix: &str) -> String {
+        self.0.to_css_variables(prefix)
+    }
 }
 
 #[cfg(test)]
@@ -89,4 +101,22 @@ mod tests {
         // Assert
         assert_eq!(actual.len(), 3);
     }
+
+    #[test]
+    fn test_to_css_variables() {
+        // Arrange
+        let image = image::open("../../gfx/olympic_logo.png").unwrap();
+        let (width, height) = image.dimensions();
+        let pixels = image.to_rgba8().into_vec();
+        let image_data = ImageData::new(width, height, &pixels).unwrap();
+        let palette = Palette::extract(&image_data).unwrap();
+        let wrapper = PaletteWrapper(palette);
+
+        // Act
+        let actual = wrapper.to_css_variables("logo");
+
+        // Assert
+        assert!(actual.starts_with(":root {\n  --logo-1: #FFFFFF;\n"));
+        assert_eq!(actual.matches("--logo-").count(), 6);
+    }
 }
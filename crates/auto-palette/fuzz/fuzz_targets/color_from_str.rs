@@ -0,0 +1,15 @@
+#![no_main]
+
+use std::str::FromStr;
+
+use auto_palette::color::Color;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    if let Ok(color) = Color::<f32>::from_str(input) {
+        // Any parsed color must survive a round trip through its hexadecimal representation.
+        let hex = color.to_hex_string();
+        let parsed = Color::<f32>::from_str(&hex).expect("the hexadecimal string should be parsable");
+        assert_eq!(parsed.to_hex_string(), hex);
+    }
+});
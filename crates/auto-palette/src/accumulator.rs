@@ -0,0 +1,393 @@
+use std::cmp::Reverse;
+
+use crate::{
+    algorithm::Algorithm,
+    color::Color,
+    error::Error,
+    image::ImageData,
+    math::{denormalize, FloatNumber, Point},
+    palette::{cluster_color_points, cluster_colors, cluster_foo},
+    palette_builder::{COLOR_MERGE_EPSILON, COLOR_MERGE_MIN_POINTS},
+    Palette,
+    Swatch,
+};
+
+/// The minimum number of cluster statistics kept before they are merged.
+const COMPACTION_THRESHOLD: usize = 1024;
+
+/// The running statistics of a pixel cluster found in a tile.
+#[derive(Debug, Clone, PartialEq)]
+struct ClusterStats<T>
+where
+    T: FloatNumber,
+{
+    color: Point<T, 3>,
+    position: (u32, u32),
+    population: usize,
+}
+
+/// The accumulator to build a palette incrementally from image tiles.
+///
+/// Each tile is clustered when it is accumulated, and only the statistics of the clusters are kept,
+/// so very large images can be processed tile by tile without holding all pixels in memory.
+/// The statistics of similar colors are merged periodically, so the memory usage is bounded by the number of
+/// distinct colors rather than the number of tiles.
+///
+/// # Type Parameters
+/// * `T` - The floating point type.
+///
+/// # Examples
+/// ```
+/// use auto_palette::{ImageData, Palette, PaletteAccumulator};
+///
+/// // A 64x64 red tile
+/// let pixels = [255, 0, 0, 255].repeat(64 * 64);
+/// let tile = ImageData::new(64, 64, &pixels).unwrap();
+///
+/// let mut accumulator: PaletteAccumulator<f32> = PaletteAccumulator::default();
+/// accumulator.accumulate(&tile).unwrap();
+/// accumulator.accumulate(&tile).unwrap();
+///
+/// let palette: Palette<f32> = accumulator.finish();
+/// assert_eq!(palette.len(), 1);
+/// assert_eq!(palette.swatches()[0].color().to_hex_string(), "#FF0000");
+/// ```
+#[derive(Debug)]
+pub struct PaletteAccumulator<T>
+where
+    T: FloatNumber,
+{
+    algorithm: Algorithm,
+    clusters: Vec<ClusterStats<T>>,
+    compaction_threshold: usize,
+    total_pixels: usize,
+}
+
+impl<T> PaletteAccumulator<T>
+where
+    T: FloatNumber,
+{
+    /// Creates a new `PaletteAccumulator` instance.
+    ///
+    /// # Arguments
+    /// * `algorithm` - The clustering algorithm to use for each tile.
+    ///
+    /// # Returns
+    /// A new `PaletteAccumulator` instance.
+    #[must_use]
+    pub fn new(algorithm: Algorithm) -> Self {
+        Self {
+            algorithm,
+            clusters: Vec::new(),
+            compaction_threshold: COMPACTION_THRESHOLD,
+            total_pixels: 0,
+        }
+    }
+
+    /// Accumulates the given image tile.
+    ///
+    /// The tile is placed at the origin of the image. Use [`PaletteAccumulator::accumulate_at`]
+    /// to make the positions of the swatches relative to the whole image.
+    ///
+    /// # Arguments
+    /// * `tile` - The image tile to accumulate.
+    ///
+    /// # Errors
+    /// Returns an error if the parameters of the algorithm are invalid.
+    pub fn accumulate(&mut self, tile: &ImageData) -> Result<(), Error> {
+        self.accumulate_at(tile, 0, 0)
+    }
+
+    /// Accumulates the given image tile placed at the given offset in the whole image.
+    ///
+    /// # Arguments
+    /// * `tile` - The image tile to accumulate.
+    /// * `x` - The x-coordinate of the top-left corner of the tile in the whole image.
+    /// * `y` - The y-coordinate of the top-left corner of the tile in the whole image.
+    ///
+    /// # Errors
+    /// Returns an error if the parameters of the algorithm are invalid.
+    pub fn accumulate_at(&mut self, tile: &ImageData, x: u32, y: u32) -> Result<(), Error> {
+        let pixels = tile.data();
+        if pixels.is_empty() {
+            return Ok(());
+        }
+
+        let width = tile.width();
+        let height = tile.height();
+        let pixel_clusters: Vec<_> = cluster_foo::<T>(
+            width as usize,
+            height as usize,
+            pixels,
+            self.algorithm.clone(),
+            None,
+        )?
+        .into_iter()
+        .filter(|cluster| !cluster.is_empty())
+        .collect();
+        self.total_pixels += (width * height) as usize;
+        let width = T::from_u32(width);
+        let height = T::from_u32(height);
+        self.clusters.extend(
+            pixel_clusters
+                .iter()
+                .zip(cluster_colors(&pixel_clusters))
+                .map(|(cluster, color)| {
+                    let centroid = cluster.centroid();
+                    ClusterStats {
+                        color,
+                        position: (
+                            x + denormalize(centroid[3], T::zero(), width).to_u32_unsafe(),
+                            y + denormalize(centroid[4], T::zero(), height).to_u32_unsafe(),
+                        ),
+                        population: cluster.len(),
+                    }
+                }),
+        );
+
+        if self.clusters.len() > self.compaction_threshold {
+            self.compact();
+            // Merge again only after the statistics double, so the merging cost stays amortized.
+            self.compaction_threshold = COMPACTION_THRESHOLD.max(self.clusters.len() * 2);
+        }
+        Ok(())
+    }
+
+    /// Finishes the accumulation and builds the palette.
+    ///
+    /// # Returns
+    /// The palette built from the accumulated tiles.
+    #[must_use]
+    pub fn finish(mut self) -> Palette<T> {
+        if self.clusters.is_empty() {
+            return Palette::new(Vec::new());
+        }
+
+        self.compact();
+        let total_pixels = T::from_usize(self.total_pixels);
+        let mut swatches: Vec<Swatch<T>> = self
+            .clusters
+            .iter()
+            .map(|stats| {
+                Swatch::new(
+                    Color::new(stats.color[0], stats.color[1], stats.color[2]),
+                    stats.position,
+                    stats.population,
+                    T::from_usize(stats.population) / total_pixels,
+                )
+            })
+            .collect();
+        swatches.sort_by_key(|swatch| Reverse(swatch.population()));
+        Palette::new(swatches)
+    }
+
+    /// Merges the statistics of the clusters with similar colors.
+    ///
+    /// The merged color is the population-weighted mean color, and the merged position is the position of
+    /// the most populous cluster.
+    fn compact(&mut self) {
+        let colors: Vec<Point<T, 3>> = self.clusters.iter().map(|stats| stats.color).collect();
+        let color_clusters = cluster_color_points(
+            &colors,
+            T::from_f32(COLOR_MERGE_EPSILON),
+            COLOR_MERGE_MIN_POINTS,
+        );
+        self.clusters = color_clusters
+            .iter()
+            .map(|color_cluster| {
+                let mut members: Vec<usize> = color_cluster.members().copied().collect();
+                members.sort_unstable();
+
+                let mut color = [T::zero(); 3];
+                let mut best = &self.clusters[members[0]];
+                let mut population = 0;
+                for member in members {
+                    let stats = &self.clusters[member];
+                    population += stats.population;
+                    let fraction = T::from_usize(stats.population) / T::from_usize(population);
+                    for (value, &target) in color.iter_mut().zip(&stats.color) {
+                        *value += fraction * (target - *value);
+                    }
+                    if stats.population > best.population {
+                        best = stats;
+                    }
+                }
+                ClusterStats {
+                    color,
+                    position: best.position,
+                    population,
+                }
+            })
+            .collect();
+    }
+}
+
+impl<T> Default for PaletteAccumulator<T>
+where
+    T: FloatNumber,
+{
+    /// Creates a new `PaletteAccumulator` instance with the DBSCAN algorithm.
+    ///
+    /// # Returns
+    /// A new `PaletteAccumulator` instance.
+    fn default() -> Self {
+        Self::new(Algorithm::DBSCAN)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new() {
+        // Act
+        let actual: PaletteAccumulator<f32> = PaletteAccumulator::new(Algorithm::KMeans);
+
+        // Assert
+        assert_eq!(actual.algorithm, Algorithm::KMeans);
+        assert!(actual.clusters.is_empty());
+        assert_eq!(actual.total_pixels, 0);
+    }
+
+    #[test]
+    fn test_accumulate_at() {
+        // Arrange
+        let red = [255, 0, 0, 255].repeat(16 * 16);
+        let blue = [0, 0, 255, 255].repeat(16 * 16);
+        let red_tile = ImageData::new(16, 16, &red).unwrap();
+        let blue_tile = ImageData::new(16, 16, &blue).unwrap();
+        let mut accumulator: PaletteAccumulator<f32> =
+            PaletteAccumulator::new(Algorithm::UniformGrid {
+                bits_per_channel: 4,
+            });
+
+        // Act
+        accumulator.accumulate_at(&red_tile, 0, 0).unwrap();
+        accumulator.accumulate_at(&blue_tile, 100, 200).unwrap();
+        let actual = accumulator.finish();
+
+        // Assert
+        assert_eq!(actual.len(), 2);
+        let blue_swatch = actual
+            .swatches()
+            .iter()
+            .find(|swatch| swatch.color().to_hex_string() == "#0000FF")
+            .unwrap();
+        let (x, y) = blue_swatch.position();
+        assert!((100..116).contains(&x));
+        assert!((200..216).contains(&y));
+        let red_swatch = actual
+            .swatches()
+            .iter()
+            .find(|swatch| swatch.color().to_hex_string() == "#FF0000")
+            .unwrap();
+        assert!(red_swatch.position().0 < 16 && red_swatch.position().1 < 16);
+    }
+
+    #[test]
+    fn test_accumulate_invalid_algorithm() {
+        // Arrange
+        let pixels = [255, 0, 0, 255].repeat(16 * 16);
+        let tile = ImageData::new(16, 16, &pixels).unwrap();
+        let mut accumulator: PaletteAccumulator<f32> =
+            PaletteAccumulator::new(Algorithm::MedianCut { max_colors: 0 });
+
+        // Act
+        let actual = accumulator.accumulate(&tile);
+
+        // Assert
+        assert!(matches!(actual, Err(Error::ExtractionFailure { .. })));
+        assert_eq!(accumulator.total_pixels, 0);
+        assert!(accumulator.finish().is_empty());
+    }
+
+    #[test]
+    fn test_accumulate_compacts_clusters() {
+        // Arrange
+        let tile_pixels: Vec<u8> = (0..64 * 64)
+            .flat_map(|index| [(index % 64 * 4) as u8, (index / 64 * 4) as u8, 128, 255])
+            .collect();
+        let tile = ImageData::new(64, 64, &tile_pixels).unwrap();
+        let mut accumulator: PaletteAccumulator<f32> =
+            PaletteAccumulator::new(Algorithm::UniformGrid {
+                bits_per_channel: 8,
+            });
+
+        // Act
+        for _ in 0..16 {
+            accumulator.accumulate(&tile).unwrap();
+        }
+
+        // Assert
+        assert!(accumulator.clusters.len() <= accumulator.compaction_threshold);
+        assert!(accumulator.clusters.len() < 16 * 64 * 64);
+        let actual = accumulator.finish();
+        let population: usize = actual.swatches().iter().map(Swatch::population).sum();
+        assert_eq!(population, 16 * 64 * 64);
+    }
+
+    #[test]
+    fn test_finish_empty() {
+        // Arrange
+        let accumulator: PaletteAccumulator<f32> = PaletteAccumulator::default();
+
+        // Act
+        let actual = accumulator.finish();
+
+        // Assert
+        assert!(actual.is_empty());
+    }
+
+    #[test]
+    #[cfg(feature = "image")]
+    fn test_accumulate_quadrants() {
+        // Arrange
+        let image_data = ImageData::load("../../gfx/olympic_logo.png").unwrap();
+        let width = image_data.width() as usize;
+        let height = image_data.height() as usize;
+        let quadrants = [
+            (0, 0, width / 2, height / 2),
+            (width / 2, 0, width, height / 2),
+            (0, height / 2, width / 2, height),
+            (width / 2, height / 2, width, height),
+        ];
+        let tiles: Vec<(u32, u32, Vec<u8>)> = quadrants
+            .iter()
+            .map(|&(left, top, right, bottom)| {
+                let pixels = (top..bottom)
+                    .flat_map(|y| {
+                        let start = (y * width + left) * 4;
+                        let end = (y * width + right) * 4;
+                        image_data.data()[start..end].to_vec()
+                    })
+                    .collect();
+                ((right - left) as u32, (bottom - top) as u32, pixels)
+            })
+            .collect();
+
+        // Act
+        let mut accumulator: PaletteAccumulator<f32> = PaletteAccumulator::default();
+        for (&(left, top, _, _), (width, height, pixels)) in quadrants.iter().zip(&tiles) {
+            let tile = ImageData::new(*width, *height, pixels).unwrap();
+            accumulator
+                .accumulate_at(&tile, left as u32, top as u32)
+                .unwrap();
+        }
+        let actual = accumulator.finish();
+
+        // Assert
+        let expected: Palette<f32> = Palette::extract(&image_data).unwrap();
+        assert!(!actual.is_empty());
+        let actual_population: usize = actual.swatches().iter().map(Swatch::population).sum();
+        let expected_population: usize = expected.swatches().iter().map(Swatch::population).sum();
+        assert!(actual_population.abs_diff(expected_population) * 100 < expected_population);
+        for swatch in expected.swatches() {
+            let found = actual
+                .swatches()
+                .iter()
+                .any(|candidate| candidate.color().delta_e(swatch.color()) < 5.0);
+            assert!(found, "{} not found", swatch.color().to_hex_string());
+        }
+    }
+}
@@ -1,17 +1,39 @@
-use std::str::FromStr;
+use std::{collections::HashMap, str::FromStr};
 
-use rand::thread_rng;
+use rand::{rngs::StdRng, thread_rng, SeedableRng};
 
 use crate::{
+    color::{Color, Lab},
     error::Error,
     math::{
-        clustering::{Cluster, ClusteringAlgorithm, DBSCANPlusPlus, KMeans, DBSCAN},
+        clustering::{
+            Cluster,
+            ClusteringAlgorithm,
+            DBSCANPlusPlus,
+            KMeans,
+            MedianCut,
+            Octree,
+            DBSCAN,
+        },
+        denormalize,
         DistanceMetric,
         FloatNumber,
         Point,
     },
 };
 
+/// The default number of bits per channel of the uniform grid.
+const DEFAULT_GRID_BITS_PER_CHANNEL: u8 = 4;
+
+/// The default maximum number of colors of the median cut.
+const DEFAULT_MEDIAN_CUT_MAX_COLORS: usize = 32;
+
+/// The default maximum number of colors of the octree.
+const DEFAULT_OCTREE_MAX_COLORS: usize = 256;
+
+/// The maximum depth of the octree.
+const OCTREE_MAX_DEPTH: usize = 6;
+
 /// The clustering algorithm to use for color palette extraction.
 #[allow(clippy::upper_case_acronyms)]
 #[derive(Debug, Clone, PartialEq)]
@@ -22,6 +44,23 @@ pub enum Algorithm {
     DBSCAN,
     /// DBSCAN++ clustering algorithm.
     DBSCANpp,
+    /// Median cut quantization algorithm.
+    MedianCut {
+        /// The maximum number of colors. The value must be greater than zero.
+        max_colors: usize,
+    },
+    /// Octree quantization algorithm.
+    Octree {
+        /// The maximum number of colors. Values less than 1 are treated as 1.
+        max_colors: usize,
+    },
+    /// Uniform grid quantization algorithm.
+    ///
+    /// The colors are bucketed into a uniform RGB grid, which is the fastest and fully deterministic.
+    UniformGrid {
+        /// The number of bits per RGB channel in the range [1, 8]. The grid has `2^(3 * bits)` cells.
+        bits_per_channel: u8,
+    },
 }
 
 impl Algorithm {
@@ -29,19 +68,33 @@ impl Algorithm {
     ///
     /// # Arguments
     /// * `pixels` - The pixels to cluster.
+    /// * `seed` - The seed of the random number generator, or `None` to seed it from the thread-local generator.
+    ///   The deterministic algorithms ignore the seed.
     ///
     /// # Returns
     /// The clusters found by the algorithm.
-    #[must_use]
-    pub(crate) fn cluster<T>(&self, pixels: &[Point<T, 5>]) -> Vec<Cluster<T, 5>>
+    ///
+    /// # Errors
+    /// Returns an error if the parameters of the algorithm are invalid.
+    pub(crate) fn cluster<T>(
+        &self,
+        pixels: &[Point<T, 5>],
+        seed: Option<u64>,
+    ) -> Result<Vec<Cluster<T, 5>>, Error>
     where
         T: FloatNumber,
     {
-        match self {
-            Self::KMeans => cluster_with_kmeans(pixels),
+        let clusters = match self {
+            Self::KMeans => cluster_with_kmeans(pixels, seed),
             Self::DBSCAN => cluster_with_dbscan(pixels),
             Self::DBSCANpp => cluster_with_dbscanpp(pixels),
-        }
+            Self::MedianCut { max_colors } => cluster_with_median_cut(pixels, *max_colors)?,
+            Self::Octree { max_colors } => cluster_with_octree(pixels, *max_colors),
+            Self::UniformGrid { bits_per_channel } => {
+                cluster_with_uniform_grid(pixels, *bits_per_channel)
+            }
+        };
+        Ok(clusters)
     }
 }
 
@@ -53,6 +106,15 @@ impl FromStr for Algorithm {
             "kmeans" => Ok(Self::KMeans),
             "dbscan" => Ok(Self::DBSCAN),
             "dbscan++" => Ok(Self::DBSCANpp),
+            "mediancut" => Ok(Self::MedianCut {
+                max_colors: DEFAULT_MEDIAN_CUT_MAX_COLORS,
+            }),
+            "octree" => Ok(Self::Octree {
+                max_colors: DEFAULT_OCTREE_MAX_COLORS,
+            }),
+            "uniformgrid" => Ok(Self::UniformGrid {
+                bits_per_channel: DEFAULT_GRID_BITS_PER_CHANNEL,
+            }),
             _ => Err(Error::UnsupportedAlgorithm {
                 name: s.to_string(),
             }),
@@ -61,16 +123,20 @@ impl FromStr for Algorithm {
 }
 
 #[must_use]
-fn cluster_with_kmeans<T>(pixels: &[Point<T, 5>]) -> Vec<Cluster<T, 5>>
+fn cluster_with_kmeans<T>(pixels: &[Point<T, 5>], seed: Option<u64>) -> Vec<Cluster<T, 5>>
 where
     T: FloatNumber,
 {
+    let rng = match seed {
+        Some(seed) => StdRng::seed_from_u64(seed),
+        None => StdRng::from_rng(thread_rng()).unwrap(),
+    };
     let clustering = KMeans::new(
         32,
         100,
         T::from_f32(1e-3),
         DistanceMetric::SquaredEuclidean,
-        thread_rng(),
+        rng,
     )
     .unwrap();
     clustering.fit(pixels)
@@ -100,11 +166,60 @@ where
     clustering.fit(pixels)
 }
 
+fn cluster_with_median_cut<T>(
+    pixels: &[Point<T, 5>],
+    max_colors: usize,
+) -> Result<Vec<Cluster<T, 5>>, Error>
+where
+    T: FloatNumber,
+{
+    let clustering = MedianCut::new(max_colors).map_err(|details| Error::ExtractionFailure {
+        details: details.to_string(),
+    })?;
+    Ok(clustering.fit(pixels))
+}
+
+#[must_use]
+fn cluster_with_octree<T>(pixels: &[Point<T, 5>], max_colors: usize) -> Vec<Cluster<T, 5>>
+where
+    T: FloatNumber,
+{
+    let clustering = Octree::new(max_colors.max(1), OCTREE_MAX_DEPTH).unwrap();
+    clustering.fit(pixels)
+}
+
+#[must_use]
+fn cluster_with_uniform_grid<T>(pixels: &[Point<T, 5>], bits_per_channel: u8) -> Vec<Cluster<T, 5>>
+where
+    T: FloatNumber,
+{
+    let shift = 8 - bits_per_channel.clamp(1, 8);
+    let mut cells: HashMap<(u8, u8, u8), Cluster<T, 5>> = HashMap::new();
+    for (index, pixel) in pixels.iter().enumerate() {
+        let color: Color<T> = Color::from_lab(
+            denormalize(pixel[0], Lab::<T>::min_l(), Lab::<T>::max_l()),
+            denormalize(pixel[1], Lab::<T>::min_a(), Lab::<T>::max_a()),
+            denormalize(pixel[2], Lab::<T>::min_b(), Lab::<T>::max_b()),
+        );
+        let rgb = color.to_rgb();
+        cells
+            .entry((rgb.r >> shift, rgb.g >> shift, rgb.b >> shift))
+            .or_default()
+            .add_member(index, pixel);
+    }
+
+    // Sort the cells to make the order of the clusters independent of the hash map iteration order.
+    let mut cells: Vec<_> = cells.into_iter().collect();
+    cells.sort_unstable_by_key(|(cell, _)| *cell);
+    cells.into_iter().map(|(_, cluster)| cluster).collect()
+}
+
 #[cfg(test)]
 mod tests {
     use rstest::rstest;
 
     use super::*;
+    use crate::math::normalize;
 
     #[rstest]
     #[case::kmeans("kmeans", Algorithm::KMeans)]
@@ -116,6 +231,12 @@ mod tests {
     #[case::kmeans_capitalized("Kmeans", Algorithm::KMeans)]
     #[case::dbscan_capitalized("Dbscan", Algorithm::DBSCAN)]
     #[case::dbscanpp_capitalized("Dbscan++", Algorithm::DBSCANpp)]
+    #[case::median_cut("mediancut", Algorithm::MedianCut { max_colors: 32 })]
+    #[case::median_cut_capitalized("MedianCut", Algorithm::MedianCut { max_colors: 32 })]
+    #[case::octree("octree", Algorithm::Octree { max_colors: 256 })]
+    #[case::octree_upper("OCTREE", Algorithm::Octree { max_colors: 256 })]
+    #[case::uniform_grid("uniformgrid", Algorithm::UniformGrid { bits_per_channel: 4 })]
+    #[case::uniform_grid_capitalized("UniformGrid", Algorithm::UniformGrid { bits_per_channel: 4 })]
     fn test_from_str(#[case] input: &str, #[case] expected: Algorithm) {
         // Act
         let actual = Algorithm::from_str(input).unwrap();
@@ -138,4 +259,103 @@ mod tests {
             format!("The algorithm '{}' is not supported.", input)
         );
     }
+
+    fn gradient_points() -> Vec<Point<f32, 5>> {
+        (0..=255)
+            .step_by(5)
+            .flat_map(|r| {
+                (0..=255).step_by(15).map(move |b| {
+                    let color: Color<f32> =
+                        Color::from_linear_rgb(r as f32 / 255.0, 0.5, b as f32 / 255.0);
+                    let lab = color.to_lab();
+                    [
+                        normalize(lab.l, Lab::<f32>::min_l(), Lab::<f32>::max_l()),
+                        normalize(lab.a, Lab::<f32>::min_a(), Lab::<f32>::max_a()),
+                        normalize(lab.b, Lab::<f32>::min_b(), Lab::<f32>::max_b()),
+                        0.0,
+                        0.0,
+                    ]
+                })
+            })
+            .collect()
+    }
+
+    #[rstest]
+    #[case::one_bit(1, 8)]
+    #[case::two_bits(2, 64)]
+    #[case::out_of_range(0, 8)]
+    fn test_cluster_with_uniform_grid(#[case] bits_per_channel: u8, #[case] max_clusters: usize) {
+        // Arrange
+        let pixels = gradient_points();
+
+        // Act
+        let algorithm = Algorithm::UniformGrid { bits_per_channel };
+        let actual = algorithm.cluster(&pixels, None).unwrap();
+
+        // Assert
+        assert!(actual.len() > 1);
+        assert!(actual.len() <= max_clusters);
+        assert_eq!(actual.iter().map(Cluster::len).sum::<usize>(), pixels.len());
+    }
+
+    #[rstest]
+    #[case::one(1)]
+    #[case::eight(8)]
+    #[case::sixty_four(64)]
+    fn test_cluster_with_octree(#[case] max_colors: usize) {
+        // Arrange
+        let pixels = gradient_points();
+
+        // Act
+        let algorithm = Algorithm::Octree { max_colors };
+        let actual = algorithm.cluster(&pixels, None).unwrap();
+
+        // Assert
+        assert!(!actual.is_empty());
+        assert!(actual.len() <= max_colors);
+        assert_eq!(actual.iter().map(Cluster::len).sum::<usize>(), pixels.len());
+    }
+
+    #[rstest]
+    #[case::one(1)]
+    #[case::eight(8)]
+    #[case::sixty_four(64)]
+    fn test_cluster_with_median_cut(#[case] max_colors: usize) {
+        // Arrange
+        let pixels = gradient_points();
+
+        // Act
+        let algorithm = Algorithm::MedianCut { max_colors };
+        let actual = algorithm.cluster(&pixels, None).unwrap();
+
+        // Assert
+        assert_eq!(actual.len(), max_colors);
+        assert_eq!(actual.iter().map(Cluster::len).sum::<usize>(), pixels.len());
+    }
+
+    #[test]
+    fn test_cluster_with_median_cut_zero_colors() {
+        // Arrange
+        let pixels = gradient_points();
+
+        // Act
+        let algorithm = Algorithm::MedianCut { max_colors: 0 };
+        let actual = algorithm.cluster::<f32>(&pixels, None);
+
+        // Assert
+        assert!(matches!(actual, Err(Error::ExtractionFailure { .. })));
+    }
+
+    #[test]
+    fn test_cluster_with_octree_zero_colors() {
+        // Arrange
+        let pixels = gradient_points();
+
+        // Act
+        let algorithm = Algorithm::Octree { max_colors: 0 };
+        let actual = algorithm.cluster(&pixels, None).unwrap();
+
+        // Assert
+        assert_eq!(actual.len(), 1);
+    }
 }
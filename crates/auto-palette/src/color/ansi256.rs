@@ -8,6 +8,29 @@ use crate::{
     FloatNumber,
 };
 
+/// The RGB components of the 16 system colors in the default xterm palette.
+const SYSTEM_COLORS: [(u8, u8, u8); 16] = [
+    (0, 0, 0),
+    (128, 0, 0),
+    (0, 128, 0),
+    (128, 128, 0),
+    (0, 0, 128),
+    (128, 0, 128),
+    (0, 128, 128),
+    (192, 192, 192),
+    (128, 128, 128),
+    (255, 0, 0),
+    (0, 255, 0),
+    (255, 255, 0),
+    (0, 0, 255),
+    (255, 0, 255),
+    (0, 255, 255),
+    (255, 255, 255),
+];
+
+/// The component levels of the 6x6x6 color cube in the default xterm palette.
+const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
 /// The 8-bit ANSI 256 color.
 ///
 /// See the following for more details:
@@ -48,6 +71,31 @@ impl Ansi256 {
     pub fn code(&self) -> u8 {
         self.code
     }
+
+    /// Converts this color to the RGB color rendered by a terminal with the default xterm palette.
+    ///
+    /// Terminals may customize the first 16 colors, so the result is only an approximation for those codes.
+    ///
+    /// # Returns
+    /// The converted `RGB` color.
+    #[must_use]
+    pub fn to_rgb(&self) -> RGB {
+        match self.code {
+            0..=15 => {
+                let (r, g, b) = SYSTEM_COLORS[self.code as usize];
+                RGB::new(r, g, b)
+            }
+            16..=231 => {
+                let index = self.code - 16;
+                let level = |value: u8| CUBE_LEVELS[value as usize];
+                RGB::new(level(index / 36), level(index / 6 % 6), level(index % 6))
+            }
+            232..=255 => {
+                let value = 8 + 10 * (self.code - 232);
+                RGB::new(value, value, value)
+            }
+        }
+    }
 }
 
 impl Display for Ansi256 {
@@ -151,6 +199,61 @@ mod tests {
         assert_eq!(actual.code(), expected);
     }
 
+    #[rstest]
+    #[case::system_black(0, (0, 0, 0))]
+    #[case::system_red(1, (128, 0, 0))]
+    #[case::system_bright_white(15, (255, 255, 255))]
+    #[case::cube_black(16, (0, 0, 0))]
+    #[case::cube_blue(21, (0, 0, 255))]
+    #[case::cube_green(78, (95, 215, 135))]
+    #[case::cube_red(196, (255, 0, 0))]
+    #[case::cube_white(231, (255, 255, 255))]
+    #[case::gray_darkest(232, (8, 8, 8))]
+    #[case::gray(244, (128, 128, 128))]
+    #[case::gray_lightest(255, (238, 238, 238))]
+    fn test_to_rgb(#[case] code: u8, #[case] expected: (u8, u8, u8)) {
+        // Act
+        let actual = Ansi256::new(code).to_rgb();
+
+        // Assert
+        assert_eq!(actual, RGB::new(expected.0, expected.1, expected.2));
+    }
+
+    #[rstest]
+    #[case::black(16)]
+    #[case::white(231)]
+    #[case::red(196)]
+    #[case::green(46)]
+    #[case::blue(21)]
+    #[case::yellow(226)]
+    #[case::cyan(51)]
+    #[case::magenta(201)]
+    fn test_to_rgb_round_trip(#[case] code: u8) {
+        // Act
+        let rgb = Ansi256::new(code).to_rgb();
+        let actual = Ansi256::from(&rgb);
+
+        // Assert
+        assert_eq!(actual.code(), code);
+    }
+
+    #[test]
+    fn test_to_rgb_approximation_error() {
+        // Arrange
+        let rgb = RGB::new(30, 215, 96);
+
+        // Act
+        let actual = Ansi256::from(&rgb).to_rgb();
+
+        // Assert
+        let error = [(actual.r, rgb.r), (actual.g, rgb.g), (actual.b, rgb.b)]
+            .iter()
+            .map(|&(actual, expected)| actual.abs_diff(expected))
+            .max()
+            .unwrap();
+        assert!(error <= 70);
+    }
+
     #[rstest]
     #[case::black(Ansi16::black(), 0)]
     #[case::red(Ansi16::red(), 1)]
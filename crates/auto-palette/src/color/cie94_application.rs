@@ -0,0 +1,60 @@
+use crate::math::FloatNumber;
+
+/// The application of the CIE94 color difference formula.
+///
+/// Each application selects the weighting constants of the formula.
+///
+/// # Examples
+/// ```
+/// use std::str::FromStr;
+///
+/// use auto_palette::color::{Cie94Application, Color};
+///
+/// let color1: Color<f32> = Color::from_str("#EE334E").unwrap();
+/// let color2: Color<f32> = Color::from_str("#E0344E").unwrap();
+/// let delta_e = color1.delta_e_94(&color2, Cie94Application::Textiles);
+/// assert!(delta_e < color1.delta_e(&color2));
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Cie94Application {
+    /// The graphic arts application. (k_L = 1, K_1 = 0.045, K_2 = 0.015)
+    #[default]
+    GraphicArts,
+    /// The textiles application. (k_L = 2, K_1 = 0.048, K_2 = 0.014)
+    Textiles,
+}
+
+impl Cie94Application {
+    /// Returns the weighting constants of this application.
+    ///
+    /// # Returns
+    /// The tuple of the k_L, K_1, and K_2 constants.
+    #[must_use]
+    pub(crate) fn constants<T>(&self) -> (T, T, T)
+    where
+        T: FloatNumber,
+    {
+        match self {
+            Self::GraphicArts => (T::one(), T::from_f32(0.045), T::from_f32(0.015)),
+            Self::Textiles => (T::from_u32(2), T::from_f32(0.048), T::from_f32(0.014)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case::graphic_arts(Cie94Application::GraphicArts, (1.0, 0.045, 0.015))]
+    #[case::textiles(Cie94Application::Textiles, (2.0, 0.048, 0.014))]
+    fn test_constants(#[case] application: Cie94Application, #[case] expected: (f32, f32, f32)) {
+        // Act
+        let actual: (f32, f32, f32) = application.constants();
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+}
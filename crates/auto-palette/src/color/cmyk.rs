@@ -27,6 +27,7 @@ use crate::{color::RGB, FloatNumber};
 /// assert_eq!(format!("{}", cmyk), "CMYK(0.00, 0.00, 1.00, 0.00)");
 /// ```
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CMYK<T>
 where
     T: FloatNumber,
@@ -67,10 +68,11 @@ where
     T: FloatNumber,
 {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let precision = f.precision().unwrap_or(2);
         write!(
             f,
-            "CMYK({:.2}, {:.2}, {:.2}, {:.2})",
-            self.c, self.m, self.y, self.k
+            "CMYK({:.*}, {:.*}, {:.*}, {:.*})",
+            precision, self.c, precision, self.m, precision, self.y, precision, self.k
         )
     }
 }
@@ -0,0 +1,69 @@
+/// The color space used to select the components of a color.
+///
+/// # Examples
+/// ```
+/// use std::str::FromStr;
+///
+/// use auto_palette::color::{Color, ColorSpace};
+///
+/// let color: Color<f32> = Color::from_str("#2C7DE7").unwrap();
+/// assert_eq!(color.to_components(ColorSpace::Rgb), [44.0, 125.0, 231.0]);
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum ColorSpace {
+    /// The sRGB color space with components in the range [0, 255].
+    Rgb,
+    /// The CMYK color space with four components in the range [0, 1].
+    Cmyk,
+    /// The CIE XYZ color space.
+    Xyz,
+    /// The CIE L*a*b* color space.
+    #[default]
+    Lab,
+    /// The CIE LCh(ab) color space with the hue in degrees.
+    Lch,
+    /// The HSL color space with the hue in degrees.
+    Hsl,
+    /// The HSV color space with the hue in degrees.
+    Hsv,
+    /// The HWB color space with the hue in degrees.
+    Hwb,
+    /// The Oklab color space.
+    Oklab,
+    /// The Oklch color space with the hue in degrees.
+    Oklch,
+}
+
+impl ColorSpace {
+    /// Returns the number of components of this color space.
+    ///
+    /// # Returns
+    /// The number of components, which is 4 for CMYK and 3 for the other color spaces.
+    #[must_use]
+    pub fn num_components(&self) -> usize {
+        match self {
+            ColorSpace::Cmyk => 4,
+            _ => 3,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case::rgb(ColorSpace::Rgb, 3)]
+    #[case::cmyk(ColorSpace::Cmyk, 4)]
+    #[case::lab(ColorSpace::Lab, 3)]
+    #[case::oklch(ColorSpace::Oklch, 3)]
+    fn test_num_components(#[case] space: ColorSpace, #[case] expected: usize) {
+        // Act
+        let actual = space.num_components();
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+}
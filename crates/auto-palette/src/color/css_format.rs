@@ -0,0 +1,26 @@
+/// The format of a CSS color string.
+///
+/// # Examples
+/// ```
+/// use std::str::FromStr;
+///
+/// use auto_palette::color::{Color, CssFormat};
+///
+/// let color: Color<f32> = Color::from_str("#2C7DE7").unwrap();
+/// assert_eq!(color.to_css_string(CssFormat::Rgb), "rgb(44, 125, 231)");
+/// assert_eq!(color.to_css_string(CssFormat::Hsl), "hsl(214, 80%, 54%)");
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum CssFormat {
+    /// The hexadecimal notation, e.g. `#2C7DE7`.
+    #[default]
+    Hex,
+    /// The hexadecimal notation with the given alpha value, e.g. `#2C7DE780`.
+    HexAlpha(u8),
+    /// The `rgb()` function with integer components, e.g. `rgb(44, 125, 231)`.
+    Rgb,
+    /// The `rgb()` function with percentage components, e.g. `rgb(17.3%, 49.0%, 90.6%)`.
+    RgbPercent,
+    /// The `hsl()` function with an integer hue and percentage saturation and lightness, e.g. `hsl(214, 80%, 54%)`.
+    Hsl,
+}
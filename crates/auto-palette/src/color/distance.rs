@@ -0,0 +1,102 @@
+use crate::{
+    color::Color,
+    math::{DistanceMetric, FloatNumber},
+};
+
+/// Computes the pairwise distances between the given colors in the CIE L*a*b* color space.
+///
+/// # Type Parameters
+/// * `T` - The floating point type.
+///
+/// # Arguments
+/// * `colors` - The colors to measure.
+/// * `metric` - The distance metric to use.
+///
+/// # Returns
+/// The symmetric matrix where the element at `[i][j]` is the distance between `colors[i]` and `colors[j]`.
+///
+/// # Examples
+/// ```
+/// use std::str::FromStr;
+///
+/// use auto_palette::{
+///     color::{distance_matrix, Color},
+///     DistanceMetric,
+/// };
+///
+/// let colors: Vec<Color<f32>> = vec![
+///     Color::from_str("#FF0000").unwrap(),
+///     Color::from_str("#00FF00").unwrap(),
+/// ];
+/// let matrix = distance_matrix(&colors, DistanceMetric::Euclidean);
+/// assert_eq!(matrix[0][0], 0.0);
+/// assert_eq!(matrix[0][1], matrix[1][0]);
+/// ```
+#[must_use]
+pub fn distance_matrix<T>(colors: &[Color<T>], metric: DistanceMetric) -> Vec<Vec<T>>
+where
+    T: FloatNumber,
+{
+    let points: Vec<[T; 3]> = colors
+        .iter()
+        .map(|color| [color.l, color.a, color.b])
+        .collect();
+    let mut matrix = vec![vec![T::zero(); points.len()]; points.len()];
+    for i in 0..points.len() {
+        for j in (i + 1)..points.len() {
+            let distance = metric.measure(&points[i], &points[j]);
+            matrix[i][j] = distance;
+            matrix[j][i] = distance;
+        }
+    }
+    matrix
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case::euclidean(DistanceMetric::Euclidean)]
+    #[case::squared_euclidean(DistanceMetric::SquaredEuclidean)]
+    fn test_distance_matrix(#[case] metric: DistanceMetric) {
+        // Arrange
+        let colors: Vec<Color<f32>> = ["#EE334E", "#0081C8", "#FCB131"]
+            .iter()
+            .map(|hex| Color::from_str(hex).unwrap())
+            .collect();
+
+        // Act
+        let actual = distance_matrix(&colors, metric.clone());
+
+        // Assert
+        assert_eq!(actual.len(), 3);
+        for i in 0..3 {
+            assert_eq!(actual[i].len(), 3);
+            assert_eq!(actual[i][i], 0.0);
+            for j in 0..3 {
+                assert_eq!(actual[i][j], actual[j][i]);
+                if i != j {
+                    let expected = match metric {
+                        DistanceMetric::Euclidean => colors[i].delta_e(&colors[j]),
+                        DistanceMetric::SquaredEuclidean => colors[i].delta_e(&colors[j]).powi(2),
+                    };
+                    assert!((actual[i][j] - expected).abs() < 1e-2);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_distance_matrix_empty() {
+        // Act
+        let actual = distance_matrix::<f32>(&[], DistanceMetric::Euclidean);
+
+        // Assert
+        assert!(actual.is_empty());
+    }
+}
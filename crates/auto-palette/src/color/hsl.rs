@@ -29,6 +29,7 @@ use crate::{
 /// assert_eq!(format!("{}", hsl), "HSL(60.00, 1.00, 0.50)");
 /// ```
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct HSL<T>
 where
     T: FloatNumber,
@@ -66,7 +67,12 @@ where
     T: FloatNumber,
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "HSL({:.2}, {:.2}, {:.2})", self.h, self.s, self.l)
+        let precision = f.precision().unwrap_or(2);
+        write!(
+            f,
+            "HSL({:.*}, {:.*}, {:.*})",
+            precision, self.h, precision, self.s, precision, self.l
+        )
     }
 }
 
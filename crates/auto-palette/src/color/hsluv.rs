@@ -0,0 +1,272 @@
+use std::fmt::Display;
+
+use num_traits::clamp;
+
+use crate::{
+    color::{hue::Hue, LCHuv},
+    math::FloatNumber,
+};
+
+/// The inverse sRGB transformation matrix used to find the chroma bounds.
+const M: [[f64; 3]; 3] = [
+    [
+        3.240_969_941_904_521,
+        -1.537_383_177_570_093,
+        -0.498_610_760_293,
+    ],
+    [
+        -0.969_243_636_280_87,
+        1.875_967_501_507_72,
+        0.041_555_057_407_175,
+    ],
+    [
+        0.055_630_079_696_993,
+        -0.203_976_958_888_97,
+        1.056_971_514_242_878,
+    ],
+];
+
+/// The actual CIE standard constant kappa.
+const KAPPA: f64 = 903.296_296_296_296_3;
+
+/// The actual CIE standard constant epsilon.
+const EPSILON: f64 = 0.008_856_451_679_035_631;
+
+/// The HSLuv color representation.
+///
+/// HSLuv is a human-friendly alternative to HSL based on the CIE LCH(uv) color space.
+/// The saturation is relative to the maximum chroma within the sRGB gamut for the given lightness and hue.
+///
+/// See the following for more details:
+/// [HSLuv - Human-friendly HSL](https://www.hsluv.org/)
+///
+/// # Type Parameters
+/// * `T` - The floating point type.
+///
+/// # Fields
+/// * `h` - The hue component.
+/// * `s` - The saturation component.
+/// * `l` - The lightness component.
+///
+/// # Examples
+/// ```
+/// use auto_palette::color::{HSLuv, LCHuv};
+///
+/// let lchuv: LCHuv<f32> = LCHuv::new(53.237, 179.041, 12.177);
+/// let hsluv = HSLuv::<f32>::from(&lchuv);
+/// assert_eq!(format!("{}", hsluv), "HSLuv(12.18, 100.00, 53.24)");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HSLuv<T>
+where
+    T: FloatNumber,
+{
+    pub h: Hue<T>,
+    pub s: T,
+    pub l: T,
+}
+
+impl<T> HSLuv<T>
+where
+    T: FloatNumber,
+{
+    /// Creates a new `HSLuv` instance.
+    ///
+    /// # Arguments
+    /// * `h` - The hue component.
+    /// * `s` - The saturation component.
+    /// * `l` - The lightness component.
+    ///
+    /// # Returns
+    /// A new `HSLuv` instance.
+    #[must_use]
+    pub fn new(h: T, s: T, l: T) -> Self {
+        Self {
+            h: Hue::from_degrees(h),
+            s: clamp(s, T::zero(), T::from_u32(100)),
+            l: clamp(l, T::zero(), T::from_u32(100)),
+        }
+    }
+}
+
+impl<T> Display for HSLuv<T>
+where
+    T: FloatNumber,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let precision = f.precision().unwrap_or(2);
+        write!(
+            f,
+            "HSLuv({:.*}, {:.*}, {:.*})",
+            precision, self.h, precision, self.s, precision, self.l
+        )
+    }
+}
+
+impl<T> From<&LCHuv<T>> for HSLuv<T>
+where
+    T: FloatNumber,
+{
+    fn from(lchuv: &LCHuv<T>) -> Self {
+        // This implementation is based on the reference implementation from the following link:
+        // https://github.com/hsluv/hsluv
+        let h = lchuv.h.to_degrees();
+        if lchuv.l > T::from_f64(99.999_999_9) {
+            return Self::new(h, T::zero(), T::from_u32(100));
+        }
+        if lchuv.l < T::from_f64(1e-8) {
+            return Self::new(h, T::zero(), T::zero());
+        }
+
+        let max_chroma = max_chroma_for(lchuv.l, lchuv.h.to_radians());
+        let s = lchuv.c / max_chroma * T::from_u32(100);
+        Self::new(h, s, lchuv.l)
+    }
+}
+
+impl<T> From<&HSLuv<T>> for LCHuv<T>
+where
+    T: FloatNumber,
+{
+    fn from(hsluv: &HSLuv<T>) -> Self {
+        let h = hsluv.h.to_degrees();
+        if hsluv.l > T::from_f64(99.999_999_9) {
+            return LCHuv::new(T::from_u32(100), T::zero(), h);
+        }
+        if hsluv.l < T::from_f64(1e-8) {
+            return LCHuv::new(T::zero(), T::zero(), h);
+        }
+
+        let max_chroma = max_chroma_for(hsluv.l, hsluv.h.to_radians());
+        let c = max_chroma / T::from_u32(100) * hsluv.s;
+        LCHuv::new(hsluv.l, c, h)
+    }
+}
+
+/// Returns the maximum chroma within the sRGB gamut for the given lightness and hue.
+///
+/// # Arguments
+/// * `l` - The lightness component.
+/// * `h` - The hue component in radians.
+///
+/// # Returns
+/// The maximum chroma.
+#[inline]
+#[must_use]
+fn max_chroma_for<T>(l: T, h: T) -> T
+where
+    T: FloatNumber,
+{
+    let sub1 = (l + T::from_u32(16)).powi(3) / T::from_u32(1_560_896);
+    let sub2 = if sub1 > T::from_f64(EPSILON) {
+        sub1
+    } else {
+        l / T::from_f64(KAPPA)
+    };
+
+    let (sin, cos) = h.sin_cos();
+    let mut min_length = T::infinity();
+    for row in &M {
+        let m1 = T::from_f64(row[0]);
+        let m2 = T::from_f64(row[1]);
+        let m3 = T::from_f64(row[2]);
+        for t in 0..2 {
+            let t = T::from_u32(t);
+            let top1 = (T::from_u32(284_517) * m1 - T::from_u32(94_839) * m3) * sub2;
+            let top2 =
+                (T::from_u32(838_422) * m3 + T::from_u32(769_860) * m2 + T::from_u32(731_718) * m1)
+                    * l
+                    * sub2
+                    - T::from_u32(769_860) * t * l;
+            let bottom = (T::from_u32(632_260) * m3 - T::from_u32(126_452) * m2) * sub2
+                + T::from_u32(126_452) * t;
+
+            let slope = top1 / bottom;
+            let intercept = top2 / bottom;
+            let length = intercept / (sin - slope * cos);
+            if length >= T::zero() && length < min_length {
+                min_length = length;
+            }
+        }
+    }
+    min_length
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[test]
+    fn test_new() {
+        // Act
+        let actual: HSLuv<f32> = HSLuv::new(12.177, 100.0, 53.237);
+
+        // Assert
+        assert_eq!(
+            actual,
+            HSLuv {
+                h: Hue::from_degrees(12.177),
+                s: 100.0,
+                l: 53.237,
+            }
+        );
+    }
+
+    #[rstest]
+    #[case::over((360.0, 120.0, 120.0), (0.0, 100.0, 100.0))]
+    #[case::under((-90.0, -10.0, -10.0), (270.0, 0.0, 0.0))]
+    fn test_new_with_out_of_range(
+        #[case] input: (f32, f32, f32),
+        #[case] expected: (f32, f32, f32),
+    ) {
+        // Act
+        let actual = HSLuv::new(input.0, input.1, input.2);
+
+        // Assert
+        assert_eq!(actual, HSLuv::new(expected.0, expected.1, expected.2));
+    }
+
+    #[test]
+    fn test_fmt() {
+        // Act
+        let hsluv: HSLuv<f32> = HSLuv::new(12.177, 100.0, 53.237);
+        let actual = format!("{}", hsluv);
+
+        // Assert
+        assert_eq!(actual, "HSLuv(12.18, 100.00, 53.24)");
+    }
+
+    #[rstest]
+    #[case::black((0.0, 0.0, 0.0), (0.0, 0.0, 0.0))]
+    #[case::white((100.0, 0.0, 0.0), (0.0, 0.0, 100.0))]
+    #[case::red((53.237, 179.041, 12.177), (12.177, 100.0, 53.237))]
+    #[case::blue((32.301, 130.689, 265.874), (265.874, 100.0, 32.301))]
+    fn test_from_lchuv(#[case] input: (f64, f64, f64), #[case] expected: (f64, f64, f64)) {
+        // Act
+        let lchuv: LCHuv<f64> = LCHuv::new(input.0, input.1, input.2);
+        let actual = HSLuv::from(&lchuv);
+
+        // Assert
+        assert!((actual.h.to_degrees() - expected.0).abs() < 1e-3);
+        assert!((actual.s - expected.1).abs() < 1e-2);
+        assert!((actual.l - expected.2).abs() < 1e-3);
+    }
+
+    #[rstest]
+    #[case::black((0.0, 0.0, 0.0), (0.0, 0.0, 0.0))]
+    #[case::white((0.0, 0.0, 100.0), (100.0, 0.0, 0.0))]
+    #[case::red((12.177, 100.0, 53.237), (53.237, 179.041, 12.177))]
+    #[case::blue((265.874, 100.0, 32.301), (32.301, 130.689, 265.874))]
+    fn test_to_lchuv(#[case] input: (f64, f64, f64), #[case] expected: (f64, f64, f64)) {
+        // Act
+        let hsluv: HSLuv<f64> = HSLuv::new(input.0, input.1, input.2);
+        let actual = LCHuv::from(&hsluv);
+
+        // Assert
+        assert!((actual.l - expected.0).abs() < 1e-3);
+        assert!((actual.c - expected.1).abs() < 1e-2);
+        assert!((actual.h.to_degrees() - expected.2).abs() < 1e-3);
+    }
+}
@@ -19,6 +19,7 @@ use crate::math::FloatNumber;
 /// assert_eq!(format!("{}", hue), "240.00");
 /// ```
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Hue<T>(T)
 where
     T: FloatNumber;
@@ -75,7 +76,8 @@ where
     T: FloatNumber,
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{:.2}", self.0)
+        let precision = f.precision().unwrap_or(2);
+        write!(f, "{:.*}", precision, self.0)
     }
 }
 
@@ -157,4 +159,14 @@ mod tests {
         // Assert
         assert_eq!(actual, "45.00");
     }
+
+    #[test]
+    fn test_fmt_with_precision() {
+        // Act
+        let degree = Hue::from_degrees(45.125);
+        let actual = format!("{:.3}", degree);
+
+        // Assert
+        assert_eq!(actual, "45.125");
+    }
 }
@@ -0,0 +1,178 @@
+use std::fmt::Display;
+
+use num_traits::clamp;
+
+use crate::{
+    color::{hue::Hue, RGB},
+    math::FloatNumber,
+};
+
+/// The HWB color representation.
+///
+/// See the following for more details:
+/// [HWB color model - Wikipedia](https://en.wikipedia.org/wiki/HWB_color_model)
+///
+/// # Type Parameters
+/// * `T` - The floating point type.
+///
+/// # Fields
+/// * `h` - The hue component.
+/// * `w` - The whiteness component.
+/// * `b` - The blackness component.
+///
+/// # Examples
+/// ```
+/// use auto_palette::color::{HWB, RGB};
+///
+/// let rgb = RGB::new(255, 255, 0);
+/// let hwb = HWB::<f32>::from(&rgb);
+/// assert_eq!(format!("{}", hwb), "HWB(60.00, 0.00, 0.00)");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HWB<T>
+where
+    T: FloatNumber,
+{
+    pub h: Hue<T>,
+    pub w: T,
+    pub b: T,
+}
+
+impl<T> HWB<T>
+where
+    T: FloatNumber,
+{
+    /// Creates a new `HWB` instance.
+    ///
+    /// # Arguments
+    /// * `h` - The hue component.
+    /// * `w` - The whiteness component.
+    /// * `b` - The blackness component.
+    ///
+    /// # Returns
+    /// A new `HWB` instance.
+    #[must_use]
+    pub fn new(h: T, w: T, b: T) -> Self {
+        Self {
+            h: Hue::from_degrees(h),
+            w: clamp(w, T::zero(), T::one()),
+            b: clamp(b, T::zero(), T::one()),
+        }
+    }
+}
+
+impl<T> Display for HWB<T>
+where
+    T: FloatNumber,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let precision = f.precision().unwrap_or(2);
+        write!(
+            f,
+            "HWB({:.*}, {:.*}, {:.*})",
+            precision, self.h, precision, self.w, precision, self.b
+        )
+    }
+}
+
+impl<T> From<&RGB> for HWB<T>
+where
+    T: FloatNumber,
+{
+    fn from(rgb: &RGB) -> Self {
+        let max = RGB::max_value::<T>();
+        let r = T::from_u8(rgb.r) / max;
+        let g = T::from_u8(rgb.g) / max;
+        let b = T::from_u8(rgb.b) / max;
+
+        let max = r.max(g).max(b);
+        let min = r.min(g).min(b);
+
+        let delta = max - min;
+
+        let h = if delta == T::zero() {
+            T::zero()
+        } else if max == r {
+            T::from_f32(60.0) * (((g - b) / delta) % T::from_f32(6.0))
+        } else if max == g {
+            T::from_f32(60.0) * (((b - r) / delta) + T::from_f32(2.0))
+        } else {
+            T::from_f32(60.0) * (((r - g) / delta) + T::from_f32(4.0))
+        };
+
+        let w = min;
+        let b = T::one() - max;
+
+        Self::new(h, w, b)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[test]
+    fn test_new() {
+        // Act
+        let actual = HWB::new(60.0, 0.25, 0.5);
+
+        // Assert
+        assert_eq!(
+            actual,
+            HWB {
+                h: Hue::from_degrees(60.0),
+                w: 0.25,
+                b: 0.5
+            }
+        )
+    }
+
+    #[rstest]
+    #[case((400.0, 2.0, 2.0), (40.0, 1.0, 1.0))]
+    #[case((-40.0, -1.0, -1.0), (320.0, 0.0, 0.0))]
+    fn test_new_with_out_of_range_values(
+        #[case] input: (f32, f32, f32),
+        #[case] expected: (f32, f32, f32),
+    ) {
+        // Act
+        let (h, w, b) = input;
+        let actual = HWB::new(h, w, b);
+
+        // Assert
+        let (h, w, b) = expected;
+        assert_eq!(actual, HWB::new(h, w, b));
+    }
+
+    #[test]
+    fn test_fmt() {
+        // Act
+        let hwb = HWB::new(60.0, 0.25, 0.5);
+        let actual = format!("{}", hwb);
+
+        // Assert
+        assert_eq!(actual, "HWB(60.00, 0.25, 0.50)");
+    }
+
+    #[rstest]
+    #[case::black((0, 0, 0), (0.0, 0.0, 1.0))]
+    #[case::white((255, 255, 255), (0.0, 1.0, 0.0))]
+    #[case::gray((51, 51, 51), (0.0, 0.2, 0.8))]
+    #[case::red((255, 0, 0), (0.0, 0.0, 0.0))]
+    #[case::green((0, 255, 0), (120.0, 0.0, 0.0))]
+    #[case::blue((0, 0, 255), (240.0, 0.0, 0.0))]
+    #[case::pink((255, 102, 153), (340.0, 0.4, 0.0))]
+    #[case::navy((0, 0, 128), (240.0, 0.0, 0.498_039_2))]
+    fn test_from_rgb(#[case] rgb: (u8, u8, u8), #[case] hwb: (f32, f32, f32)) {
+        // Act
+        let rgb = RGB::new(rgb.0, rgb.1, rgb.2);
+        let actual = HWB::<f32>::from(&rgb);
+
+        // Assert
+        assert!((actual.h.to_degrees() - hwb.0).abs() < 1e-3);
+        assert!((actual.w - hwb.1).abs() < 1e-6);
+        assert!((actual.b - hwb.2).abs() < 1e-6);
+    }
+}
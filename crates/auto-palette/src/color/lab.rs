@@ -33,6 +33,7 @@ use crate::{
 /// assert_eq!(format!("{}", lchab), "LCH(ab)(87.74, 119.78, 136.02)");
 /// ```
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Lab<T, W = D65>
 where
     T: FloatNumber,
@@ -41,6 +42,7 @@ where
     pub l: T,
     pub a: T,
     pub b: T,
+    #[cfg_attr(feature = "serde", serde(skip))]
     _marker: PhantomData<W>,
 }
 
@@ -152,7 +154,12 @@ where
     T: FloatNumber,
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "Lab({:.2}, {:.2}, {:.2})", self.l, self.a, self.b)
+        let precision = f.precision().unwrap_or(2);
+        write!(
+            f,
+            "Lab({:.*}, {:.*}, {:.*})",
+            precision, self.l, precision, self.a, precision, self.b
+        )
     }
 }
 
@@ -258,6 +265,16 @@ mod tests {
         assert_eq!(actual, "Lab(53.24, 80.09, 67.24)");
     }
 
+    #[test]
+    fn test_fmt_with_precision() {
+        // Act
+        let lab = Lab::<_>::new(53.2437, 80.09315, 67.2388);
+        let actual = format!("{:.0}", lab);
+
+        // Assert
+        assert_eq!(actual, "Lab(53, 80, 67)");
+    }
+
     #[test]
     fn test_from_xyz() {
         // Act
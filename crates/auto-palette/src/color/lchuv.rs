@@ -74,11 +74,15 @@ where
     W: WhitePoint,
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let precision = f.precision().unwrap_or(2);
         write!(
             f,
-            "LCH(uv)({:.2}, {:.2}, {:.2})",
+            "LCH(uv)({:.*}, {:.*}, {:.*})",
+            precision,
             self.l,
+            precision,
             self.c,
+            precision,
             self.h.to_degrees()
         )
     }
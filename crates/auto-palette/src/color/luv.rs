@@ -80,7 +80,12 @@ where
     W: WhitePoint,
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "Luv({:.2}, {:.2}, {:.2})", self.l, self.u, self.v)
+        let precision = f.precision().unwrap_or(2);
+        write!(
+            f,
+            "Luv({:.*}, {:.*}, {:.*})",
+            precision, self.l, precision, self.u, precision, self.v
+        )
     }
 }
 
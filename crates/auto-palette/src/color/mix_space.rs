@@ -0,0 +1,31 @@
+/// The color space used to interpolate between colors.
+///
+/// # Examples
+/// ```
+/// use std::str::FromStr;
+///
+/// use auto_palette::color::{Color, MixSpace};
+///
+/// let red: Color<f32> = Color::from_str("#FF0000").unwrap();
+/// let blue: Color<f32> = Color::from_str("#0000FF").unwrap();
+/// let ramp = red.ramp_to(&blue, 5, MixSpace::Oklab);
+/// assert_eq!(ramp.len(), 5);
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum MixSpace {
+    /// The CIE L*a*b* color space.
+    #[default]
+    Lab,
+    /// The Oklab color space.
+    Oklab,
+    /// The CIE L*u*v* color space.
+    Luv,
+    /// The gamma-encoded sRGB color space.
+    ///
+    /// The midpoint of two saturated colors is often darker than either of them.
+    Srgb,
+    /// The linear-light sRGB color space.
+    ///
+    /// This avoids the dark band between complementary colors in the gamma-encoded sRGB space.
+    LinearRgb,
+}
@@ -1,16 +1,26 @@
 mod ansi16;
 mod ansi256;
+mod cie94_application;
 mod cmyk;
+mod color_space;
+mod css_format;
+mod distance;
 mod hsl;
+mod hsluv;
 mod hsv;
 mod hue;
+mod hwb;
 mod lab;
 mod lchab;
 mod lchuv;
 mod luv;
+mod mix_space;
+mod named;
 mod oklab;
 mod oklch;
 mod rgb;
+mod transfer;
+mod wcag_level;
 mod white_point;
 mod xyz;
 
@@ -23,23 +33,44 @@ use std::{
 
 pub use ansi16::Ansi16;
 pub use ansi256::Ansi256;
+pub use cie94_application::Cie94Application;
 pub use cmyk::CMYK;
+pub use color_space::ColorSpace;
+pub use css_format::CssFormat;
+pub use distance::distance_matrix;
 pub use hsl::HSL;
+pub use hsluv::HSLuv;
 pub use hsv::HSV;
 pub use hue::Hue;
+pub use hwb::HWB;
 pub(crate) use lab::xyz_to_lab;
 pub use lab::Lab;
 pub use lchab::LCHab;
 pub use lchuv::LCHuv;
 pub use luv::Luv;
+pub use mix_space::MixSpace;
+use num_traits::clamp;
 pub use oklab::Oklab;
 pub use oklch::Oklch;
+use rgb::xyz_to_linear_rgb;
 pub use rgb::RGB;
+pub use transfer::{linear_to_srgb, srgb_to_linear};
+pub use wcag_level::WcagLevel;
 pub use white_point::*;
 pub(crate) use xyz::rgb_to_xyz;
 pub use xyz::XYZ;
+use xyz::{lab_to_xyz_unclamped, linear_rgb_to_xyz};
 
-use crate::math::FloatNumber;
+use crate::{math::FloatNumber, Error};
+
+/// The number of binary search iterations to find the lightness meeting a contrast ratio.
+const CONTRAST_SEARCH_ITERATIONS: usize = 24;
+
+/// The number of binary search iterations to find the largest chroma within the sRGB gamut.
+const GAMUT_SEARCH_ITERATIONS: usize = 24;
+
+/// The tolerance of the linear sRGB components for a color to be considered within the sRGB gamut.
+const GAMUT_EPSILON: f32 = 1e-3;
 
 /// The color representation.
 ///
@@ -69,6 +100,7 @@ use crate::math::FloatNumber;
 /// assert_eq!(format!("{}", lab), "Lab(52.92, 13.59, -60.47)");
 /// ```
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Color<T, W = D65>
 where
     T: FloatNumber,
@@ -76,6 +108,12 @@ where
     pub(super) l: T,
     pub(super) a: T,
     pub(super) b: T,
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    alpha: Option<T>,
+    #[cfg_attr(feature = "serde", serde(skip))]
     _marker: PhantomData<W>,
 }
 
@@ -99,10 +137,37 @@ where
             l,
             a,
             b,
+            alpha: None,
             _marker: PhantomData,
         }
     }
 
+    /// Returns the alpha component of this color.
+    ///
+    /// # Returns
+    /// The alpha component in the range [0, 1], or `None` if this color has no alpha component.
+    #[must_use]
+    pub fn alpha(&self) -> Option<T> {
+        self.alpha
+    }
+
+    /// Returns a copy of this color with the given alpha component.
+    ///
+    /// The alpha component is not carried over by the methods that derive a new color, such as [`Color::mix`].
+    ///
+    /// # Arguments
+    /// * `alpha` - The alpha component in the range [0, 1].
+    ///
+    /// # Returns
+    /// A new `Color` instance with the alpha component.
+    #[must_use]
+    pub fn with_alpha(self, alpha: T) -> Self {
+        Self {
+            alpha: Some(clamp(alpha, T::zero(), T::one())),
+            ..self
+        }
+    }
+
     /// Returns the minimum value of the chroma component.
     ///
     /// # Returns
@@ -143,6 +208,81 @@ where
         T::from_f32(100.0)
     }
 
+    /// Creates a new `Color` instance from the given CIE LCH(ab) color.
+    ///
+    /// # Arguments
+    /// * `lchab` - The CIE LCH(ab) color.
+    ///
+    /// # Returns
+    /// A new `Color` instance.
+    #[must_use]
+    pub(crate) fn from_lchab(lchab: &LCHab<T, W>) -> Self {
+        let lab = Lab::<T, W>::from(lchab);
+        Self::new(lab.l, lab.a, lab.b)
+    }
+
+    /// Creates a new `Color` instance from the given CIE L*a*b* components.
+    ///
+    /// The components are clamped to the valid range of the CIE L*a*b* color space.
+    ///
+    /// # Arguments
+    /// * `l` - The L component in the range [0, 100].
+    /// * `a` - The a component in the range [-128, 127].
+    /// * `b` - The b component in the range [-128, 127].
+    ///
+    /// # Returns
+    /// A new `Color` instance.
+    ///
+    /// # Examples
+    /// ```
+    /// use auto_palette::color::Color;
+    ///
+    /// let color: Color<f32> = Color::from_lab(120.0, 20.0, -200.0);
+    /// assert_eq!(color.lightness(), 100.0);
+    /// assert_eq!(color.to_lab().b, -128.0);
+    /// ```
+    #[must_use]
+    pub fn from_lab(l: T, a: T, b: T) -> Self {
+        let lab = Lab::<T, W>::new(l, a, b);
+        Self::new(lab.l, lab.a, lab.b)
+    }
+
+    /// Creates a new `Color` instance from the given CIE L*a*b* components, validating the L component.
+    ///
+    /// The a and b components are clamped to the valid range as in [`Color::from_lab`].
+    ///
+    /// # Arguments
+    /// * `l` - The L component in the range [0, 100].
+    /// * `a` - The a component in the range [-128, 127].
+    /// * `b` - The b component in the range [-128, 127].
+    ///
+    /// # Returns
+    /// A new `Color` instance.
+    ///
+    /// # Errors
+    /// Returns an error if the L component is not in the range [0, 100].
+    pub fn from_lab_checked(l: T, a: T, b: T) -> Result<Self, Error> {
+        if !(l >= Lab::<T, W>::min_l() && l <= Lab::<T, W>::max_l()) {
+            return Err(Error::InvalidColor {
+                details: format!("The L component {} is out of range [0, 100].", l),
+            });
+        }
+        Ok(Self::from_lab(l, a, b))
+    }
+
+    /// Creates a new `Color` instance from the given CIE XYZ color.
+    ///
+    /// # Arguments
+    /// * `xyz` - The CIE XYZ color.
+    ///
+    /// # Returns
+    /// A new `Color` instance.
+    #[must_use]
+    pub(crate) fn from_xyz(xyz: &XYZ<T>) -> Self {
+        let lab = Lab::<T, W>::from(xyz);
+        Self::new(lab.l, lab.a, lab.b)
+    }
+
     /// Returns whether this color is light.
     ///
     /// # Returns
@@ -189,281 +329,2776 @@ where
         Hue::from_degrees(degrees)
     }
 
-    /// Converts this color to a hexadecimal string.
+    /// Calculates the color difference between this color and the given color using the CIE76 formula.
+    ///
+    /// # Arguments
+    /// * `other` - The other color.
     ///
     /// # Returns
-    /// The hexadecimal string representation of this color.
+    /// The Euclidean distance between the two colors in the CIE L*a*b* color space.
     #[must_use]
-    pub fn to_hex_string(&self) -> String {
-        let RGB { r, g, b } = self.to_rgb();
-        format!("#{:02X}{:02X}{:02X}", r, g, b)
+    pub fn delta_e(&self, other: &Self) -> T {
+        let delta_l = self.l - other.l;
+        let delta_a = self.a - other.a;
+        let delta_b = self.b - other.b;
+        (delta_l * delta_l + delta_a * delta_a + delta_b * delta_b).sqrt()
     }
 
-    /// Converts this color to the RGB color space.
+    /// Calculates the color difference between this color and the given color using the CIE94 formula.
+    ///
+    /// The formula is not symmetric; this color is used as the reference color.
+    ///
+    /// # Arguments
+    /// * `other` - The other color.
+    /// * `application` - The application which selects the weighting constants.
     ///
     /// # Returns
-    /// The converted `RGB` color.
+    /// The CIE94 color difference between the two colors.
     #[must_use]
-    pub fn to_rgb(&self) -> RGB {
-        let xyz = self.to_xyz();
-        RGB::from(&xyz)
+    pub fn delta_e_94(&self, other: &Self, application: Cie94Application) -> T {
+        let (k_l, k_1, k_2) = application.constants::<T>();
+        let c1 = self.chroma();
+        let c2 = other.chroma();
+        let delta_l = self.l - other.l;
+        let delta_c = c1 - c2;
+        let delta_a = self.a - other.a;
+        let delta_b = self.b - other.b;
+        let delta_h_squared =
+            (delta_a * delta_a + delta_b * delta_b - delta_c * delta_c).max(T::zero());
+
+        let s_c = T::one() + k_1 * c1;
+        let s_h = T::one() + k_2 * c1;
+        let term_l = delta_l / k_l;
+        let term_c = delta_c / s_c;
+        (term_l * term_l + term_c * term_c + delta_h_squared / (s_h * s_h)).sqrt()
     }
 
-    /// Converts this color to the CMYK color space.
+    /// Calculates the color difference between this color and the given color using the CIEDE2000 formula.
+    ///
+    /// See the following for more details:
+    /// [The CIEDE2000 Color-Difference Formula](https://hajim.rochester.edu/ece/sites/gsharma/ciede2000/)
+    ///
+    /// # Arguments
+    /// * `other` - The other color.
     ///
     /// # Returns
-    /// The converted `CMYK` color.
+    /// The CIEDE2000 color difference between the two colors.
     #[must_use]
-    pub fn to_cmyk(&self) -> CMYK<T> {
-        let rgb = self.to_rgb();
-        CMYK::from(&rgb)
+    pub fn delta_e_2000(&self, other: &Self) -> T {
+        // This implementation is based on the following paper:
+        // G. Sharma, W. Wu, and E. N. Dalal, "The CIEDE2000 color-difference formula: Implementation notes,
+        // supplementary test data, and mathematical observations", Color Research & Application, 2005.
+        let pow25_7 = T::from_u32(25).powi(7);
+        let c1 = self.chroma();
+        let c2 = other.chroma();
+        let c_bar = (c1 + c2) / T::from_u32(2);
+        let g = (T::one() - (c_bar.powi(7) / (c_bar.powi(7) + pow25_7)).sqrt()) / T::from_u32(2);
+
+        let a1 = (T::one() + g) * self.a;
+        let a2 = (T::one() + g) * other.a;
+        let c1 = (a1 * a1 + self.b * self.b).sqrt();
+        let c2 = (a2 * a2 + other.b * other.b).sqrt();
+        let hue = |a: T, b: T| -> T {
+            if a.is_zero() && b.is_zero() {
+                T::zero()
+            } else {
+                let degrees = b.atan2(a).to_degrees();
+                if degrees < T::zero() {
+                    degrees + T::from_u32(360)
+                } else {
+                    degrees
+                }
+            }
+        };
+        let h1 = hue(a1, self.b);
+        let h2 = hue(a2, other.b);
+
+        let delta_l = other.l - self.l;
+        let delta_c = c2 - c1;
+        let chroma_product = c1 * c2;
+        let delta_h = if chroma_product.is_zero() {
+            T::zero()
+        } else if (h2 - h1).abs() <= T::from_u32(180) {
+            h2 - h1
+        } else if h2 - h1 > T::from_u32(180) {
+            h2 - h1 - T::from_u32(360)
+        } else {
+            h2 - h1 + T::from_u32(360)
+        };
+        let delta_h =
+            T::from_u32(2) * chroma_product.sqrt() * (delta_h.to_radians() / T::from_u32(2)).sin();
+
+        let l_bar = (self.l + other.l) / T::from_u32(2);
+        let c_bar = (c1 + c2) / T::from_u32(2);
+        let h_bar = if chroma_product.is_zero() {
+            h1 + h2
+        } else if (h1 - h2).abs() <= T::from_u32(180) {
+            (h1 + h2) / T::from_u32(2)
+        } else if h1 + h2 < T::from_u32(360) {
+            (h1 + h2 + T::from_u32(360)) / T::from_u32(2)
+        } else {
+            (h1 + h2 - T::from_u32(360)) / T::from_u32(2)
+        };
+
+        let cos = |degrees: T| degrees.to_radians().cos();
+        let t = T::one() - T::from_f64(0.17) * cos(h_bar - T::from_u32(30))
+            + T::from_f64(0.24) * cos(T::from_u32(2) * h_bar)
+            + T::from_f64(0.32) * cos(T::from_u32(3) * h_bar + T::from_u32(6))
+            - T::from_f64(0.20) * cos(T::from_u32(4) * h_bar - T::from_u32(63));
+        let delta_theta =
+            T::from_u32(30) * (-((h_bar - T::from_u32(275)) / T::from_u32(25)).powi(2)).exp();
+        let r_c = T::from_u32(2) * (c_bar.powi(7) / (c_bar.powi(7) + pow25_7)).sqrt();
+        let l_bar_50 = (l_bar - T::from_u32(50)).powi(2);
+        let s_l = T::one() + T::from_f64(0.015) * l_bar_50 / (T::from_u32(20) + l_bar_50).sqrt();
+        let s_c = T::one() + T::from_f64(0.045) * c_bar;
+        let s_h = T::one() + T::from_f64(0.015) * c_bar * t;
+        let r_t = -(T::from_u32(2) * delta_theta).to_radians().sin() * r_c;
+
+        let term_l = delta_l / s_l;
+        let term_c = delta_c / s_c;
+        let term_h = delta_h / s_h;
+        (term_l * term_l + term_c * term_c + term_h * term_h + r_t * term_c * term_h).sqrt()
     }
 
-    /// Converts this color to the HSL color space.
+    /// Returns the relative luminance of this color as defined by WCAG 2.1.
+    ///
+    /// The relative luminance is computed from the linearized sRGB components, not from the L* component.
     ///
     /// # Returns
-    /// The converted `HSL` color.
+    /// The relative luminance in the range [0, 1].
     #[must_use]
-    pub fn to_hsl(&self) -> HSL<T> {
+    pub fn relative_luminance(&self) -> T {
         let rgb = self.to_rgb();
-        HSL::from(&rgb)
+        let (_, y, _) = rgb_to_xyz::<T>(rgb.r, rgb.g, rgb.b);
+        y
     }
 
-    /// Converts this color to the HSV color space.
+    /// Calculates the WCAG 2.1 contrast ratio between this color and the given color.
+    ///
+    /// # Arguments
+    /// * `other` - The other color.
     ///
     /// # Returns
-    /// The converted `HSV` color.
+    /// The contrast ratio in the range [1, 21].
     #[must_use]
-    pub fn to_hsv(&self) -> HSV<T> {
-        let rgb = self.to_rgb();
-        HSV::from(&rgb)
+    pub fn contrast_ratio(&self, other: &Self) -> T {
+        let luminance1 = self.relative_luminance();
+        let luminance2 = other.relative_luminance();
+        let offset = T::from_f32(0.05);
+        (luminance1.max(luminance2) + offset) / (luminance1.min(luminance2) + offset)
     }
 
-    /// Converts this color to the CIE XYZ color space.
+    /// Returns whether the contrast between this color and the given color meets the WCAG AA level.
+    ///
+    /// # Arguments
+    /// * `other` - The other color.
+    /// * `large_text` - Whether the text is large. The minimum contrast ratio is 3:1 for large text, otherwise 4.5:1.
     ///
     /// # Returns
-    /// The converted `XYZ` color.
+    /// `true` if the contrast meets the WCAG AA level, otherwise `false`.
     #[must_use]
-    pub fn to_xyz(&self) -> XYZ<T> {
-        let lab = self.to_lab();
-        XYZ::from(&lab)
+    pub fn meets_wcag_aa(&self, other: &Self, large_text: bool) -> bool {
+        let level = if large_text {
+            WcagLevel::AALarge
+        } else {
+            WcagLevel::AA
+        };
+        self.passes_wcag(other, level)
     }
 
-    /// Converts this color to the CIE L*u*v* color space.
+    /// Returns whether the contrast between this color and the given color meets the WCAG AAA level.
+    ///
+    /// # Arguments
+    /// * `other` - The other color.
+    /// * `large_text` - Whether the text is large. The minimum contrast ratio is 4.5:1 for large text, otherwise 7:1.
     ///
     /// # Returns
-    /// The converted `Luv` color.
+    /// `true` if the contrast meets the WCAG AAA level, otherwise `false`.
     #[must_use]
-    pub fn to_luv(&self) -> Luv<T, W> {
-        let xyz = self.to_xyz();
-        Luv::<T, W>::from(&xyz)
+    pub fn meets_wcag_aaa(&self, other: &Self, large_text: bool) -> bool {
+        let level = if large_text {
+            WcagLevel::AAALarge
+        } else {
+            WcagLevel::AAA
+        };
+        self.passes_wcag(other, level)
     }
 
-    /// Converts this color to the CIE LCH(uv) color space.
+    /// Returns whether the contrast between this color and the given color meets the given WCAG level.
+    ///
+    /// # Arguments
+    /// * `other` - The other color.
+    /// * `level` - The WCAG conformance level.
     ///
     /// # Returns
-    /// The converted `LCHuv` color.
+    /// `true` if the contrast ratio is at least the minimum ratio of the level, otherwise `false`.
     #[must_use]
-    pub fn to_lchuv(&self) -> LCHuv<T, W> {
-        let luv = self.to_luv();
-        LCHuv::<T, W>::from(&luv)
+    pub fn passes_wcag(&self, other: &Self, level: WcagLevel) -> bool {
+        self.contrast_ratio(other) >= level.min_contrast_ratio()
     }
 
-    /// Converts this color to the CIE L*a*b* color space.
+    /// Returns the candidate color with the highest contrast against this color as a background.
+    ///
+    /// # Arguments
+    /// * `candidates` - The candidate text colors.
     ///
     /// # Returns
-    /// The converted `Lab` color.
+    /// The most readable candidate color. If no candidates are given, black or white is returned.
     #[must_use]
-    pub fn to_lab(&self) -> Lab<T, W> {
-        Lab::<T, W>::new(self.l, self.a, self.b)
+    pub fn best_text_color(&self, candidates: &[Self]) -> Self {
+        candidates
+            .iter()
+            .map(|candidate| (candidate, self.contrast_ratio(candidate)))
+            .max_by(|(_, ratio1), (_, ratio2)| ratio1.partial_cmp(ratio2).unwrap())
+            .map_or_else(|| self.black_or_white_text(), |(candidate, _)| *candidate)
     }
 
-    /// Converts this color to the CIE LCH(ab) color space.
+    /// Returns black or white, whichever has the higher contrast against this color as a background.
     ///
     /// # Returns
-    /// The converted `LCHab` color.
+    /// The black or white color.
     #[must_use]
-    pub fn to_lchab(&self) -> LCHab<T, W> {
-        let lab = self.to_lab();
-        LCHab::<T, W>::from(&lab)
+    pub fn black_or_white_text(&self) -> Self {
+        let black = Self::new(T::zero(), T::zero(), T::zero());
+        let white = Self::new(T::from_u32(100), T::zero(), T::zero());
+        if self.contrast_ratio(&black) >= self.contrast_ratio(&white) {
+            black
+        } else {
+            white
+        }
     }
 
-    /// Converts this color to the CIE Oklab color space.
+    /// Adjusts the lightness of this color until it meets the given contrast ratio against the background.
+    ///
+    /// The lightness is changed in the CIE LCH(ab) color space, so the hue and chroma are preserved where possible.
+    /// The color moves away from the background first, e.g. a color darker than the background is darkened.
+    ///
+    /// # Arguments
+    /// * `background` - The background color.
+    /// * `target_ratio` - The target contrast ratio in the range [1, 21].
     ///
     /// # Returns
-    /// The converted `Oklab` color.
+    /// The color with the smallest lightness change that meets the target contrast ratio.
+    /// If the target is not achievable, the color with the highest achievable contrast ratio is returned.
     #[must_use]
-    pub fn to_oklab(&self) -> Oklab<T> {
-        let xyz = self.to_xyz();
-        Oklab::from(&xyz)
+    pub fn adjust_for_contrast(&self, background: &Self, target_ratio: T) -> Self {
+        if self.contrast_ratio(background) >= target_ratio {
+            return *self;
+        }
+
+        let lchab = self.to_lchab();
+        let with_lightness = |l: T| Self::from_lchab(&LCHab::new(l, lchab.c, lchab.h.to_degrees()));
+        let bounds = if self.relative_luminance() <= background.relative_luminance() {
+            [Self::min_lightness(), Self::max_lightness()]
+        } else {
+            [Self::max_lightness(), Self::min_lightness()]
+        };
+
+        let adjusted = bounds.into_iter().find_map(|bound| {
+            if with_lightness(bound).contrast_ratio(background) < target_ratio {
+                return None;
+            }
+
+            // Binary search for the lightness closest to this color that meets the target.
+            let (mut near, mut far) = (self.l, bound);
+            for _ in 0..CONTRAST_SEARCH_ITERATIONS {
+                let middle = (near + far) / T::from_u32(2);
+                if with_lightness(middle).contrast_ratio(background) >= target_ratio {
+                    far = middle;
+                } else {
+                    near = middle;
+                }
+            }
+            Some(with_lightness(far))
+        });
+        adjusted.unwrap_or_else(|| {
+            let [first, second] = bounds.map(with_lightness);
+            if first.contrast_ratio(background) >= second.contrast_ratio(background) {
+                first
+            } else {
+                second
+            }
+        })
     }
 
-    /// Converts this color to the CIE Oklch color space.
+    /// Lightens this color by the given amount in the CIE LCH(ab) color space.
+    ///
+    /// # Arguments
+    /// * `amount` - The amount to add to the L* component. The result is clamped to the range [0, 100].
     ///
     /// # Returns
-    /// The converted `Oklch` color.
+    /// The lightened color.
     #[must_use]
-    pub fn to_oklch(&self) -> Oklch<T> {
-        let oklab = self.to_oklab();
-        Oklch::from(&oklab)
+    pub fn lighten(&self, amount: T) -> Self {
+        self.map_lchab(|l, c, h| (l + amount, c, h))
+    }
+
+    /// Darkens this color by the given amount in the CIE LCH(ab) color space.
+    ///
+    /// # Arguments
+    /// * `amount` - The amount to subtract from the L* component. The result is clamped to the range [0, 100].
+    ///
+    /// # Returns
+    /// The darkened color.
+    #[must_use]
+    pub fn darken(&self, amount: T) -> Self {
+        self.map_lchab(|l, c, h| (l - amount, c, h))
+    }
+
+    /// Saturates this color by the given amount in the CIE LCH(ab) color space.
+    ///
+    /// # Arguments
+    /// * `amount` - The amount to add to the C* component. The result is clamped to be non-negative.
+    ///
+    /// # Returns
+    /// The saturated color.
+    #[must_use]
+    pub fn saturate(&self, amount: T) -> Self {
+        self.map_lchab(|l, c, h| (l, c + amount, h))
+    }
+
+    /// Desaturates this color by the given amount in the CIE LCH(ab) color space.
+    ///
+    /// # Arguments
+    /// * `amount` - The amount to subtract from the C* component. The result is clamped to be non-negative.
+    ///
+    /// # Returns
+    /// The desaturated color.
+    #[must_use]
+    pub fn desaturate(&self, amount: T) -> Self {
+        self.map_lchab(|l, c, h| (l, c - amount, h))
+    }
+
+    /// Rotates the hue of this color by the given angle in the CIE LCH(ab) color space.
+    ///
+    /// # Arguments
+    /// * `degrees` - The angle to rotate the hue by in degrees. Negative values rotate counterclockwise.
+    ///
+    /// # Returns
+    /// The color with the rotated hue.
+    #[must_use]
+    pub fn rotate_hue(&self, degrees: T) -> Self {
+        self.map_lchab(|l, c, h| (l, c, h + degrees))
+    }
+
+    /// Returns the complementary color of this color.
+    ///
+    /// # Returns
+    /// The color with the hue rotated by 180 degrees in the CIE LCH(ab) color space.
+    #[must_use]
+    pub fn complementary(&self) -> Self {
+        self.rotate_hue(T::from_f32(180.0))
+    }
+
+    /// Returns the analogous colors of this color.
+    ///
+    /// # Arguments
+    /// * `angle` - The angle between this color and each analogous color in degrees, e.g. 30.
+    ///
+    /// # Returns
+    /// The colors with the hue rotated by `-angle` and `+angle` degrees in the CIE LCH(ab) color space.
+    #[must_use]
+    pub fn analogous(&self, angle: T) -> [Self; 2] {
+        [self.rotate_hue(-angle), self.rotate_hue(angle)]
+    }
+
+    /// Returns the triadic colors of this color.
+    ///
+    /// # Returns
+    /// The colors with the hue rotated by 120 and 240 degrees in the CIE LCH(ab) color space.
+    #[must_use]
+    pub fn triadic(&self) -> [Self; 2] {
+        [
+            self.rotate_hue(T::from_f32(120.0)),
+            self.rotate_hue(T::from_f32(240.0)),
+        ]
+    }
+
+    /// Returns the tetradic colors of this color.
+    ///
+    /// # Returns
+    /// The colors with the hue rotated by 90, 180, and 270 degrees in the CIE LCH(ab) color space.
+    #[must_use]
+    pub fn tetradic(&self) -> [Self; 3] {
+        [
+            self.rotate_hue(T::from_f32(90.0)),
+            self.rotate_hue(T::from_f32(180.0)),
+            self.rotate_hue(T::from_f32(270.0)),
+        ]
+    }
+
+    #[must_use]
+    fn map_lchab<F>(&self, f: F) -> Self
+    where
+        F: FnOnce(T, T, T) -> (T, T, T),
+    {
+        let lchab = self.to_lchab();
+        let (l, c, h) = f(lchab.l, lchab.c, lchab.h.to_degrees());
+        Self::from_lchab(&LCHab::new(l, c, h))
+    }
+
+    /// Returns the difference vector from the given color to this color in the CIE L*a*b* color space.
+    ///
+    /// # Arguments
+    /// * `other` - The reference color.
+    ///
+    /// # Returns
+    /// The difference of the L*, a*, and b* components.
+    #[must_use]
+    pub fn delta_from(&self, other: &Self) -> [T; 3] {
+        [self.l - other.l, self.a - other.a, self.b - other.b]
+    }
+
+    /// Applies the given difference vector to this color in the CIE L*a*b* color space.
+    ///
+    /// The resulting components are clamped to the valid range as in [`Color::from_lab`].
+    ///
+    /// # Arguments
+    /// * `delta` - The difference of the L*, a*, and b* components, e.g. from [`Color::delta_from`].
+    ///
+    /// # Returns
+    /// The color with the difference applied.
+    #[must_use]
+    pub fn apply_delta(&self, delta: [T; 3]) -> Self {
+        Self::from_lab(self.l + delta[0], self.a + delta[1], self.b + delta[2])
+    }
+
+    /// Mixes this color with the given color in the CIE L*a*b* color space.
+    ///
+    /// # Arguments
+    /// * `other` - The color to mix with.
+    /// * `fraction` - The fraction of the other color. The value is clamped to the range [0, 1].
+    ///
+    /// # Returns
+    /// The mixed color.
+    #[must_use]
+    pub fn mix(&self, other: &Self, fraction: T) -> Self {
+        let fraction = clamp(fraction, T::zero(), T::one());
+        Self::new(
+            self.l + (other.l - self.l) * fraction,
+            self.a + (other.a - self.a) * fraction,
+            self.b + (other.b - self.b) * fraction,
+        )
+    }
+
+    /// Mixes this color with the given color by interpolating the gamma-encoded sRGB channels directly.
+    ///
+    /// This matches the naive 8-bit mixing of CSS `color-mix` in the sRGB space and many legacy tools.
+    /// Unlike the perceptual [`Color::mix`], the midpoint of two colors is often darker and duller.
+    ///
+    /// # Arguments
+    /// * `other` - The color to mix with.
+    /// * `fraction` - The fraction of the other color. The value is clamped to the range [0, 1].
+    ///
+    /// # Returns
+    /// The mixed color.
+    #[must_use]
+    pub fn mix_rgb(&self, other: &Self, fraction: T) -> Self {
+        let fraction = clamp(fraction, T::zero(), T::one());
+        let rgb1 = self.to_rgb();
+        let rgb2 = other.to_rgb();
+        let channel = |c1: u8, c2: u8| -> u8 {
+            let c1 = T::from_u8(c1);
+            let c2 = T::from_u8(c2);
+            (c1 + (c2 - c1) * fraction).round().to_u8_unsafe()
+        };
+        let rgb = RGB::new(
+            channel(rgb1.r, rgb2.r),
+            channel(rgb1.g, rgb2.g),
+            channel(rgb1.b, rgb2.b),
+        );
+        Self::from_xyz(&XYZ::from(&rgb))
+    }
+
+    /// Composites this color over the given background color with the given alpha.
+    ///
+    /// This implements the "source over" alpha compositing operator in the linear RGB space.
+    /// The CIE XYZ color space is a linear transform of the linear RGB space, so the composition is computed there.
+    ///
+    /// # Arguments
+    /// * `background` - The background color.
+    /// * `alpha` - The alpha of this color. The value is clamped to the range [0, 1].
+    ///
+    /// # Returns
+    /// The composited color.
+    #[must_use]
+    pub fn blend_over(&self, background: &Self, alpha: T) -> Self {
+        let alpha = clamp(alpha, T::zero(), T::one());
+        let source = self.to_xyz();
+        let backdrop = background.to_xyz();
+        let xyz = XYZ::new(
+            source.x * alpha + backdrop.x * (T::one() - alpha),
+            source.y * alpha + backdrop.y * (T::one() - alpha),
+            source.z * alpha + backdrop.z * (T::one() - alpha),
+        );
+        Self::from_xyz(&xyz)
+    }
+
+    /// Mixes this color with the given color while preserving the hue.
+    ///
+    /// The lightness and chroma are interpolated linearly, and the hue is interpolated along the shortest path
+    /// in the CIE LCH(ab) color space. Unlike [`Color::mix`], this avoids the gray midpoint when mixing
+    /// complementary colors.
+    ///
+    /// # Arguments
+    /// * `other` - The color to mix with.
+    /// * `fraction` - The fraction of the other color. The value is clamped to the range [0, 1].
+    ///
+    /// # Returns
+    /// The mixed color.
+    #[must_use]
+    pub fn mix_preserve_hue(&self, other: &Self, fraction: T) -> Self {
+        self.mix_lch(other, fraction, false)
     }
 
-    /// Converts this color to the 4-bit ANSI 16 color space.
-    ///
-    /// # Returns
-    /// The converted `Ansi16` color.
-    #[must_use]
-    pub fn to_ansi16(&self) -> Ansi16 {
-        let rgb = self.to_rgb();
-        Ansi16::from(&rgb)
+    /// Mixes this color with the given color in the CIE LCH(ab) color space.
+    ///
+    /// The lightness and chroma are interpolated linearly, and the hue is interpolated along the shorter
+    /// or the longer arc of the hue circle.
+    ///
+    /// # Arguments
+    /// * `other` - The color to mix with.
+    /// * `fraction` - The fraction of the other color. The value is clamped to the range [0, 1].
+    /// * `long_way` - Whether to interpolate the hue along the longer arc of the hue circle.
+    ///
+    /// # Returns
+    /// The mixed color.
+    #[must_use]
+    pub fn mix_lch(&self, other: &Self, fraction: T, long_way: bool) -> Self {
+        let fraction = clamp(fraction, T::zero(), T::one());
+        let lchab1 = self.to_lchab();
+        let lchab2 = other.to_lchab();
+
+        let l = lchab1.l + (lchab2.l - lchab1.l) * fraction;
+        let c = lchab1.c + (lchab2.c - lchab1.c) * fraction;
+
+        let half_turn = T::from_f32(180.0);
+        let full_turn = T::from_f32(360.0);
+        let mut delta = lchab2.h.to_degrees() - lchab1.h.to_degrees();
+        if delta > half_turn {
+            delta -= full_turn;
+        } else if delta < -half_turn {
+            delta += full_turn;
+        }
+        if long_way {
+            delta = if delta > T::zero() {
+                delta - full_turn
+            } else {
+                delta + full_turn
+            };
+        }
+        let h = lchab1.h.to_degrees() + delta * fraction;
+        Self::from_lchab(&LCHab::new(l, c, h))
+    }
+
+    /// Builds a ramp of evenly spaced colors from this color to the given color.
+    ///
+    /// # Arguments
+    /// * `other` - The color at the end of the ramp.
+    /// * `n` - The number of colors in the ramp.
+    /// * `space` - The color space to interpolate in.
+    ///
+    /// # Returns
+    /// The colors of the ramp. The first and last colors are this color and the given color.
+    #[must_use]
+    pub fn ramp_to(&self, other: &Self, n: usize, space: MixSpace) -> Vec<Self> {
+        match n {
+            0 => Vec::new(),
+            1 => vec![*self],
+            _ => {
+                let steps = T::from_usize(n - 1);
+                (0..n)
+                    .map(|i| self.mix_in(other, T::from_usize(i) / steps, space))
+                    .collect()
+            }
+        }
+    }
+
+    /// Builds a gradient of evenly spaced colors from this color to the given color in the CIE L*a*b* color space.
+    ///
+    /// Use [`Color::ramp_to`] to interpolate in another color space such as [`MixSpace::Oklab`].
+    ///
+    /// # Arguments
+    /// * `other` - The color at the end of the gradient.
+    /// * `steps` - The number of colors in the gradient. Values less than 2 yield only the endpoints.
+    ///
+    /// # Returns
+    /// The colors of the gradient including this color and the given color as the endpoints.
+    #[must_use]
+    pub fn gradient(&self, other: &Self, steps: usize) -> Vec<Self> {
+        self.ramp_to(other, steps.max(2), MixSpace::Lab)
+    }
+
+    /// Builds a ramp of evenly spaced colors from this color to the given color in the Oklab color space,
+    /// mapping each color into the sRGB gamut.
+    ///
+    /// Each out-of-gamut color has its chroma reduced in the CIE LCH(ab) color space
+    /// while keeping the lightness and hue, so no step of the ramp is clipped per channel.
+    ///
+    /// # Arguments
+    /// * `other` - The color at the end of the ramp.
+    /// * `n` - The number of colors in the ramp.
+    ///
+    /// # Returns
+    /// The colors of the ramp within the sRGB gamut.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::str::FromStr;
+    ///
+    /// use auto_palette::color::Color;
+    ///
+    /// let red: Color<f32> = Color::from_str("#FF0000").unwrap();
+    /// let blue: Color<f32> = Color::from_str("#0000FF").unwrap();
+    /// let ramp = red.oklab_ramp_clipped(&blue, 7);
+    /// assert_eq!(ramp.len(), 7);
+    /// assert!(ramp.iter().all(Color::is_in_srgb_gamut));
+    /// ```
+    #[must_use]
+    pub fn oklab_ramp_clipped(&self, other: &Self, n: usize) -> Vec<Self> {
+        self.ramp_to(other, n, MixSpace::Oklab)
+            .iter()
+            .map(Self::clip_to_srgb_gamut)
+            .collect()
+    }
+
+    #[must_use]
+    fn mix_in(&self, other: &Self, fraction: T, space: MixSpace) -> Self {
+        match space {
+            MixSpace::Lab => self.mix(other, fraction),
+            MixSpace::Oklab => {
+                let oklab1 = self.to_oklab();
+                let oklab2 = other.to_oklab();
+                let oklab = Oklab::new(
+                    oklab1.l + (oklab2.l - oklab1.l) * fraction,
+                    oklab1.a + (oklab2.a - oklab1.a) * fraction,
+                    oklab1.b + (oklab2.b - oklab1.b) * fraction,
+                );
+                Self::from_xyz(&XYZ::from(&oklab))
+            }
+            MixSpace::Luv => {
+                let luv1 = self.to_luv();
+                let luv2 = other.to_luv();
+                let luv = Luv::<T, W>::new(
+                    luv1.l + (luv2.l - luv1.l) * fraction,
+                    luv1.u + (luv2.u - luv1.u) * fraction,
+                    luv1.v + (luv2.v - luv1.v) * fraction,
+                );
+                Self::from_xyz(&XYZ::from(&luv))
+            }
+            MixSpace::Srgb => self.mix_rgb(other, fraction),
+            MixSpace::LinearRgb => other.blend_over(self, fraction),
+        }
+    }
+
+    /// Converts this color to a hexadecimal string.
+    ///
+    /// # Returns
+    /// The hexadecimal string representation of this color, e.g. `#2C7DE7`.
+    /// If this color has an alpha component, it is appended as the fourth byte, e.g. `#2C7DE780`.
+    #[must_use]
+    pub fn to_hex_string(&self) -> String {
+        let RGB { r, g, b } = self.to_rgb();
+        match self.alpha {
+            Some(alpha) => {
+                let alpha = (alpha * RGB::max_value::<T>()).round().to_u8_unsafe();
+                format!("#{:02X}{:02X}{:02X}{:02X}", r, g, b, alpha)
+            }
+            None => format!("#{:02X}{:02X}{:02X}", r, g, b),
+        }
+    }
+
+    /// Converts this color to a CSS color string in the given format.
+    ///
+    /// # Arguments
+    /// * `format` - The CSS format.
+    ///
+    /// # Returns
+    /// The CSS color string.
+    #[must_use]
+    pub fn to_css_string(&self, format: CssFormat) -> String {
+        match format {
+            CssFormat::Hex => self.to_hex_string(),
+            CssFormat::HexAlpha(alpha) => {
+                let RGB { r, g, b } = self.to_rgb();
+                format!("#{:02X}{:02X}{:02X}{:02X}", r, g, b, alpha)
+            }
+            CssFormat::Rgb => {
+                let RGB { r, g, b } = self.to_rgb();
+                format!("rgb({}, {}, {})", r, g, b)
+            }
+            CssFormat::RgbPercent => {
+                let RGB { r, g, b } = self.to_rgb();
+                let percent = |value: u8| f64::from(value) * 100.0 / 255.0;
+                format!(
+                    "rgb({:.1}%, {:.1}%, {:.1}%)",
+                    percent(r),
+                    percent(g),
+                    percent(b)
+                )
+            }
+            CssFormat::Hsl => {
+                let hsl = self.to_hsl();
+                let hundred = T::from_u32(100);
+                format!(
+                    "hsl({}, {}%, {}%)",
+                    hsl.h.to_degrees().round().to_u32_unsafe() % 360,
+                    (hsl.s * hundred).round().to_u32_unsafe(),
+                    (hsl.l * hundred).round().to_u32_unsafe()
+                )
+            }
+        }
+    }
+
+    /// Converts this color to a CSS color string.
+    ///
+    /// # Returns
+    /// The CSS named color if this color exactly matches a keyword, otherwise the hexadecimal string.
+    #[must_use]
+    pub fn to_css(&self) -> String {
+        let rgb = self.to_rgb();
+        named::find_name(&rgb).map_or_else(|| self.to_hex_string(), String::from)
+    }
+
+    /// Returns the name of the CSS named color closest to this color.
+    ///
+    /// The closest color is the one with the smallest CIE76 color difference.
+    ///
+    /// # Returns
+    /// The name of the closest CSS named color.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::str::FromStr;
+    ///
+    /// use auto_palette::color::Color;
+    ///
+    /// let color: Color<f32> = Color::from_str("#FE0102").unwrap();
+    /// assert_eq!(color.nearest_named(), "red");
+    /// ```
+    #[must_use]
+    pub fn nearest_named(&self) -> &'static str {
+        let (nearest, _) = named::NAMED_COLORS.iter().fold(
+            (named::NAMED_COLORS[0].0, T::infinity()),
+            |(nearest, min_delta), &(name, value)| {
+                let rgb = RGB::new((value >> 16) as u8, (value >> 8) as u8, value as u8);
+                let delta = self.delta_e(&Self::from_xyz(&XYZ::from(&rgb)));
+                if delta < min_delta {
+                    (name, delta)
+                } else {
+                    (nearest, min_delta)
+                }
+            },
+        );
+        nearest
+    }
+
+    /// Converts this color to a CSS Color Level 4 `lab()` function string.
+    ///
+    /// The components are the CIE L*a*b* values of this color, so the conversion is lossless up to 2 decimal places.
+    ///
+    /// # Returns
+    /// The CSS `lab()` function string, e.g. `lab(52.92% 13.59 -60.47)`.
+    #[must_use]
+    pub fn to_css_lab(&self) -> String {
+        format!("lab({:.2}% {:.2} {:.2})", self.l, self.a, self.b)
+    }
+
+    /// Converts this color to the RGB color space.
+    ///
+    /// # Returns
+    /// The converted `RGB` color.
+    #[must_use]
+    pub fn to_rgb(&self) -> RGB {
+        let xyz = self.to_xyz();
+        RGB::from(&xyz)
+    }
+
+    /// Returns whether this color is within the sRGB gamut.
+    ///
+    /// # Returns
+    /// `true` if this color is representable in the sRGB color space without clipping, otherwise `false`.
+    #[must_use]
+    pub fn is_in_srgb_gamut(&self) -> bool {
+        let (x, y, z) = lab_to_xyz_unclamped::<T, W>(self.l, self.a, self.b);
+        let (r, g, b) = xyz_to_linear_rgb(x, y, z);
+        let min = -T::from_f32(GAMUT_EPSILON);
+        let max = T::one() + T::from_f32(GAMUT_EPSILON);
+        [r, g, b]
+            .iter()
+            .all(|&component| component >= min && component <= max)
+    }
+
+    /// Converts this color to the RGB color space, mapping out-of-gamut colors into the sRGB gamut.
+    ///
+    /// Unlike [`Color::to_rgb`], which clips each channel independently and may shift the hue,
+    /// this reduces the chroma in the CIE LCH(ab) color space while keeping the lightness and hue.
+    ///
+    /// # Returns
+    /// The converted `RGB` color.
+    #[must_use]
+    pub fn to_srgb_gamut_mapped(&self) -> RGB {
+        if self.is_in_srgb_gamut() {
+            return self.to_rgb();
+        }
+        self.clip_to_srgb_gamut().to_rgb()
+    }
+
+    #[must_use]
+    fn clip_to_srgb_gamut(&self) -> Self {
+        if self.is_in_srgb_gamut() {
+            return *self;
+        }
+
+        let lchab = self.to_lchab();
+        let with_chroma = |c: T| Self::from_lchab(&LCHab::new(lchab.l, c, lchab.h.to_degrees()));
+        // Binary search for the largest chroma within the sRGB gamut.
+        let (mut low, mut high) = (T::zero(), lchab.c);
+        for _ in 0..GAMUT_SEARCH_ITERATIONS {
+            let middle = (low + high) / T::from_u32(2);
+            if with_chroma(middle).is_in_srgb_gamut() {
+                low = middle;
+            } else {
+                high = middle;
+            }
+        }
+        with_chroma(low)
+    }
+
+    /// Returns the most vivid version of this color within the sRGB gamut.
+    ///
+    /// The chroma is pushed to the sRGB gamut boundary in the CIE LCH(ab) color space while keeping the lightness and hue.
+    /// Neutral colors are returned as they are because they have no meaningful hue.
+    ///
+    /// # Returns
+    /// The color with the largest chroma within the sRGB gamut.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::str::FromStr;
+    ///
+    /// use auto_palette::color::Color;
+    ///
+    /// let color: Color<f32> = Color::from_str("#A05A5A").unwrap();
+    /// let vivid = color.maximize_chroma();
+    /// assert!(vivid.chroma() > color.chroma());
+    /// assert!(vivid.is_in_srgb_gamut());
+    /// ```
+    #[must_use]
+    pub fn maximize_chroma(&self) -> Self {
+        if self.chroma() < T::from_f32(GAMUT_EPSILON) {
+            return *self;
+        }
+
+        let lchab = self.to_lchab();
+        let with_chroma = |c: T| Self::from_lchab(&LCHab::new(lchab.l, c, lchab.h.to_degrees()));
+        // The sRGB gamut does not exceed a CIE LCH(ab) chroma of 150 at any lightness and hue.
+        let (mut low, mut high) = (T::zero(), T::from_u32(150));
+        for _ in 0..GAMUT_SEARCH_ITERATIONS {
+            let middle = (low + high) / T::from_u32(2);
+            if with_chroma(middle).is_in_srgb_gamut() {
+                low = middle;
+            } else {
+                high = middle;
+            }
+        }
+        with_chroma(low)
+    }
+
+    /// Creates a new `Color` instance from the given linear-light sRGB components.
+    ///
+    /// The components are clamped to the range [0, 1].
+    ///
+    /// # Arguments
+    /// * `r` - The linear red component.
+    /// * `g` - The linear green component.
+    /// * `b` - The linear blue component.
+    ///
+    /// # Returns
+    /// A new `Color` instance.
+    ///
+    /// # Examples
+    /// ```
+    /// use auto_palette::color::Color;
+    ///
+    /// let color: Color<f32> = Color::from_linear_rgb(1.0, 0.216, 0.0);
+    /// assert_eq!(color.to_hex_string(), "#FF8000");
+    /// ```
+    #[must_use]
+    pub fn from_linear_rgb(r: T, g: T, b: T) -> Self {
+        let (x, y, z) = linear_rgb_to_xyz(
+            clamp(r, T::zero(), T::one()),
+            clamp(g, T::zero(), T::one()),
+            clamp(b, T::zero(), T::one()),
+        );
+        Self::from_xyz(&XYZ::new(x, y, z))
+    }
+
+    /// Converts this color to the linear-light sRGB components.
+    ///
+    /// The components are decoded with the sRGB transfer function, which is suitable for blending in linear space.
+    ///
+    /// # Returns
+    /// The linear red, green, and blue components in the range [0, 1].
+    #[must_use]
+    pub fn to_linear_rgb(&self) -> (T, T, T) {
+        let xyz = self.to_xyz();
+        let (r, g, b) = xyz_to_linear_rgb(xyz.x, xyz.y, xyz.z);
+        (
+            clamp(r, T::zero(), T::one()),
+            clamp(g, T::zero(), T::one()),
+            clamp(b, T::zero(), T::one()),
+        )
+    }
+
+    /// Creates a new `Color` instance from the given 16-bit RGB565 value.
+    ///
+    /// # Arguments
+    /// * `value` - The RGB565 value packed as 5 bits of red, 6 bits of green, and 5 bits of blue.
+    ///
+    /// # Returns
+    /// A new `Color` instance.
+    #[must_use]
+    pub fn from_rgb565(value: u16) -> Self {
+        let expand = |component: u16, max: u16| -> u8 { ((component * 255 + max / 2) / max) as u8 };
+        let rgb = RGB::new(
+            expand((value >> 11) & 0x1F, 0x1F),
+            expand((value >> 5) & 0x3F, 0x3F),
+            expand(value & 0x1F, 0x1F),
+        );
+        Self::from_xyz(&XYZ::from(&rgb))
+    }
+
+    /// Converts this color to a 16-bit RGB565 value.
+    ///
+    /// # Returns
+    /// The RGB565 value packed as 5 bits of red, 6 bits of green, and 5 bits of blue.
+    #[must_use]
+    pub fn to_rgb565(&self) -> u16 {
+        let rgb = self.to_rgb();
+        let quantize = |component: u8, max: u16| -> u16 { (component as u16 * max + 127) / 255 };
+        (quantize(rgb.r, 0x1F) << 11) | (quantize(rgb.g, 0x3F) << 5) | quantize(rgb.b, 0x1F)
+    }
+
+    /// Converts this color to the nearest color in the 216-color web-safe palette.
+    ///
+    /// Each RGB component is snapped to the nearest multiple of 0x33.
+    ///
+    /// # Returns
+    /// The nearest web-safe color.
+    #[must_use]
+    pub fn to_web_safe(&self) -> Self {
+        let rgb = self.to_rgb();
+        let snap = |component: u8| -> u8 { ((component as u16 + 25) / 51 * 51) as u8 };
+        let rgb = RGB::new(snap(rgb.r), snap(rgb.g), snap(rgb.b));
+        Self::from_xyz(&XYZ::from(&rgb))
+    }
+
+    /// Converts this color to the CMYK color space.
+    ///
+    /// # Returns
+    /// The converted `CMYK` color.
+    #[must_use]
+    pub fn to_cmyk(&self) -> CMYK<T> {
+        let rgb = self.to_rgb();
+        CMYK::from(&rgb)
+    }
+
+    /// Converts this color to the HSL color space.
+    ///
+    /// # Returns
+    /// The converted `HSL` color.
+    #[must_use]
+    pub fn to_hsl(&self) -> HSL<T> {
+        let rgb = self.to_rgb();
+        HSL::from(&rgb)
+    }
+
+    /// Converts this color to the HSV color space.
+    ///
+    /// # Returns
+    /// The converted `HSV` color.
+    #[must_use]
+    pub fn to_hsv(&self) -> HSV<T> {
+        let rgb = self.to_rgb();
+        HSV::from(&rgb)
+    }
+
+    /// Converts this color to the HWB color space.
+    ///
+    /// # Returns
+    /// The converted `HWB` color.
+    #[must_use]
+    pub fn to_hwb(&self) -> HWB<T> {
+        let rgb = self.to_rgb();
+        HWB::from(&rgb)
+    }
+
+    /// Converts this color to the CIE XYZ color space.
+    ///
+    /// # Returns
+    /// The converted `XYZ` color.
+    #[must_use]
+    pub fn to_xyz(&self) -> XYZ<T> {
+        let lab = self.to_lab();
+        XYZ::from(&lab)
+    }
+
+    /// Adapts this color to the given white point.
+    ///
+    /// This performs the Bradford chromatic adaptation transform through the CIE XYZ color space.
+    ///
+    /// # Type Parameters
+    /// * `W2` - The destination white point.
+    ///
+    /// # Returns
+    /// The adapted color under the destination white point.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::str::FromStr;
+    ///
+    /// use auto_palette::color::{Color, D50};
+    ///
+    /// let color: Color<f32> = Color::from_str("#FFFFFF").unwrap();
+    /// let adapted: Color<f32, D50> = color.adapt();
+    /// assert!((adapted.lightness() - 100.0).abs() < 2e-2);
+    /// assert!(adapted.to_lab().a.abs() < 2e-2);
+    /// assert!(adapted.to_lab().b.abs() < 2e-2);
+    /// ```
+    #[must_use]
+    pub fn adapt<W2>(&self) -> Color<T, W2>
+    where
+        W2: WhitePoint,
+    {
+        let (x, y, z) = xyz::lab_to_xyz::<T, W>(self.l, self.a, self.b);
+        let (x, y, z) = white_point::bradford_adapt::<T, W, W2>(x, y, z);
+        let (l, a, b) = xyz_to_lab::<T, W2>(x, y, z);
+        Color {
+            l,
+            a,
+            b,
+            alpha: self.alpha,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Converts this color to the CIE L*u*v* color space.
+    ///
+    /// # Returns
+    /// The converted `Luv` color.
+    #[must_use]
+    pub fn to_luv(&self) -> Luv<T, W> {
+        let xyz = self.to_xyz();
+        Luv::<T, W>::from(&xyz)
+    }
+
+    /// Converts this color to the CIE LCH(uv) color space.
+    ///
+    /// # Returns
+    /// The converted `LCHuv` color.
+    #[must_use]
+    pub fn to_lchuv(&self) -> LCHuv<T, W> {
+        let luv = self.to_luv();
+        LCHuv::<T, W>::from(&luv)
+    }
+
+    /// Converts this color to the CIE L*a*b* color space.
+    ///
+    /// # Returns
+    /// The converted `Lab` color.
+    #[must_use]
+    pub fn to_lab(&self) -> Lab<T, W> {
+        Lab::<T, W>::new(self.l, self.a, self.b)
+    }
+
+    /// Converts this color to the CIE LCH(ab) color space.
+    ///
+    /// # Returns
+    /// The converted `LCHab` color.
+    #[must_use]
+    pub fn to_lchab(&self) -> LCHab<T, W> {
+        let lab = self.to_lab();
+        LCHab::<T, W>::from(&lab)
+    }
+
+    /// Converts this color to the CIE Oklab color space.
+    ///
+    /// # Returns
+    /// The converted `Oklab` color.
+    #[must_use]
+    pub fn to_oklab(&self) -> Oklab<T> {
+        let xyz = self.to_xyz();
+        Oklab::from(&xyz)
+    }
+
+    /// Converts this color to the CIE Oklch color space.
+    ///
+    /// # Returns
+    /// The converted `Oklch` color.
+    #[must_use]
+    pub fn to_oklch(&self) -> Oklch<T> {
+        let oklab = self.to_oklab();
+        Oklch::from(&oklab)
+    }
+
+    /// Converts this color to the components of the given color space.
+    ///
+    /// # Arguments
+    /// * `space` - The color space of the components.
+    ///
+    /// # Returns
+    /// The components of this color in the given color space.
+    /// The length is given by [`ColorSpace::num_components`], and hue components are given in degrees.
+    #[must_use]
+    pub fn to_components(&self, space: ColorSpace) -> Vec<T> {
+        match space {
+            ColorSpace::Rgb => {
+                let RGB { r, g, b } = self.to_rgb();
+                vec![T::from_u8(r), T::from_u8(g), T::from_u8(b)]
+            }
+            ColorSpace::Cmyk => {
+                let CMYK { c, m, y, k } = self.to_cmyk();
+                vec![c, m, y, k]
+            }
+            ColorSpace::Xyz => {
+                let XYZ { x, y, z } = self.to_xyz();
+                vec![x, y, z]
+            }
+            ColorSpace::Lab => vec![self.l, self.a, self.b],
+            ColorSpace::Lch => {
+                let lch = self.to_lchab();
+                vec![lch.l, lch.c, lch.h.to_degrees()]
+            }
+            ColorSpace::Hsl => {
+                let HSL { h, s, l } = self.to_hsl();
+                vec![h.to_degrees(), s, l]
+            }
+            ColorSpace::Hsv => {
+                let HSV { h, s, v } = self.to_hsv();
+                vec![h.to_degrees(), s, v]
+            }
+            ColorSpace::Hwb => {
+                let HWB { h, w, b } = self.to_hwb();
+                vec![h.to_degrees(), w, b]
+            }
+            ColorSpace::Oklab => {
+                let Oklab { l, a, b } = self.to_oklab();
+                vec![l, a, b]
+            }
+            ColorSpace::Oklch => {
+                let Oklch { l, c, h } = self.to_oklch();
+                vec![l, c, h.to_degrees()]
+            }
+        }
+    }
+
+    /// Converts this color to the 4-bit ANSI 16 color space.
+    ///
+    /// # Returns
+    /// The converted `Ansi16` color.
+    #[must_use]
+    pub fn to_ansi16(&self) -> Ansi16 {
+        let rgb = self.to_rgb();
+        Ansi16::from(&rgb)
+    }
+
+    /// Converts this color to the 8-bit ANSI 256 color space.
+    ///
+    /// # Returns
+    /// The converted `Ansi256` color.
+    #[must_use]
+    pub fn to_ansi256(&self) -> Ansi256 {
+        let rgb = self.to_rgb();
+        Ansi256::from(&rgb)
+    }
+}
+
+impl<T> Color<T>
+where
+    T: FloatNumber,
+{
+    /// Returns the Oklch components of this color normalized for UI sliders.
+    ///
+    /// The chroma is scaled by the largest chroma within the sRGB gamut at the lightness and hue of this color,
+    /// so any normalized chroma in the range [0, 1] maps to a displayable color.
+    ///
+    /// # Returns
+    /// The tuple of the lightness in the range [0, 1], the normalized chroma in the range [0, 1],
+    /// and the hue in degrees in the range [0, 360).
+    ///
+    /// # Examples
+    /// ```
+    /// use std::str::FromStr;
+    ///
+    /// use auto_palette::color::Color;
+    ///
+    /// let color: Color<f32> = Color::from_str("#808080").unwrap();
+    /// let (l, c, _) = color.oklch_normalized();
+    /// assert!((l - 0.6).abs() < 1e-2);
+    /// assert!(c < 1e-3);
+    /// ```
+    #[must_use]
+    pub fn oklch_normalized(&self) -> (T, T, T) {
+        let oklch = self.to_oklch();
+        let l = clamp(oklch.l, T::zero(), T::one());
+        let h = oklch.h.to_degrees();
+        let max_chroma = max_oklch_chroma(l, h);
+        let c = if max_chroma > T::zero() {
+            clamp(oklch.c / max_chroma, T::zero(), T::one())
+        } else {
+            T::zero()
+        };
+        (l, c, h)
+    }
+
+    /// Parses a color from a hexadecimal string or a CSS `lab()` function leniently.
+    ///
+    /// Unlike [`Color::from_str`], this accepts the `#`, `0x` and `0X` prefixes as well as bare hex strings.
+    /// Both 6-digit (`RRGGBB`) and 8-digit (`RRGGBBAA`) hex strings are accepted, and the alpha component is kept.
+    /// The CSS `lab()` function is accepted in the format produced by [`Color::to_css_lab`],
+    /// and the CSS `rgb()` and `hsl()` functions are accepted as in [`Color::from_str`].
+    /// Leading and trailing whitespace is ignored.
+    ///
+    /// # Arguments
+    /// * `s` - The string to parse.
+    ///
+    /// # Returns
+    /// The parsed color, or an error message if the string is not a valid color.
+    pub fn parse(s: &str) -> Result<Self, &'static str> {
+        let s = s.trim();
+        if let Some(args) = s
+            .strip_prefix("lab(")
+            .and_then(|rest| rest.strip_suffix(')'))
+        {
+            return Self::parse_css_lab(args);
+        }
+        if s.starts_with("rgb") || s.starts_with("hsl") {
+            return s.parse();
+        }
+
+        let hex = s
+            .strip_prefix('#')
+            .or_else(|| s.strip_prefix("0x"))
+            .or_else(|| s.strip_prefix("0X"))
+            .unwrap_or(s);
+        if hex.len() != 6 && hex.len() != 8 {
+            return Err("Invalid color format");
+        }
+        if !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Err("Invalid hex value");
+        }
+        Self::from_str(&format!("#{}", hex))
+    }
+
+    fn parse_css_lab(args: &str) -> Result<Self, &'static str> {
+        let components: Vec<&str> = args.split_whitespace().collect();
+        let [l, a, b] = components[..] else {
+            return Err("Invalid lab() format");
+        };
+
+        let parse = |value: &str| -> Result<T, &'static str> {
+            parse_css_number(value)
+                .map(T::from_f64)
+                .ok_or("Invalid lab() value")
+        };
+        let l = parse(l.strip_suffix('%').unwrap_or(l))?;
+        let a = parse(a)?;
+        let b = parse(b)?;
+        let lab = Lab::<T>::new(l, a, b);
+        Ok(Self::new(lab.l, lab.a, lab.b))
+    }
+
+    fn parse_css_rgb(args: &str) -> Result<Self, &'static str> {
+        let components = css_components(args);
+        let (&[r, g, b] | &[r, g, b, _]) = &components[..] else {
+            return Err("Invalid rgb() format");
+        };
+        let alpha = components
+            .get(3)
+            .map(|&alpha| parse_css_alpha(alpha))
+            .transpose()?;
+
+        let parse = |value: &str| -> Result<u8, &'static str> {
+            let (value, divisor) = match value.strip_suffix('%') {
+                Some(percent) => (percent, 100.0),
+                None => (value, 255.0),
+            };
+            parse_css_number(value)
+                .map(|value| (value * 255.0 / divisor).round().clamp(0.0, 255.0) as u8)
+                .ok_or("Invalid rgb() value")
+        };
+        let rgb = RGB::new(parse(r)?, parse(g)?, parse(b)?);
+        let color = Self::from_xyz(&XYZ::from(&rgb));
+        Ok(alpha.map_or(color, |alpha| color.with_alpha(alpha)))
+    }
+
+    fn parse_css_hsl(args: &str) -> Result<Self, &'static str> {
+        let components = css_components(args);
+        let (&[h, s, l] | &[h, s, l, _]) = &components[..] else {
+            return Err("Invalid hsl() format");
+        };
+        let alpha = components
+            .get(3)
+            .map(|&alpha| parse_css_alpha(alpha))
+            .transpose()?;
+
+        let parse = |value: &str| -> Result<T, &'static str> {
+            parse_css_number(value)
+                .map(T::from_f64)
+                .ok_or("Invalid hsl() value")
+        };
+        let h = parse(h.strip_suffix("deg").unwrap_or(h))?;
+        let s = parse(s.strip_suffix('%').unwrap_or(s))? / T::from_u32(100);
+        let l = parse(l.strip_suffix('%').unwrap_or(l))? / T::from_u32(100);
+        let rgb = RGB::from(&HSL::new(h, s, l));
+        let color = Self::from_xyz(&XYZ::from(&rgb));
+        Ok(alpha.map_or(color, |alpha| color.with_alpha(alpha)))
+    }
+
+    /// Creates a new `Color` instance from the given HSLuv color.
+    ///
+    /// # Arguments
+    /// * `hsluv` - The HSLuv color.
+    ///
+    /// # Returns
+    /// A new `Color` instance.
+    #[must_use]
+    pub fn from_hsluv(hsluv: &HSLuv<T>) -> Self {
+        let lchuv = LCHuv::from(hsluv);
+        let luv = Luv::from(&lchuv);
+        Self::from_xyz(&XYZ::from(&luv))
+    }
+
+    /// Converts this color to the HSLuv color space.
+    ///
+    /// # Returns
+    /// The converted `HSLuv` color.
+    #[must_use]
+    pub fn to_hsluv(&self) -> HSLuv<T> {
+        let lchuv = self.to_lchuv();
+        HSLuv::from(&lchuv)
+    }
+}
+
+impl<T> Display for Color<T>
+where
+    T: FloatNumber,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Color(l: {:.2}, a: {:.2}, b: {:.2})",
+            self.l, self.a, self.b
+        )
+    }
+}
+
+impl<T> FromStr for Color<T>
+where
+    T: FloatNumber,
+{
+    type Err = &'static str;
+
+    /// Parses a color from a hexadecimal string (`#RRGGBB` or `#RRGGBBAA`) or a CSS `rgb()`, `rgba()`, `hsl()`, or `hsla()` function.
+    ///
+    /// Leading and trailing whitespace is ignored, and the hexadecimal digits are case-insensitive.
+    /// The components of the CSS functions may be separated by commas or whitespace.
+    /// The RGB components and the alpha component may be given as numbers or percentages.
+    ///
+    /// # Arguments
+    /// * `s` - The string to parse.
+    ///
+    /// # Returns
+    /// The parsed color, or an error message if the string is not a valid color.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if let Some((name, rest)) = s.split_once('(') {
+            let args = rest.strip_suffix(')').ok_or("Invalid color format")?;
+            return match name {
+                "rgb" | "rgba" => Self::parse_css_rgb(args),
+                "hsl" | "hsla" => Self::parse_css_hsl(args),
+                _ => Err("Invalid color format"),
+            };
+        }
+
+        if (s.len() != 7 && s.len() != 9) || !s.starts_with('#') {
+            return Err("Invalid color format");
+        }
+        // Checking the digits up front rejects multi-byte characters before slicing and signs accepted by `from_str_radix`.
+        if !s[1..].bytes().all(|byte| byte.is_ascii_hexdigit()) {
+            return Err("Invalid hex value");
+        }
+
+        let r = u8::from_str_radix(&s[1..3], 16).map_err(|_| "Invalid hex value")?;
+        let g = u8::from_str_radix(&s[3..5], 16).map_err(|_| "Invalid hex value")?;
+        let b = u8::from_str_radix(&s[5..7], 16).map_err(|_| "Invalid hex value")?;
+
+        let (x, y, z) = rgb_to_xyz::<T>(r, g, b);
+        let (l, a, b) = xyz_to_lab::<T, D65>(x, y, z);
+        let color = Self::new(l, a, b);
+        if s.len() == 7 {
+            return Ok(color);
+        }
+
+        let alpha = u8::from_str_radix(&s[7..9], 16).map_err(|_| "Invalid hex value")?;
+        Ok(color.with_alpha(T::from_u8(alpha) / RGB::max_value::<T>()))
+    }
+}
+
+/// Finds the largest Oklch chroma within the sRGB gamut at the given lightness and hue.
+///
+/// # Arguments
+/// * `l` - The Oklch lightness in the range [0, 1].
+/// * `h` - The Oklch hue in degrees.
+///
+/// # Returns
+/// The largest chroma within the sRGB gamut.
+#[must_use]
+fn max_oklch_chroma<T>(l: T, h: T) -> T
+where
+    T: FloatNumber,
+{
+    let min = -T::from_f32(GAMUT_EPSILON);
+    let max = T::one() + T::from_f32(GAMUT_EPSILON);
+    let in_gamut = |c: T| {
+        let oklab = Oklab::from(&Oklch::new(l, c, h));
+        let xyz = XYZ::from(&oklab);
+        let (r, g, b) = xyz_to_linear_rgb(xyz.x, xyz.y, xyz.z);
+        [r, g, b]
+            .iter()
+            .all(|&component| component >= min && component <= max)
+    };
+
+    // The sRGB gamut does not exceed an Oklch chroma of 0.5 at any lightness and hue.
+    let (mut low, mut high) = (T::zero(), T::from_f32(0.5));
+    for _ in 0..GAMUT_SEARCH_ITERATIONS {
+        let middle = (low + high) / T::from_u32(2);
+        if in_gamut(middle) {
+            low = middle;
+        } else {
+            high = middle;
+        }
+    }
+    low
+}
+
+/// Splits the arguments of a CSS color function into its components.
+///
+/// # Arguments
+/// * `args` - The arguments separated by commas, slashes, or whitespace.
+///
+/// # Returns
+/// The non-empty components.
+#[must_use]
+fn css_components(args: &str) -> Vec<&str> {
+    args.split(|c: char| c == ',' || c == '/' || c.is_whitespace())
+        .filter(|component| !component.is_empty())
+        .collect()
+}
+
+/// Parses the alpha component of a CSS color function.
+///
+/// # Arguments
+/// * `value` - The alpha component as a number in the range [0, 1] or a percentage.
+///
+/// # Returns
+/// The alpha component, or an error message if the value is not a number.
+fn parse_css_alpha<T>(value: &str) -> Result<T, &'static str>
+where
+    T: FloatNumber,
+{
+    let (value, divisor) = match value.strip_suffix('%') {
+        Some(percent) => (percent, 100.0),
+        None => (value, 1.0),
+    };
+    parse_css_number(value)
+        .map(|value| T::from_f64(value / divisor))
+        .ok_or("Invalid alpha value")
+}
+
+/// Parses a number in a CSS color function.
+///
+/// # Arguments
+/// * `value` - The number to parse.
+///
+/// # Returns
+/// The parsed number, or `None` if the value is not a finite number such as `nan` or `inf`.
+#[must_use]
+fn parse_css_number(value: &str) -> Option<f64> {
+    value.parse::<f64>().ok().filter(|value| value.is_finite())
+}
+
+#[cfg(test)]
+#[allow(clippy::excessive_precision, clippy::inconsistent_digit_grouping)]
+mod tests {
+    use proptest::prelude::*;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[test]
+    fn test_new() {
+        // Act
+        let actual: Color<f32> = Color::new(80.0, 0.0, 0.0);
+
+        // Assert
+        assert_eq!(actual.l, 80.0);
+        assert_eq!(actual.a, 0.0);
+        assert_eq!(actual.b, 0.0);
+    }
+
+    #[rstest]
+    #[case::in_range((53.24, 80.09, 67.2), (53.24, 80.09, 67.2))]
+    #[case::over((120.0, 200.0, 200.0), (100.0, 127.0, 127.0))]
+    #[case::under((-10.0, -200.0, -200.0), (0.0, -128.0, -128.0))]
+    fn test_from_lab(#[case] input: (f32, f32, f32), #[case] expected: (f32, f32, f32)) {
+        // Act
+        let actual: Color<f32> = Color::from_lab(input.0, input.1, input.2);
+
+        // Assert
+        assert_eq!(actual.l, expected.0);
+        assert_eq!(actual.a, expected.1);
+        assert_eq!(actual.b, expected.2);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_serde() {
+        // Arrange
+        let color: Color<f64> = Color::from_str("#2C7DE7").unwrap();
+
+        // Act
+        let json = serde_json::to_string(&color).unwrap();
+        let actual: Color<f64> = serde_json::from_str(&json).unwrap();
+
+        // Assert
+        assert_eq!(
+            json,
+            format!(r#"{{"l":{},"a":{},"b":{}}}"#, color.l, color.a, color.b)
+        );
+        assert_eq!(actual, color);
+    }
+
+    #[test]
+    fn test_from_lab_checked() {
+        // Act
+        let actual: Color<f32> = Color::from_lab_checked(53.24, 200.0, 67.2).unwrap();
+
+        // Assert
+        assert_eq!(actual.l, 53.24);
+        assert_eq!(actual.a, 127.0);
+        assert_eq!(actual.b, 67.2);
+    }
+
+    #[rstest]
+    #[case::over(100.1)]
+    #[case::under(-0.1)]
+    #[case::nan(f32::NAN)]
+    fn test_from_lab_checked_error(#[case] l: f32) {
+        // Act
+        let actual = Color::<f32>::from_lab_checked(l, 0.0, 0.0);
+
+        // Assert
+        assert!(matches!(actual, Err(Error::InvalidColor { .. })));
+    }
+
+    #[rstest]
+    #[case((0.0, 0.0, 0.0), false)]
+    #[case((50.0, 0.0, 0.0), false)]
+    #[case((50.1, 0.0, 0.0), true)]
+    #[case((80.0, 0.0, 0.0), true)]
+    fn test_color_is_light(#[case] input: (f32, f32, f32), #[case] expected: bool) {
+        // Act
+        let color: Color<f32> = Color::new(input.0, input.1, input.2);
+        let actual = color.is_light();
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+
+    #[rstest]
+    #[case((0.0, 0.0, 0.0), true)]
+    #[case((50.0, 0.0, 0.0), true)]
+    #[case((50.1, 0.0, 0.0), false)]
+    #[case((80.0, 0.0, 0.0), false)]
+    fn test_color_is_dark(#[case] input: (f32, f32, f32), #[case] expected: bool) {
+        // Act
+        let color: Color<f32> = Color::new(input.0, input.1, input.2);
+        let actual = color.is_dark();
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_lightness() {
+        // Act
+        let color: Color<f32> = Color::new(91.1120, -48.0806, -14.1521);
+        let actual = color.lightness();
+
+        // Assert
+        assert_eq!(actual, 91.1120);
+    }
+
+    #[test]
+    fn test_chroma() {
+        // Act
+        let color: Color<f32> = Color::new(91.1120, -48.0806, -14.1521);
+        let actual = color.chroma();
+
+        // Assert
+        assert!((actual - 50.120_117).abs() < 1e-3);
+    }
+
+    #[rstest]
+    #[case::black((0.0, 0.0, 0.0), 0.0)]
+    #[case::white((100.0, - 0.002_443, 0.011_384), 102.111_946)]
+    #[case::red((53.237_144, 80.088_320, 67.199_460), 39.998_900)]
+    #[case::green((87.735_535, - 86.183_550, 83.179_924), 136.016_020)]
+    #[case::blue((32.300_800, 79.194_260, - 107.868_910), 306.28503)]
+    #[case::cyan((91.114_750, - 48.080_950, - 14.142_858), 196.391_080,)]
+    #[case::magenta((60.322_700, 98.235_580, - 60.842_370), 328.227_940,)]
+    #[case::yellow((97.138_580, - 21.562_368, 94.476_760), 102.856_380)]
+    fn test_hue(#[case] input: (f32, f32, f32), #[case] expected: f32) {
+        // Act
+        let color: Color<f32> = Color::new(input.0, input.1, input.2);
+        let actual = color.hue();
+
+        // Assert
+        assert!((actual.to_degrees() - expected).abs() < 1e-3);
+    }
+
+    #[rstest]
+    #[case::same((50.0, 20.0, -30.0), (50.0, 20.0, -30.0), 0.0)]
+    #[case::lightness((50.0, 0.0, 0.0), (60.0, 0.0, 0.0), 10.0)]
+    #[case::chroma((50.0, 3.0, 0.0), (50.0, 0.0, 4.0), 5.0)]
+    #[case::all((20.0, -10.0, 5.0), (22.0, -7.0, 11.0), 7.0)]
+    fn test_delta_e(
+        #[case] input1: (f32, f32, f32),
+        #[case] input2: (f32, f32, f32),
+        #[case] expected: f32,
+    ) {
+        // Arrange
+        let color1: Color<f32> = Color::new(input1.0, input1.1, input1.2);
+        let color2: Color<f32> = Color::new(input2.0, input2.1, input2.2);
+
+        // Act
+        let actual = color1.delta_e(&color2);
+
+        // Assert
+        assert!((actual - expected).abs() < 1e-6);
+        assert_eq!(actual, color2.delta_e(&color1));
+    }
+
+    #[rstest]
+    #[case::black_white("#000000", "#FFFFFF", 21.0)]
+    #[case::white_black("#FFFFFF", "#000000", 21.0)]
+    #[case::same("#EE334E", "#EE334E", 1.0)]
+    #[case::gray_white("#767676", "#FFFFFF", 4.54)]
+    fn test_contrast_ratio(#[case] input1: &str, #[case] input2: &str, #[case] expected: f32) {
+        // Arrange
+        let color1: Color<f32> = Color::from_str(input1).unwrap();
+        let color2: Color<f32> = Color::from_str(input2).unwrap();
+
+        // Act
+        let actual = color1.contrast_ratio(&color2);
+
+        // Assert
+        assert!((actual - expected).abs() < 1e-2);
+    }
+
+    #[rstest]
+    #[case::black("#000000", 0.0)]
+    #[case::white("#FFFFFF", 1.0)]
+    #[case::red("#FF0000", 0.2126)]
+    #[case::gray("#808080", 0.2159)]
+    fn test_relative_luminance(#[case] input: &str, #[case] expected: f32) {
+        // Arrange
+        let color: Color<f32> = Color::from_str(input).unwrap();
+
+        // Act
+        let actual = color.relative_luminance();
+
+        // Assert
+        assert!((actual - expected).abs() < 1e-3, "{}", actual);
+    }
+
+    #[rstest]
+    #[case::gray_white("#767676", "#FFFFFF", false, true)]
+    #[case::gray_white_large("#949494", "#FFFFFF", true, true)]
+    #[case::light_gray_white("#949494", "#FFFFFF", false, false)]
+    #[case::low_contrast_large("#777777", "#888888", true, false)]
+    fn test_meets_wcag_aa(
+        #[case] input1: &str,
+        #[case] input2: &str,
+        #[case] large_text: bool,
+        #[case] expected: bool,
+    ) {
+        // Arrange
+        let color1: Color<f32> = Color::from_str(input1).unwrap();
+        let color2: Color<f32> = Color::from_str(input2).unwrap();
+
+        // Act
+        let actual = color1.meets_wcag_aa(&color2, large_text);
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+
+    #[rstest]
+    #[case::black_white("#000000", "#FFFFFF", false, true)]
+    #[case::gray_white("#767676", "#FFFFFF", false, false)]
+    #[case::gray_white_large("#767676", "#FFFFFF", true, true)]
+    #[case::light_gray_white_large("#949494", "#FFFFFF", true, false)]
+    fn test_meets_wcag_aaa(
+        #[case] input1: &str,
+        #[case] input2: &str,
+        #[case] large_text: bool,
+        #[case] expected: bool,
+    ) {
+        // Arrange
+        let color1: Color<f32> = Color::from_str(input1).unwrap();
+        let color2: Color<f32> = Color::from_str(input2).unwrap();
+
+        // Act
+        let actual = color1.meets_wcag_aaa(&color2, large_text);
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+
+    #[rstest]
+    #[case::black_white_aaa("#000000", "#FFFFFF", WcagLevel::AAA, true)]
+    #[case::gray_white_aa("#767676", "#FFFFFF", WcagLevel::AA, true)]
+    #[case::gray_white_aaa("#767676", "#FFFFFF", WcagLevel::AAA, false)]
+    #[case::low_contrast_aa("#777777", "#888888", WcagLevel::AA, false)]
+    #[case::low_contrast_aa_large("#777777", "#888888", WcagLevel::AALarge, false)]
+    #[case::large_text_aa_large("#949494", "#FFFFFF", WcagLevel::AALarge, true)]
+    #[case::large_text_aaa_large("#949494", "#FFFFFF", WcagLevel::AAALarge, false)]
+    fn test_passes_wcag(
+        #[case] input1: &str,
+        #[case] input2: &str,
+        #[case] level: WcagLevel,
+        #[case] expected: bool,
+    ) {
+        // Arrange
+        let color1: Color<f32> = Color::from_str(input1).unwrap();
+        let color2: Color<f32> = Color::from_str(input2).unwrap();
+
+        // Act
+        let actual = color1.passes_wcag(&color2, level);
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+
+    #[rstest]
+    #[case::dark("#1A1A2E", "#FFFFFF")]
+    #[case::light("#F5F5DC", "#000000")]
+    #[case::red("#FF0000", "#000000")]
+    #[case::blue("#0000FF", "#FFFFFF")]
+    fn test_black_or_white_text(#[case] input: &str, #[case] expected: &str) {
+        // Arrange
+        let background: Color<f32> = Color::from_str(input).unwrap();
+
+        // Act
+        let actual = background.black_or_white_text();
+
+        // Assert
+        assert_eq!(actual.to_hex_string(), expected);
+    }
+
+    #[rstest]
+    #[case::gray_on_white("#888888", "#FFFFFF", 4.5)]
+    #[case::gray_on_black("#444444", "#000000", 7.0)]
+    #[case::blue_on_white("#6495ED", "#FFFFFF", 4.5)]
+    #[case::orange_on_navy("#CC6600", "#000080", 4.5)]
+    fn test_adjust_for_contrast(
+        #[case] input: &str,
+        #[case] background: &str,
+        #[case] target: f32,
+    ) {
+        // Arrange
+        let color: Color<f32> = Color::from_str(input).unwrap();
+        let background: Color<f32> = Color::from_str(background).unwrap();
+
+        // Act
+        let actual = color.adjust_for_contrast(&background, target);
+
+        // Assert
+        assert!(color.contrast_ratio(&background) < target);
+        assert!(actual.contrast_ratio(&background) >= target);
+        assert!(actual.contrast_ratio(&background) < target + 0.5);
+    }
+
+    #[test]
+    fn test_adjust_for_contrast_darkens_gray_on_white() {
+        // Arrange
+        let gray: Color<f32> = Color::from_str("#888888").unwrap();
+        let white: Color<f32> = Color::from_str("#FFFFFF").unwrap();
+
+        // Act
+        let actual = gray.adjust_for_contrast(&white, 4.5);
+
+        // Assert
+        assert!(actual.lightness() < gray.lightness());
+        assert!(actual.meets_wcag_aa(&white, false));
+        assert!(actual.chroma() < 1.0);
+    }
+
+    #[test]
+    fn test_adjust_for_contrast_already_met() {
+        // Arrange
+        let black: Color<f32> = Color::from_str("#000000").unwrap();
+        let white: Color<f32> = Color::from_str("#FFFFFF").unwrap();
+
+        // Act
+        let actual = black.adjust_for_contrast(&white, 4.5);
+
+        // Assert
+        assert_eq!(actual, black);
+    }
+
+    #[test]
+    fn test_adjust_for_contrast_unachievable() {
+        // Arrange
+        let gray: Color<f32> = Color::from_str("#777777").unwrap();
+        let background: Color<f32> = Color::from_str("#767676").unwrap();
+
+        // Act
+        let actual = gray.adjust_for_contrast(&background, 21.0);
+
+        // Assert
+        assert_eq!(actual.to_hex_string(), "#000000");
+    }
+
+    #[rstest]
+    #[case::lighten(10.0, 62.918)]
+    #[case::lighten_clamped(80.0, 100.0)]
+    fn test_lighten(#[case] amount: f32, #[case] expected: f32) {
+        // Arrange
+        let color: Color<f32> = Color::from_str("#2C7DE7").unwrap();
+
+        // Act
+        let actual = color.lighten(amount);
+
+        // Assert
+        assert!((actual.lightness() - expected).abs() < 1e-3);
+        assert!((actual.hue().to_degrees() - color.hue().to_degrees()).abs() < 1e-3);
+    }
+
+    #[rstest]
+    #[case::darken(10.0, 42.918)]
+    #[case::darken_clamped(80.0, 0.0)]
+    fn test_darken(#[case] amount: f32, #[case] expected: f32) {
+        // Arrange
+        let color: Color<f32> = Color::from_str("#2C7DE7").unwrap();
+
+        // Act
+        let actual = color.darken(amount);
+
+        // Assert
+        assert!((actual.lightness() - expected).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_saturate() {
+        // Arrange
+        let color: Color<f32> = Color::from_str("#2C7DE7").unwrap();
+
+        // Act
+        let actual = color.saturate(10.0);
+
+        // Assert
+        assert!((actual.chroma() - (color.chroma() + 10.0)).abs() < 1e-3);
+        assert!((actual.lightness() - color.lightness()).abs() < 1e-3);
+        assert!((actual.hue().to_degrees() - color.hue().to_degrees()).abs() < 1e-3);
+    }
+
+    #[rstest]
+    #[case::desaturate(10.0)]
+    #[case::desaturate_clamped(100.0)]
+    fn test_desaturate(#[case] amount: f32) {
+        // Arrange
+        let color: Color<f32> = Color::from_str("#2C7DE7").unwrap();
+
+        // Act
+        let actual = color.desaturate(amount);
+
+        // Assert
+        let expected = (color.chroma() - amount).max(0.0);
+        assert!((actual.chroma() - expected).abs() < 1e-3);
+        assert!((actual.lightness() - color.lightness()).abs() < 1e-3);
+    }
+
+    #[rstest]
+    #[case::clockwise(30.0, 312.662)]
+    #[case::counterclockwise(-300.0, 342.662)]
+    #[case::wrap(120.0, 42.662)]
+    fn test_rotate_hue(#[case] degrees: f32, #[case] expected: f32) {
+        // Arrange
+        let color: Color<f32> = Color::from_str("#2C7DE7").unwrap();
+
+        // Act
+        let actual = color.rotate_hue(degrees);
+
+        // Assert
+        assert!((actual.hue().to_degrees() - expected).abs() < 1e-2);
+        assert!((actual.chroma() - color.chroma()).abs() < 1e-3);
+        assert!((actual.lightness() - color.lightness()).abs() < 1e-3);
+    }
+
+    #[must_use]
+    fn hue_offset(color: &Color<f32>, base: &Color<f32>) -> f32 {
+        (color.hue().to_degrees() - base.hue().to_degrees()).rem_euclid(360.0)
+    }
+
+    #[test]
+    fn test_complementary() {
+        // Arrange
+        let color: Color<f32> = Color::from_str("#2C7DE7").unwrap();
+
+        // Act
+        let actual = color.complementary();
+
+        // Assert
+        assert!((hue_offset(&actual, &color) - 180.0).abs() < 1e-3);
+        assert!((actual.lightness() - color.lightness()).abs() < 1e-3);
+        assert!((actual.chroma() - color.chroma()).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_analogous() {
+        // Arrange
+        let color: Color<f32> = Color::from_str("#2C7DE7").unwrap();
+
+        // Act
+        let actual = color.analogous(30.0);
+
+        // Assert
+        assert!((hue_offset(&actual[0], &color) - 330.0).abs() < 1e-3);
+        assert!((hue_offset(&actual[1], &color) - 30.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_triadic() {
+        // Arrange
+        let color: Color<f32> = Color::from_str("#2C7DE7").unwrap();
+
+        // Act
+        let actual = color.triadic();
+
+        // Assert
+        assert!((hue_offset(&actual[0], &color) - 120.0).abs() < 1e-3);
+        assert!((hue_offset(&actual[1], &color) - 240.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_tetradic() {
+        // Arrange
+        let color: Color<f32> = Color::from_str("#2C7DE7").unwrap();
+
+        // Act
+        let actual = color.tetradic();
+
+        // Assert
+        assert!((hue_offset(&actual[0], &color) - 90.0).abs() < 1e-3);
+        assert!((hue_offset(&actual[1], &color) - 180.0).abs() < 1e-3);
+        assert!((hue_offset(&actual[2], &color) - 270.0).abs() < 1e-3);
+        for harmony in actual {
+            assert!((harmony.lightness() - color.lightness()).abs() < 1e-3);
+            assert!((harmony.chroma() - color.chroma()).abs() < 1e-3);
+        }
+    }
+
+    #[rstest]
+    #[case::dark("#1A1A2E", "#FCB131")]
+    #[case::light("#F5F5DC", "#0081C8")]
+    fn test_best_text_color(#[case] input: &str, #[case] expected: &str) {
+        // Arrange
+        let background: Color<f32> = Color::from_str(input).unwrap();
+        let candidates: Vec<Color<f32>> = ["#0081C8", "#FCB131", "#808080"]
+            .iter()
+            .map(|hex| Color::from_str(hex).unwrap())
+            .collect();
+
+        // Act
+        let actual = background.best_text_color(&candidates);
+
+        // Assert
+        assert_eq!(actual.to_hex_string(), expected);
+    }
+
+    #[test]
+    fn test_best_text_color_empty() {
+        // Arrange
+        let background: Color<f32> = Color::from_str("#1A1A2E").unwrap();
+
+        // Act
+        let actual = background.best_text_color(&[]);
+
+        // Assert
+        assert_eq!(actual.to_hex_string(), "#FFFFFF");
+    }
+
+    #[rstest]
+    #[case::graphic_arts_blue(
+        (50.0, 2.6772, -79.7751),
+        (50.0, 0.0, -82.7485),
+        Cie94Application::GraphicArts,
+        1.3950
+    )]
+    #[case::textiles_blue(
+        (50.0, 2.6772, -79.7751),
+        (50.0, 0.0, -82.7485),
+        Cie94Application::Textiles,
+        1.4230
+    )]
+    #[case::graphic_arts_lightness(
+        (50.0, 2.5, 0.0),
+        (73.0, 25.0, -18.0),
+        Cie94Application::GraphicArts,
+        34.6892
+    )]
+    #[case::textiles_lightness(
+        (50.0, 2.5, 0.0),
+        (73.0, 25.0, -18.0),
+        Cie94Application::Textiles,
+        28.2503
+    )]
+    #[case::graphic_arts_green(
+        (60.2574, -34.0099, 36.2677),
+        (60.4626, -34.1751, 39.4387),
+        Cie94Application::GraphicArts,
+        1.3910
+    )]
+    #[case::same(
+        (50.0, 20.0, -30.0),
+        (50.0, 20.0, -30.0),
+        Cie94Application::Textiles,
+        0.0
+    )]
+    fn test_delta_e_94(
+        #[case] input1: (f64, f64, f64),
+        #[case] input2: (f64, f64, f64),
+        #[case] application: Cie94Application,
+        #[case] expected: f64,
+    ) {
+        // Arrange
+        let color1: Color<f64> = Color::new(input1.0, input1.1, input1.2);
+        let color2: Color<f64> = Color::new(input2.0, input2.1, input2.2);
+
+        // Act
+        let actual = color1.delta_e_94(&color2, application);
+
+        // Assert
+        assert!((actual - expected).abs() < 1e-4, "{}", actual);
+    }
+
+    #[rstest]
+    #[case::pair01((50.0, 2.6772, -79.7751), (50.0, 0.0, -82.7485), 2.0425)]
+    #[case::pair02((50.0, 3.1571, -77.2803), (50.0, 0.0, -82.7485), 2.8615)]
+    #[case::pair03((50.0, 2.8361, -74.0200), (50.0, 0.0, -82.7485), 3.4412)]
+    #[case::pair04((50.0, -1.3802, -84.2814), (50.0, 0.0, -82.7485), 1.0000)]
+    #[case::pair05((50.0, -1.1848, -84.8006), (50.0, 0.0, -82.7485), 1.0000)]
+    #[case::pair06((50.0, -0.9009, -85.5211), (50.0, 0.0, -82.7485), 1.0000)]
+    #[case::pair07((50.0, 0.0, 0.0), (50.0, -1.0, 2.0), 2.3669)]
+    #[case::pair08((50.0, -1.0, 2.0), (50.0, 0.0, 0.0), 2.3669)]
+    #[case::pair09((50.0, 2.4900, -0.0010), (50.0, -2.4900, 0.0009), 7.1792)]
+    #[case::pair10((50.0, 2.4900, -0.0010), (50.0, -2.4900, 0.0010), 7.1792)]
+    #[case::pair11((50.0, 2.4900, -0.0010), (50.0, -2.4900, 0.0011), 7.2195)]
+    #[case::pair12((50.0, 2.4900, -0.0010), (50.0, -2.4900, 0.0012), 7.2195)]
+    #[case::pair13((50.0, -0.0010, 2.4900), (50.0, 0.0009, -2.4900), 4.8045)]
+    #[case::pair14((50.0, -0.0010, 2.4900), (50.0, 0.0010, -2.4900), 4.8045)]
+    #[case::pair15((50.0, -0.0010, 2.4900), (50.0, 0.0011, -2.4900), 4.7461)]
+    #[case::pair16((50.0, 2.5, 0.0), (50.0, 0.0, -2.5), 4.3065)]
+    #[case::pair17((50.0, 2.5, 0.0), (73.0, 25.0, -18.0), 27.1492)]
+    #[case::pair18((50.0, 2.5, 0.0), (61.0, -5.0, 29.0), 22.8977)]
+    #[case::pair19((50.0, 2.5, 0.0), (56.0, -27.0, -3.0), 31.9030)]
+    #[case::pair20((50.0, 2.5, 0.0), (58.0, 24.0, 15.0), 19.4535)]
+    #[case::pair21((50.0, 2.5, 0.0), (50.0, 3.1736, 0.5854), 1.0000)]
+    #[case::pair22((50.0, 2.5, 0.0), (50.0, 3.2972, 0.0), 1.0000)]
+    #[case::pair23((50.0, 2.5, 0.0), (50.0, 1.8634, 0.5757), 1.0000)]
+    #[case::pair24((50.0, 2.5, 0.0), (50.0, 3.2592, 0.3350), 1.0000)]
+    #[case::pair25((60.2574, -34.0099, 36.2677), (60.4626, -34.1751, 39.4387), 1.2644)]
+    #[case::pair26((63.0109, -31.0961, -5.8663), (62.8187, -29.7946, -4.0864), 1.2630)]
+    #[case::pair27((61.2901, 3.7196, -5.3901), (61.4292, 2.2480, -4.9620), 1.8731)]
+    #[case::pair28((35.0831, -44.1164, 3.7933), (35.0232, -40.0716, 1.5901), 1.8645)]
+    #[case::pair29((22.7233, 20.0904, -46.6940), (23.0331, 14.9730, -42.5619), 2.0373)]
+    #[case::pair30((36.4612, 47.8580, 18.3852), (36.2715, 50.5065, 21.2231), 1.4146)]
+    #[case::pair31((90.8027, -2.0831, 1.4410), (91.1528, -1.6435, 0.0447), 1.4441)]
+    #[case::pair32((90.9257, -0.5406, -0.9208), (88.6381, -0.8985, -0.7239), 1.5381)]
+    #[case::pair33((6.7747, -0.2908, -2.4247), (5.8714, -0.0985, -2.2286), 0.6377)]
+    #[case::pair34((2.0776, 0.0795, -1.1350), (0.9033, -0.0636, -0.5514), 0.9082)]
+    fn test_delta_e_2000(
+        #[case] input1: (f64, f64, f64),
+        #[case] input2: (f64, f64, f64),
+        #[case] expected: f64,
+    ) {
+        // Arrange
+        let color1: Color<f64> = Color::new(input1.0, input1.1, input1.2);
+        let color2: Color<f64> = Color::new(input2.0, input2.1, input2.2);
+
+        // Act
+        let actual = color1.delta_e_2000(&color2);
+
+        // Assert
+        assert!((actual - expected).abs() < 1e-4, "{}", actual);
+        assert!((actual - color2.delta_e_2000(&color1)).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_delta_from() {
+        // Arrange
+        let color1: Color<f32> = Color::new(60.0, 20.0, -30.0);
+        let color2: Color<f32> = Color::new(50.0, 25.0, -10.0);
+
+        // Act
+        let actual = color1.delta_from(&color2);
+
+        // Assert
+        assert_eq!(actual, [10.0, -5.0, -20.0]);
+    }
+
+    #[test]
+    fn test_apply_delta() {
+        // Arrange
+        let reference: Color<f32> = Color::new(50.0, 10.0, 10.0);
+        let lighter: Color<f32> = Color::new(65.0, 10.0, 10.0);
+        let delta = lighter.delta_from(&reference);
+
+        // Act
+        let color: Color<f32> = Color::new(30.0, -20.0, 40.0);
+        let actual = color.apply_delta(delta);
+
+        // Assert
+        assert_eq!(actual.lightness(), 45.0);
+        assert_eq!(actual.a, -20.0);
+        assert_eq!(actual.b, 40.0);
+        assert_eq!(reference.apply_delta(delta), lighter);
+    }
+
+    #[test]
+    fn test_apply_delta_overshoot() {
+        // Arrange
+        let color: Color<f32> = Color::new(80.0, 100.0, -100.0);
+
+        // Act
+        let actual = color.apply_delta([50.0, 60.0, -60.0]);
+
+        // Assert
+        assert_eq!(actual.lightness(), 100.0);
+        assert_eq!(actual.a, 127.0);
+        assert_eq!(actual.b, -128.0);
+    }
+
+    #[rstest]
+    #[case::start(0.0, "#FF0000")]
+    #[case::end(1.0, "#0000FF")]
+    #[case::below(-0.5, "#FF0000")]
+    #[case::above(1.5, "#0000FF")]
+    fn test_mix(#[case] fraction: f32, #[case] expected: &str) {
+        // Arrange
+        let color1: Color<f32> = Color::from_str("#FF0000").unwrap();
+        let color2: Color<f32> = Color::from_str("#0000FF").unwrap();
+
+        // Act
+        let actual = color1.mix(&color2, fraction);
+
+        // Assert
+        assert_eq!(actual.to_hex_string(), expected);
+    }
+
+    #[test]
+    fn test_mix_midpoint() {
+        // Arrange
+        let color1: Color<f32> = Color::new(20.0, -40.0, 10.0);
+        let color2: Color<f32> = Color::new(80.0, 40.0, 30.0);
+
+        // Act
+        let actual = color1.mix(&color2, 0.5);
+
+        // Assert
+        assert!((actual.l - 50.0).abs() < 1e-3);
+        assert!((actual.a - 0.0).abs() < 1e-3);
+        assert!((actual.b - 20.0).abs() < 1e-3);
+    }
+
+    #[rstest]
+    #[case::start(0.0, "#FF0000")]
+    #[case::midpoint(0.5, "#800080")]
+    #[case::end(1.0, "#0000FF")]
+    fn test_mix_rgb(#[case] fraction: f32, #[case] expected: &str) {
+        // Arrange
+        let red: Color<f32> = Color::from_str("#FF0000").unwrap();
+        let blue: Color<f32> = Color::from_str("#0000FF").unwrap();
+
+        // Act
+        let actual = red.mix_rgb(&blue, fraction);
+
+        // Assert
+        assert_eq!(actual.to_hex_string(), expected);
+    }
+
+    #[test]
+    fn test_mix_rgb_differs_from_mix() {
+        // Arrange
+        let red: Color<f32> = Color::from_str("#FF0000").unwrap();
+        let blue: Color<f32> = Color::from_str("#0000FF").unwrap();
+
+        // Act
+        let actual = red.mix_rgb(&blue, 0.5);
+
+        // Assert
+        let lab_mixed = red.mix(&blue, 0.5);
+        assert_ne!(actual.to_hex_string(), lab_mixed.to_hex_string());
+        assert!(actual.lightness() < lab_mixed.lightness());
+    }
+
+    #[rstest]
+    #[case::transparent(0.0, (255, 255, 255))]
+    #[case::half(0.5, (255, 188, 188))]
+    #[case::opaque(1.0, (255, 0, 0))]
+    #[case::above(1.5, (255, 0, 0))]
+    fn test_blend_over(#[case] alpha: f32, #[case] expected: (u8, u8, u8)) {
+        // Arrange
+        let red: Color<f32> = Color::from_str("#FF0000").unwrap();
+        let white: Color<f32> = Color::from_str("#FFFFFF").unwrap();
+
+        // Act
+        let actual = red.blend_over(&white, alpha);
+
+        // Assert
+        let rgb = actual.to_rgb();
+        assert!(rgb.r.abs_diff(expected.0) <= 1);
+        assert!(rgb.g.abs_diff(expected.1) <= 1);
+        assert!(rgb.b.abs_diff(expected.2) <= 1);
+    }
+
+    #[test]
+    fn test_mix_preserve_hue() {
+        // Arrange
+        let red: Color<f32> = Color::from_str("#FF0000").unwrap();
+        let cyan: Color<f32> = Color::from_str("#00FFFF").unwrap();
+
+        // Act
+        let actual = red.mix_preserve_hue(&cyan, 0.5);
+
+        // Assert
+        let expected_chroma = (red.chroma() + cyan.chroma()) / 2.0;
+        assert!((actual.chroma() - expected_chroma).abs() < 1e-3);
+        assert!((actual.lightness() - (red.l + cyan.l) / 2.0).abs() < 1e-3);
+        assert!(actual.chroma() > red.mix(&cyan, 0.5).chroma());
+    }
+
+    #[test]
+    fn test_mix_lch() {
+        // Arrange
+        let red: Color<f32> = Color::from_str("#FF0000").unwrap();
+        let orange: Color<f32> = Color::from_str("#FF8000").unwrap();
+
+        // Act
+        let short = red.mix_lch(&orange, 0.5, false);
+        let long = red.mix_lch(&orange, 0.5, true);
+
+        // Assert
+        let red_hue = red.hue().to_degrees();
+        let orange_hue = orange.hue().to_degrees();
+        let short_hue = short.hue().to_degrees();
+        assert!(short_hue > red_hue && short_hue < orange_hue);
+
+        // The long way passes through the far side of the hue circle.
+        let opposite = (red_hue + orange_hue) / 2.0 + 180.0;
+        assert!((long.to_lchab().h.to_degrees() - opposite).abs() < 1e-1);
+        assert_eq!(long.to_lchab().l, short.to_lchab().l);
+    }
+
+    #[rstest]
+    #[case::short(false)]
+    #[case::long(true)]
+    fn test_mix_lch_endpoints(#[case] long_way: bool) {
+        // Arrange
+        let red: Color<f32> = Color::from_str("#FF0000").unwrap();
+        let orange: Color<f32> = Color::from_str("#FF8000").unwrap();
+
+        // Act
+        let start = red.mix_lch(&orange, 0.0, long_way);
+        let end = red.mix_lch(&orange, 1.0, long_way);
+
+        // Assert
+        assert_eq!(start.to_hex_string(), "#FF0000");
+        assert_eq!(end.to_hex_string(), "#FF8000");
+    }
+
+    #[rstest]
+    #[case::start(0.0, "#FF0000")]
+    #[case::end(1.0, "#00FFFF")]
+    fn test_mix_preserve_hue_endpoints(#[case] fraction: f32, #[case] expected: &str) {
+        // Arrange
+        let red: Color<f32> = Color::from_str("#FF0000").unwrap();
+        let cyan: Color<f32> = Color::from_str("#00FFFF").unwrap();
+
+        // Act
+        let actual = red.mix_preserve_hue(&cyan, fraction);
+
+        // Assert
+        assert_eq!(actual.to_hex_string(), expected);
+    }
+
+    #[rstest]
+    #[case::lab(MixSpace::Lab)]
+    #[case::oklab(MixSpace::Oklab)]
+    #[case::luv(MixSpace::Luv)]
+    #[case::srgb(MixSpace::Srgb)]
+    #[case::linear_rgb(MixSpace::LinearRgb)]
+    fn test_ramp_to(#[case] space: MixSpace) {
+        // Arrange
+        let color1: Color<f32> = Color::from_str("#FF0000").unwrap();
+        let color2: Color<f32> = Color::from_str("#0000FF").unwrap();
+
+        // Act
+        let actual = color1.ramp_to(&color2, 5, space);
+
+        // Assert
+        assert_eq!(actual.len(), 5);
+        assert_eq!(actual[0].to_hex_string(), "#FF0000");
+        assert_eq!(actual[4].to_hex_string(), "#0000FF");
+        for window in actual.windows(2) {
+            assert_ne!(window[0].to_hex_string(), window[1].to_hex_string());
+        }
+    }
+
+    #[rstest]
+    #[case::zero(0, 2)]
+    #[case::one(1, 2)]
+    #[case::two(2, 2)]
+    #[case::five(5, 5)]
+    fn test_gradient(#[case] steps: usize, #[case] expected: usize) {
+        // Arrange
+        let black: Color<f32> = Color::from_str("#000000").unwrap();
+        let white: Color<f32> = Color::from_str("#FFFFFF").unwrap();
+
+        // Act
+        let actual = black.gradient(&white, steps);
+
+        // Assert
+        assert_eq!(actual.len(), expected);
+        assert_eq!(actual[0], black);
+        assert_eq!(actual[expected - 1], white);
+        for (i, color) in actual.iter().enumerate() {
+            let expected_lightness = 100.0 * i as f32 / (expected - 1) as f32;
+            assert!((color.lightness() - expected_lightness).abs() < 1e-3);
+        }
+    }
+
+    #[test]
+    fn test_oklab_ramp_clipped() {
+        // Arrange
+        let red: Color<f32> = Color::from_str("#FF0000").unwrap();
+        let blue: Color<f32> = Color::from_str("#0000FF").unwrap();
+
+        // Act
+        let actual = red.oklab_ramp_clipped(&blue, 9);
+
+        // Assert
+        assert_eq!(actual.len(), 9);
+        assert_eq!(actual[0].to_hex_string(), "#FF0000");
+        assert_eq!(actual[8].to_hex_string(), "#0000FF");
+        assert!(actual.iter().all(Color::is_in_srgb_gamut));
+        let start = red.to_oklab().l;
+        let step = (blue.to_oklab().l - start) / 8.0;
+        for (i, color) in actual.iter().enumerate() {
+            let expected_lightness = start + step * i as f32;
+            assert!((color.to_oklab().l - expected_lightness).abs() < 0.01);
+        }
+    }
+
+    #[rstest]
+    #[case::empty(0)]
+    #[case::single(1)]
+    fn test_oklab_ramp_clipped_small(#[case] n: usize) {
+        // Arrange
+        let red: Color<f32> = Color::from_str("#FF0000").unwrap();
+        let blue: Color<f32> = Color::from_str("#0000FF").unwrap();
+
+        // Act
+        let actual = red.oklab_ramp_clipped(&blue, n);
+
+        // Assert
+        assert_eq!(actual, red.ramp_to(&blue, n, MixSpace::Oklab));
+    }
+
+    #[test]
+    fn test_ramp_to_linear_rgb_brighter_than_srgb() {
+        // Arrange
+        let red: Color<f32> = Color::from_str("#FF0000").unwrap();
+        let green: Color<f32> = Color::from_str("#00FF00").unwrap();
+
+        // Act
+        let linear = red.ramp_to(&green, 3, MixSpace::LinearRgb);
+        let gamma = red.ramp_to(&green, 3, MixSpace::Srgb);
+
+        // Assert
+        assert_eq!(gamma[1].to_hex_string(), "#808000");
+        assert!(linear[1].lightness() > gamma[1].lightness());
+        assert_eq!(linear[0].to_hex_string(), gamma[0].to_hex_string());
+        assert_eq!(linear[2].to_hex_string(), gamma[2].to_hex_string());
+    }
+
+    #[rstest]
+    #[case::empty(0, vec![])]
+    #[case::single(1, vec!["#FF0000"])]
+    #[case::pair(2, vec!["#FF0000", "#0000FF"])]
+    fn test_ramp_to_small(#[case] n: usize, #[case] expected: Vec<&str>) {
+        // Arrange
+        let color1: Color<f32> = Color::from_str("#FF0000").unwrap();
+        let color2: Color<f32> = Color::from_str("#0000FF").unwrap();
+
+        // Act
+        let actual = color1.ramp_to(&color2, n, MixSpace::default());
+
+        // Assert
+        let actual: Vec<String> = actual.iter().map(Color::to_hex_string).collect();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_to_hex_string() {
+        // Act
+        let color: Color<f32> = Color::new(91.1120, -48.0806, -14.1521);
+        let actual = color.to_hex_string();
+
+        // Assert
+        assert_eq!(actual, "#00FFFF");
+    }
+
+    #[rstest]
+    #[case::red("#FF0000", "red")]
+    #[case::cyan("#00FFFF", "aqua")]
+    #[case::cornflowerblue("#6495ED", "cornflowerblue")]
+    #[case::arbitrary("#2C7DE7", "#2C7DE7")]
+    fn test_to_css(#[case] input: &str, #[case] expected: &str) {
+        // Act
+        let color: Color<f32> = Color::from_str(input).unwrap();
+        let actual = color.to_css();
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+
+    #[rstest]
+    #[case::red("#FF0000", "red")]
+    #[case::white("#FFFFFF", "white")]
+    #[case::near_black("#010203", "black")]
+    #[case::azure("#2C7DE7", "dodgerblue")]
+    fn test_nearest_named(#[case] input: &str, #[case] expected: &str) {
+        // Arrange
+        let color: Color<f32> = Color::from_str(input).unwrap();
+
+        // Act
+        let actual = color.nearest_named();
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+
+    #[rstest]
+    #[case::red("#FF0000")]
+    #[case::green("#00FF00")]
+    #[case::blue("#0000FF")]
+    #[case::magenta("#FF00FF")]
+    fn test_oklch_normalized_max_chroma(#[case] input: &str) {
+        // Arrange
+        let color: Color<f32> = Color::from_str(input).unwrap();
+
+        // Act
+        let (l, c, h) = color.oklch_normalized();
+
+        // Assert
+        let oklch = color.to_oklch();
+        assert!((l - oklch.l).abs() < 1e-6);
+        assert!((c - 1.0).abs() < 1e-2);
+        assert!((h - oklch.h.to_degrees()).abs() < 1e-6);
+    }
+
+    #[rstest]
+    #[case::black("#000000", 0.0)]
+    #[case::white("#FFFFFF", 0.0)]
+    #[case::gray("#808080", 0.0)]
+    #[case::azure("#2C7DE7", 0.837)]
+    fn test_oklch_normalized(#[case] input: &str, #[case] expected: f32) {
+        // Arrange
+        let color: Color<f32> = Color::from_str(input).unwrap();
+
+        // Act
+        let (l, c, h) = color.oklch_normalized();
+
+        // Assert
+        assert!((0.0..=1.0).contains(&l));
+        assert!((c - expected).abs() < 1e-2);
+        assert!((0.0..360.0).contains(&h));
+    }
+
+    #[test]
+    fn test_to_rgb() {
+        // Act
+        let color: Color<f32> = Color::new(91.1120, -48.0806, -14.1521);
+        let actual = color.to_rgb();
+
+        // Assert
+        assert_eq!(actual, RGB::new(0, 255, 255));
+    }
+
+    #[rstest]
+    #[case::black(0.0, 0.0, 0.0, true)]
+    #[case::white(100.0, 0.0, 0.0, true)]
+    #[case::azure(52.917, 13.586, -60.474, true)]
+    #[case::vivid_green(60.0, -120.0, 80.0, false)]
+    #[case::vivid_magenta(50.0, 120.0, -110.0, false)]
+    #[case::light_blue(95.0, 0.0, -60.0, false)]
+    fn test_is_in_srgb_gamut(
+        #[case] l: f32,
+        #[case] a: f32,
+        #[case] b: f32,
+        #[case] expected: bool,
+    ) {
+        // Act
+        let color: Color<f32> = Color::from_lab(l, a, b);
+        let actual = color.is_in_srgb_gamut();
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_to_srgb_gamut_mapped_in_gamut() {
+        // Arrange
+        let color: Color<f32> = Color::from_str("#2C7DE7").unwrap();
+
+        // Act
+        let actual = color.to_srgb_gamut_mapped();
+
+        // Assert
+        assert_eq!(actual, color.to_rgb());
+    }
+
+    #[rstest]
+    #[case::muted_red("#A05A5A")]
+    #[case::azure("#2C7DE7")]
+    #[case::olive("#808000")]
+    #[case::pastel_blue("#AEC6CF")]
+    fn test_maximize_chroma(#[case] input: &str) {
+        // Arrange
+        let color: Color<f32> = Color::from_str(input).unwrap();
+
+        // Act
+        let actual = color.maximize_chroma();
+
+        // Assert
+        assert!(actual.chroma() >= color.chroma());
+        assert!(actual.is_in_srgb_gamut());
+        assert!((actual.lightness() - color.lightness()).abs() < 1e-3);
+        let hue_diff = (actual.hue().to_degrees() - color.hue().to_degrees()).abs();
+        assert!(hue_diff.min(360.0 - hue_diff) < 1e-2);
     }
 
-    /// Converts this color to the 8-bit ANSI 256 color space.
-    ///
-    /// # Returns
-    /// The converted `Ansi256` color.
-    #[must_use]
-    pub fn to_ansi256(&self) -> Ansi256 {
-        let rgb = self.to_rgb();
-        Ansi256::from(&rgb)
+    #[test]
+    fn test_maximize_chroma_increases_chroma() {
+        // Arrange
+        let color: Color<f32> = Color::from_str("#A05A5A").unwrap();
+
+        // Act
+        let actual = color.maximize_chroma();
+
+        // Assert
+        assert!(actual.chroma() > color.chroma() + 10.0);
     }
-}
 
-impl<T> Display for Color<T>
-where
-    T: FloatNumber,
-{
-    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        write!(
-            f,
-            "Color(l: {:.2}, a: {:.2}, b: {:.2})",
-            self.l, self.a, self.b
-        )
+    #[test]
+    fn test_maximize_chroma_neutral() {
+        // Arrange
+        let color: Color<f32> = Color::from_lab(50.0, 0.0, 0.0);
+
+        // Act
+        let actual = color.maximize_chroma();
+
+        // Assert
+        assert_eq!(actual, color);
     }
-}
 
-impl<T> FromStr for Color<T>
-where
-    T: FloatNumber,
-{
-    type Err = &'static str;
+    #[rstest]
+    #[case::vivid_green(60.0, -120.0, 80.0)]
+    #[case::vivid_magenta(50.0, 120.0, -110.0)]
+    #[case::light_blue(95.0, 0.0, -60.0)]
+    fn test_to_srgb_gamut_mapped_out_of_gamut(#[case] l: f32, #[case] a: f32, #[case] b: f32) {
+        // Arrange
+        let color: Color<f32> = Color::from_lab(l, a, b);
 
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        if s.len() != 7 || !s.starts_with('#') {
-            return Err("Invalid color format");
-        }
+        // Act
+        let actual = color.to_srgb_gamut_mapped();
 
-        let r = u8::from_str_radix(&s[1..3], 16).map_err(|_| "Invalid hex value")?;
-        let g = u8::from_str_radix(&s[3..5], 16).map_err(|_| "Invalid hex value")?;
-        let b = u8::from_str_radix(&s[5..7], 16).map_err(|_| "Invalid hex value")?;
+        // Assert
+        let mapped = Color::<f32>::from_xyz(&XYZ::from(&actual));
+        assert!((mapped.lightness() - color.lightness()).abs() < 1.0);
+        assert!(mapped.chroma() < color.chroma());
 
-        let (x, y, z) = rgb_to_xyz::<T>(r, g, b);
-        let (l, a, b) = xyz_to_lab::<T, D65>(x, y, z);
-        Ok(Self::new(l, a, b))
-    }
-}
+        let hue_diff = (mapped.hue().to_degrees() - color.hue().to_degrees()).abs();
+        assert!(hue_diff.min(360.0 - hue_diff) < 3.0);
 
-#[cfg(test)]
-mod tests {
-    use rstest::rstest;
+        // Per-channel clipping shifts the hue more than the gamut mapping.
+        let clipped = Color::<f32>::from_xyz(&XYZ::from(&color.to_rgb()));
+        let clipped_diff = (clipped.hue().to_degrees() - color.hue().to_degrees()).abs();
+        assert!(hue_diff.min(360.0 - hue_diff) <= clipped_diff.min(360.0 - clipped_diff));
+    }
 
-    use super::*;
+    #[rstest]
+    #[case::black("#000000", (0.0, 0.0, 0.0))]
+    #[case::white("#FFFFFF", (1.0, 1.0, 1.0))]
+    #[case::red("#FF0000", (1.0, 0.0, 0.0))]
+    #[case::gray("#808080", (0.215_861, 0.215_861, 0.215_861))]
+    fn test_to_linear_rgb(#[case] input: &str, #[case] expected: (f64, f64, f64)) {
+        // Arrange
+        let color: Color<f64> = Color::from_str(input).unwrap();
 
-    #[test]
-    fn test_new() {
         // Act
-        let actual: Color<f32> = Color::new(80.0, 0.0, 0.0);
+        let (r, g, b) = color.to_linear_rgb();
 
         // Assert
-        assert_eq!(actual.l, 80.0);
-        assert_eq!(actual.a, 0.0);
-        assert_eq!(actual.b, 0.0);
+        assert!((r - expected.0).abs() < 1e-3, "{r} != {}", expected.0);
+        assert!((g - expected.1).abs() < 1e-3, "{g} != {}", expected.1);
+        assert!((b - expected.2).abs() < 1e-3, "{b} != {}", expected.2);
     }
 
     #[rstest]
-    #[case((0.0, 0.0, 0.0), false)]
-    #[case((50.0, 0.0, 0.0), false)]
-    #[case((50.1, 0.0, 0.0), true)]
-    #[case((80.0, 0.0, 0.0), true)]
-    fn test_color_is_light(#[case] input: (f32, f32, f32), #[case] expected: bool) {
+    #[case::black("#000000")]
+    #[case::gray("#808080")]
+    #[case::azure("#2C7DE7")]
+    #[case::orange("#FF8000")]
+    #[case::teal("#149972")]
+    fn test_from_linear_rgb_round_trip(#[case] input: &str) {
+        // Arrange
+        let color: Color<f64> = Color::from_str(input).unwrap();
+        let (r, g, b) = color.to_linear_rgb();
+
         // Act
-        let color: Color<f32> = Color::new(input.0, input.1, input.2);
-        let actual = color.is_light();
+        let actual = Color::<f64>::from_linear_rgb(r, g, b);
 
         // Assert
-        assert_eq!(actual, expected);
+        let (actual_r, actual_g, actual_b) = actual.to_linear_rgb();
+        assert!((actual_r - r).abs() < 1e-5);
+        assert!((actual_g - g).abs() < 1e-5);
+        assert!((actual_b - b).abs() < 1e-5);
+        assert_eq!(actual.to_hex_string(), input);
     }
 
     #[rstest]
-    #[case((0.0, 0.0, 0.0), true)]
-    #[case((50.0, 0.0, 0.0), true)]
-    #[case((50.1, 0.0, 0.0), false)]
-    #[case((80.0, 0.0, 0.0), false)]
-    fn test_color_is_dark(#[case] input: (f32, f32, f32), #[case] expected: bool) {
+    #[case::black("#000000", 0x0000)]
+    #[case::white("#FFFFFF", 0xFFFF)]
+    #[case::red("#FF0000", 0xF800)]
+    #[case::green("#00FF00", 0x07E0)]
+    #[case::blue("#0000FF", 0x001F)]
+    #[case::orange("#FF8000", 0xFC00)]
+    fn test_to_rgb565(#[case] input: &str, #[case] expected: u16) {
+        // Arrange
+        let color: Color<f32> = Color::from_str(input).unwrap();
+
         // Act
-        let color: Color<f32> = Color::new(input.0, input.1, input.2);
-        let actual = color.is_dark();
+        let actual = color.to_rgb565();
 
         // Assert
         assert_eq!(actual, expected);
     }
 
-    #[test]
-    fn test_lightness() {
+    #[rstest]
+    #[case::black("#000000", "#000000")]
+    #[case::white("#FFFFFF", "#FFFFFF")]
+    #[case::web_safe("#3366CC", "#3366CC")]
+    #[case::orange("#FF8000", "#FF9900")]
+    #[case::azure("#2C7DE7", "#3366FF")]
+    #[case::gray("#191A1A", "#003333")]
+    fn test_to_web_safe(#[case] input: &str, #[case] expected: &str) {
+        // Arrange
+        let color: Color<f32> = Color::from_str(input).unwrap();
+
         // Act
-        let color: Color<f32> = Color::new(91.1120, -48.0806, -14.1521);
-        let actual = color.lightness();
+        let actual = color.to_web_safe();
 
         // Assert
-        assert_eq!(actual, 91.1120);
+        assert_eq!(actual.to_hex_string(), expected);
     }
 
-    #[test]
-    fn test_chroma() {
+    #[rstest]
+    #[case::black("#000000")]
+    #[case::white("#FFFFFF")]
+    #[case::red("#FF0000")]
+    #[case::orange("#FF8000")]
+    #[case::azure("#2C7DE7")]
+    #[case::gray("#7F7F7F")]
+    fn test_from_rgb565(#[case] input: &str) {
+        // Arrange
+        let color: Color<f32> = Color::from_str(input).unwrap();
+
         // Act
-        let color: Color<f32> = Color::new(91.1120, -48.0806, -14.1521);
-        let actual = color.chroma();
+        let actual: Color<f32> = Color::from_rgb565(color.to_rgb565());
 
         // Assert
-        assert!((actual - 50.120_117).abs() < 1e-3);
+        let expected = color.to_rgb();
+        let actual = actual.to_rgb();
+        assert!(actual.r.abs_diff(expected.r) <= 4);
+        assert!(actual.g.abs_diff(expected.g) <= 2);
+        assert!(actual.b.abs_diff(expected.b) <= 4);
     }
 
     #[rstest]
-    #[case::black((0.0, 0.0, 0.0), 0.0)]
-    #[case::white((100.0, - 0.002_443, 0.011_384), 102.111_946)]
-    #[case::red((53.237_144, 80.088_320, 67.199_460), 39.998_900)]
-    #[case::green((87.735_535, - 86.183_550, 83.179_924), 136.016_020)]
-    #[case::blue((32.300_800, 79.194_260, - 107.868_910), 306.28503)]
-    #[case::cyan((91.114_750, - 48.080_950, - 14.142_858), 196.391_080,)]
-    #[case::magenta((60.322_700, 98.235_580, - 60.842_370), 328.227_940,)]
-    #[case::yellow((97.138_580, - 21.562_368, 94.476_760), 102.856_380)]
-    fn test_hue(#[case] input: (f32, f32, f32), #[case] expected: f32) {
+    #[case::red("#FF0000", (12.177, 100.0, 53.237))]
+    #[case::blue("#0000FF", (265.874, 100.0, 32.301))]
+    #[case::gray("#808080", (0.0, 0.0, 53.585))]
+    fn test_to_hsluv(#[case] input: &str, #[case] expected: (f32, f32, f32)) {
+        // Arrange
+        let color: Color<f32> = Color::from_str(input).unwrap();
+
         // Act
-        let color: Color<f32> = Color::new(input.0, input.1, input.2);
-        let actual = color.hue();
+        let actual = color.to_hsluv();
 
         // Assert
-        assert!((actual.to_degrees() - expected).abs() < 1e-3);
+        if expected.1 > 0.0 {
+            assert!((actual.h.to_degrees() - expected.0).abs() < 1e-1);
+        }
+        assert!((actual.s - expected.1).abs() < 1e-1);
+        assert!((actual.l - expected.2).abs() < 1e-1);
     }
 
-    #[test]
-    fn test_to_hex_string() {
+    #[rstest]
+    #[case::red("#FF0000")]
+    #[case::blue("#0000FF")]
+    #[case::azure("#2C7DE7")]
+    #[case::gray("#808080")]
+    fn test_from_hsluv(#[case] input: &str) {
+        // Arrange
+        let color: Color<f32> = Color::from_str(input).unwrap();
+
         // Act
-        let color: Color<f32> = Color::new(91.1120, -48.0806, -14.1521);
-        let actual = color.to_hex_string();
+        let actual: Color<f32> = Color::from_hsluv(&color.to_hsluv());
 
         // Assert
-        assert_eq!(actual, "#00FFFF");
+        assert_eq!(actual.to_hex_string(), input);
     }
 
     #[test]
-    fn test_to_rgb() {
+    fn test_from_hsluv_equal_saturation() {
         // Act
-        let color: Color<f32> = Color::new(91.1120, -48.0806, -14.1521);
-        let actual = color.to_rgb();
+        let colors: Vec<Color<f32>> = [0.0, 60.0, 120.0, 180.0, 240.0, 300.0]
+            .iter()
+            .map(|&h| Color::from_hsluv(&HSLuv::new(h, 80.0, 60.0)))
+            .collect();
 
         // Assert
-        assert_eq!(actual, RGB::new(0, 255, 255));
+        for color in &colors {
+            // Equal HSLuv lightness yields equal perceived lightness regardless of the hue.
+            assert!((color.lightness() - 60.0).abs() < 0.5);
+            assert!((color.to_hsluv().s - 80.0).abs() < 1.0);
+        }
     }
 
     #[test]
@@ -500,6 +3135,18 @@ mod tests {
         assert!((actual.v - 1.0).abs() < 1e-3);
     }
 
+    #[test]
+    fn test_to_hwb() {
+        // Act
+        let color: Color<f32> = Color::new(91.1120, -48.0806, -14.1521);
+        let actual = color.to_hwb();
+
+        // Assert
+        assert!((actual.h.to_degrees() - 180.0).abs() < 1e-3);
+        assert!(actual.w.abs() < 1e-3);
+        assert!(actual.b.abs() < 1e-3);
+    }
+
     #[test]
     fn test_to_xyz() {
         // Act
@@ -572,6 +3219,74 @@ mod tests {
         assert!((actual.h.to_degrees() - 194.82).abs() < 1e-3);
     }
 
+    #[rstest]
+    #[case::white("#FFFFFF", (100.0, 0.0, 0.0))]
+    #[case::red("#FF0000", (54.291, 80.805, 69.891))]
+    #[case::azure("#2C7DE7", (51.957, 5.158, -61.768))]
+    fn test_adapt(#[case] input: &str, #[case] expected: (f32, f32, f32)) {
+        // Arrange
+        let color: Color<f32> = Color::from_str(input).unwrap();
+
+        // Act
+        let actual: Color<f32, D50> = color.adapt();
+
+        // Assert
+        let lab = actual.to_lab();
+        assert!(
+            (lab.l - expected.0).abs() < 2e-2,
+            "{} != {}",
+            lab.l,
+            expected.0
+        );
+        assert!(
+            (lab.a - expected.1).abs() < 2e-2,
+            "{} != {}",
+            lab.a,
+            expected.1
+        );
+        assert!(
+            (lab.b - expected.2).abs() < 2e-2,
+            "{} != {}",
+            lab.b,
+            expected.2
+        );
+    }
+
+    #[test]
+    fn test_adapt_round_trip() {
+        // Arrange
+        let color: Color<f64> = Color::from_str("#2C7DE7").unwrap().with_alpha(0.5);
+
+        // Act
+        let actual: Color<f64> = color.adapt::<D50>().adapt();
+
+        // Assert
+        assert!((actual.l - color.l).abs() < 1e-4);
+        assert!((actual.a - color.a).abs() < 1e-4);
+        assert!((actual.b - color.b).abs() < 1e-4);
+        assert_eq!(actual.alpha(), Some(0.5));
+    }
+
+    #[rstest]
+    #[case::rgb(ColorSpace::Rgb, vec![44.0, 125.0, 231.0])]
+    #[case::cmyk(ColorSpace::Cmyk, vec![0.810, 0.459, 0.0, 0.094])]
+    #[case::lab(ColorSpace::Lab, vec![52.918, 13.586, -60.474])]
+    #[case::hsl(ColorSpace::Hsl, vec![214.011, 0.796, 0.539])]
+    #[case::oklch(ColorSpace::Oklch, vec![0.598, 0.178, 256.941])]
+    fn test_to_components(#[case] space: ColorSpace, #[case] expected: Vec<f32>) {
+        // Arrange
+        let color: Color<f32> = Color::from_str("#2C7DE7").unwrap();
+
+        // Act
+        let actual = color.to_components(space);
+
+        // Assert
+        assert_eq!(actual.len(), space.num_components());
+        for (actual, expected) in actual.iter().zip(expected.iter()) {
+            assert!((actual - expected).abs() < 1e-3, "{actual} != {expected}");
+        }
+    }
+
     #[test]
     fn test_to_lchab() {
         // Act
@@ -633,6 +3348,47 @@ mod tests {
         assert!((actual.b - b).abs() < 1e-3);
     }
 
+    #[rstest]
+    #[case::lowercase("#2c7de7", "#2C7DE7")]
+    #[case::mixed_case("#2c7De7", "#2C7DE7")]
+    #[case::surrounding_whitespace("  #2C7DE7\n", "#2C7DE7")]
+    #[case::alpha_whitespace("\t#2c7de780 ", "#2C7DE780")]
+    #[case::css_function_whitespace(" rgb(255, 128, 0) ", "#FF8000")]
+    fn test_from_str_tolerant(#[case] input: &str, #[case] expected: &str) {
+        // Act
+        let actual: Color<f32> = Color::from_str(input).unwrap();
+
+        // Assert
+        assert_eq!(actual.to_hex_string(), expected);
+    }
+
+    proptest! {
+        #[test]
+        fn test_from_str_never_panics(input in any::<String>()) {
+            // Act & Assert
+            let _ = Color::<f32>::from_str(&input);
+        }
+
+        #[test]
+        fn test_from_str_hex_like_never_panics(input in "[ #0-9a-fA-F+\\-\u{e9}]{0,12}") {
+            // Act & Assert
+            let _ = Color::<f32>::from_str(&input);
+        }
+
+        #[test]
+        fn test_from_str_round_trip(r in any::<u8>(), g in any::<u8>(), b in any::<u8>(), lowercase in any::<bool>()) {
+            // Arrange
+            let expected = format!("#{:02X}{:02X}{:02X}", r, g, b);
+            let input = if lowercase { expected.to_lowercase() } else { expected.clone() };
+
+            // Act
+            let actual: Color<f32> = Color::from_str(&input).unwrap();
+
+            // Assert
+            prop_assert_eq!(actual.to_hex_string(), expected);
+        }
+    }
+
     #[rstest]
     #[case::empty("")]
     #[case::invalid("123456")]
@@ -641,6 +3397,10 @@ mod tests {
     #[case::invalid_hex_red("#GGAA99")]
     #[case::invalid_hex_green("#00GG99")]
     #[case::invalid_hex_blue("#00AAGG")]
+    #[case::invalid_hex_alpha("#00AA99GG")]
+    #[case::signed_hex("#+F+F+F")]
+    #[case::multi_byte_char("#0\u{e9}000")]
+    #[case::inner_whitespace("# 00AA9")]
     fn test_from_str_error(#[case] input: &str) {
         // Act
         let actual = Color::<f32>::from_str(input);
@@ -648,4 +3408,188 @@ mod tests {
         // Assert
         assert!(actual.is_err());
     }
+
+    #[rstest]
+    #[case::rgb_comma("rgb(255, 128, 0)", "#FF8000")]
+    #[case::rgb_whitespace("rgb(255 128 0)", "#FF8000")]
+    #[case::rgb_percent("rgb(100%, 50%, 0%)", "#FF8000")]
+    #[case::rgba("rgba(255,128,0,0.5)", "#FF800080")]
+    #[case::rgba_slash("rgb(255 128 0 / 50%)", "#FF800080")]
+    #[case::hsl("hsl(210, 50%, 40%)", "#336699")]
+    #[case::hsl_whitespace("hsl(210deg 50% 40%)", "#336699")]
+    #[case::hsla("hsla(0, 100%, 50%, 0.3)", "#FF00004D")]
+    fn test_from_str_css_function(#[case] input: &str, #[case] expected: &str) {
+        // Act
+        let actual: Color<f32> = Color::from_str(input).unwrap();
+
+        // Assert
+        assert_eq!(actual.to_hex_string(), expected);
+    }
+
+    #[rstest]
+    #[case::unknown_function("cmyk(0, 0, 0, 0)")]
+    #[case::unclosed("rgb(255, 128, 0")]
+    #[case::missing_component("rgb(255, 128)")]
+    #[case::extra_component("rgba(255, 128, 0, 0.5, 1)")]
+    #[case::invalid_rgb_value("rgb(255, abc, 0)")]
+    #[case::invalid_hsl_value("hsl(210, 50%, abc)")]
+    #[case::invalid_alpha_value("rgba(255, 128, 0, abc)")]
+    #[case::nan_red("rgb(nan, 0, 0)")]
+    #[case::inf_green("rgb(0, inf, 0)")]
+    #[case::infinity_blue("rgb(0, 0, -infinity)")]
+    #[case::nan_percent("rgb(nan%, 0%, 0%)")]
+    #[case::nan_hue("hsl(nan, 50%, 50%)")]
+    #[case::inf_saturation("hsl(210, inf%, 50%)")]
+    #[case::nan_lightness("hsl(210deg, 50%, NaN%)")]
+    #[case::nan_rgb_alpha("rgba(0, 0, 0, nan)")]
+    #[case::inf_rgb_alpha("rgb(0 0 0 / inf%)")]
+    #[case::nan_hsl_alpha("hsla(210, 50%, 50%, nan)")]
+    fn test_from_str_css_function_error(#[case] input: &str) {
+        // Act
+        let actual = Color::<f32>::from_str(input);
+
+        // Assert
+        assert!(actual.is_err());
+    }
+
+    #[rstest]
+    #[case::hash("#ff8000")]
+    #[case::prefix_lower("0xff8000")]
+    #[case::prefix_upper("0XFF8000")]
+    #[case::bare("ff8000")]
+    #[case::rgb("rgb(255, 128, 0)")]
+    #[case::leading_whitespace(" ff8000")]
+    #[case::trailing_newline("#ff8000\n")]
+    #[case::padded_rgb("\trgb(255, 128, 0) ")]
+    fn test_parse(#[case] input: &str) {
+        // Act
+        let actual: Color<f32> = Color::parse(input).unwrap();
+
+        // Assert
+        let expected: Color<f32> = Color::from_str("#ff8000").unwrap();
+        assert_eq!(actual, expected);
+    }
+
+    #[rstest]
+    #[case::hex(CssFormat::Hex, "#2C7DE7")]
+    #[case::hex_alpha(CssFormat::HexAlpha(128), "#2C7DE780")]
+    #[case::rgb(CssFormat::Rgb, "rgb(44, 125, 231)")]
+    #[case::rgb_percent(CssFormat::RgbPercent, "rgb(17.3%, 49.0%, 90.6%)")]
+    #[case::hsl(CssFormat::Hsl, "hsl(214, 80%, 54%)")]
+    fn test_to_css_string(#[case] format: CssFormat, #[case] expected: &str) {
+        // Arrange
+        let color: Color<f32> = Color::from_str("#2C7DE7").unwrap();
+
+        // Act
+        let actual = color.to_css_string(format);
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+
+    #[rstest]
+    #[case::bare("ff8000cc", "#FF8000CC")]
+    #[case::hash("#FF800080", "#FF800080")]
+    #[case::prefix("0xff800000", "#FF800000")]
+    fn test_parse_with_alpha(#[case] input: &str, #[case] expected: &str) {
+        // Act
+        let actual: Color<f32> = Color::parse(input).unwrap();
+
+        // Assert
+        assert!(actual.alpha().is_some());
+        assert_eq!(actual.to_hex_string(), expected);
+    }
+
+    #[rstest]
+    #[case::hex("#FF800080", 128.0 / 255.0)]
+    #[case::rgba("rgba(255, 128, 0, 0.5)", 0.5)]
+    #[case::rgba_percent("rgb(255 128 0 / 25%)", 0.25)]
+    #[case::hsla("hsla(0, 100%, 50%, 0.3)", 0.3)]
+    fn test_from_str_alpha(#[case] input: &str, #[case] expected: f32) {
+        // Act
+        let actual: Color<f32> = Color::from_str(input).unwrap();
+
+        // Assert
+        assert!((actual.alpha().unwrap() - expected).abs() < 1e-6);
+    }
+
+    #[rstest]
+    #[case::opaque(1.0, "#2C7DE7FF")]
+    #[case::half(0.5, "#2C7DE780")]
+    #[case::transparent(0.0, "#2C7DE700")]
+    #[case::clamped(1.5, "#2C7DE7FF")]
+    fn test_with_alpha(#[case] alpha: f32, #[case] expected: &str) {
+        // Arrange
+        let color: Color<f32> = Color::from_str("#2C7DE7").unwrap();
+
+        // Act
+        let actual = color.with_alpha(alpha);
+
+        // Assert
+        assert_eq!(color.alpha(), None);
+        assert_eq!(actual.to_hex_string(), expected);
+        assert_eq!(actual.to_css_string(CssFormat::HexAlpha(16)), "#2C7DE710");
+    }
+
+    #[rstest]
+    #[case::azure("#2C7DE7", "lab(52.92% 13.59 -60.47)")]
+    #[case::black("#000000", "lab(0.00% 0.00 0.00)")]
+    #[case::white("#FFFFFF", "lab(100.00% -0.00 0.01)")]
+    fn test_to_css_lab(#[case] input: &str, #[case] expected: &str) {
+        // Arrange
+        let color: Color<f32> = Color::from_str(input).unwrap();
+
+        // Act
+        let actual = color.to_css_lab();
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+
+    #[rstest]
+    #[case::azure("#2C7DE7")]
+    #[case::red("#FF0000")]
+    #[case::gray("#808080")]
+    fn test_parse_css_lab(#[case] input: &str) {
+        // Arrange
+        let color: Color<f32> = Color::from_str(input).unwrap();
+
+        // Act
+        let actual: Color<f32> = Color::parse(&color.to_css_lab()).unwrap();
+
+        // Assert
+        assert!(actual.delta_e(&color) < 1e-2);
+        assert_eq!(actual.to_hex_string(), input);
+    }
+
+    #[rstest]
+    #[case::missing_component("lab(52.92% 13.59)")]
+    #[case::extra_component("lab(52.92% 13.59 -60.47 1.0)")]
+    #[case::invalid_value("lab(52.92% abc -60.47)")]
+    #[case::unclosed("lab(52.92% 13.59 -60.47")]
+    #[case::nan_lightness("lab(nan% 0 0)")]
+    #[case::inf_a("lab(50% inf 0)")]
+    #[case::infinity_b("lab(50% 0 -infinity)")]
+    fn test_parse_css_lab_error(#[case] input: &str) {
+        // Act
+        let actual = Color::<f32>::parse(input);
+
+        // Assert
+        assert!(actual.is_err());
+    }
+
+    #[rstest]
+    #[case::empty("")]
+    #[case::prefix_only("0x")]
+    #[case::invalid_length("ff800")]
+    #[case::invalid_length_with_prefix("0xff80000")]
+    #[case::invalid_hex("0xGG8000")]
+    #[case::double_prefix("#0xff8000")]
+    fn test_parse_error(#[case] input: &str) {
+        // Act
+        let actual = Color::<f32>::parse(input);
+
+        // Assert
+        assert!(actual.is_err());
+    }
 }
@@ -29,6 +29,7 @@ use crate::{
 /// assert_eq!(format!("{}", xyz), "XYZ(0.15, 0.24, 0.20)");
 /// ```
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Oklab<T>
 where
     T: FloatNumber,
@@ -65,7 +66,12 @@ where
     T: FloatNumber,
 {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        write!(f, "Oklab({:.2}, {:.2}, {:.2})", self.l, self.a, self.b)
+        let precision = f.precision().unwrap_or(2);
+        write!(
+            f,
+            "Oklab({:.*}, {:.*}, {:.*})",
+            precision, self.l, precision, self.a, precision, self.b
+        )
     }
 }
 
@@ -68,11 +68,15 @@ where
     T: FloatNumber,
 {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let precision = f.precision().unwrap_or(2);
         write!(
             f,
-            "Oklch({:.2}, {:.2}, {:.2})",
+            "Oklch({:.*}, {:.*}, {:.*})",
+            precision,
             self.l,
+            precision,
             self.c,
+            precision,
             self.h.to_degrees()
         )
     }
@@ -1,7 +1,7 @@
 use std::{fmt, fmt::Display};
 
 use crate::{
-    color::{hsl::HSL, xyz::XYZ, HSV},
+    color::{hsl::HSL, linear_to_srgb, xyz::XYZ, HSV},
     math::FloatNumber,
 };
 
@@ -32,6 +32,7 @@ use crate::{
 /// assert_eq!(format!("{}", xyz), "XYZ(0.42, 0.22, 0.07)");
 /// ```
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RGB {
     pub r: u8,
     pub g: u8,
@@ -169,33 +170,60 @@ pub fn xyz_to_rgb<T>(x: T, y: T, z: T) -> (u8, u8, u8)
 where
     T: FloatNumber,
 {
-    let f = |t: T| -> T {
-        if t > T::from_f32(0.003_130_8) {
-            T::from_f32(1.055) * t.powf(T::from_f32(1.0 / 2.4)) - T::from_f32(0.055)
-        } else {
-            T::from_f32(12.92) * t
-        }
-    };
-
-    let r = f(T::from_f32(3.240_97) * x - T::from_f32(1.537_383) * y - T::from_f32(0.498_611) * z);
-    let g =
-        f(-T::from_f32(0.969_244) * x + T::from_f32(1.875_968) * y + T::from_f32(0.041_555) * z);
-    let b = f(T::from_f32(0.055_630) * x - T::from_f32(0.203_977) * y + T::from_f32(1.056_972) * z);
-
+    let (r, g, b) = xyz_to_linear_rgb(x, y, z);
     let max = RGB::max_value::<T>();
     (
-        (r * max).round().to_u8_unsafe(),
-        (g * max).round().to_u8_unsafe(),
-        (b * max).round().to_u8_unsafe(),
+        (linear_to_srgb(r) * max).round().to_u8_unsafe(),
+        (linear_to_srgb(g) * max).round().to_u8_unsafe(),
+        (linear_to_srgb(b) * max).round().to_u8_unsafe(),
     )
 }
 
+/// Converts the CIE XYZ color space to the linear-light RGB color space.
+///
+/// # Type Parameters
+/// * `T` - The floating point type.
+///
+/// # Arguments
+/// * `x` - The X component of the XYZ color.
+/// * `y` - The Y component of the XYZ color.
+/// * `z` - The Z component of the XYZ color.
+///
+/// # Returns
+/// The linear RGB components, which may be out of the range [0, 1] for out-of-gamut colors.
+#[inline]
+#[must_use]
+pub(crate) fn xyz_to_linear_rgb<T>(x: T, y: T, z: T) -> (T, T, T)
+where
+    T: FloatNumber,
+{
+    let r = T::from_f32(3.240_97) * x - T::from_f32(1.537_383) * y - T::from_f32(0.498_611) * z;
+    let g = -T::from_f32(0.969_244) * x + T::from_f32(1.875_968) * y + T::from_f32(0.041_555) * z;
+    let b = T::from_f32(0.055_630) * x - T::from_f32(0.203_977) * y + T::from_f32(1.056_972) * z;
+    (r, g, b)
+}
+
 #[cfg(test)]
 mod tests {
     use rstest::rstest;
 
     use super::*;
 
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_serde() {
+        // Arrange
+        let rgb = RGB::new(44, 125, 231);
+
+        // Act
+        let json = serde_json::to_string(&rgb).unwrap();
+        let actual: RGB = serde_json::from_str(&json).unwrap();
+
+        // Assert
+        assert_eq!(json, r#"{"r":44,"g":125,"b":231}"#);
+        assert_eq!(actual, rgb);
+    }
+
     #[test]
     fn test_new() {
         // Act
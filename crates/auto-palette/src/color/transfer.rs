@@ -0,0 +1,119 @@
+use crate::math::FloatNumber;
+
+/// Converts the gamma-encoded sRGB component to the linear-light component.
+///
+/// See the following for more details:
+/// [sRGB - Wikipedia](https://en.wikipedia.org/wiki/SRGB#Transfer_function_(%22gamma%22))
+///
+/// # Type Parameters
+/// * `T` - The floating point type.
+///
+/// # Arguments
+/// * `c` - The gamma-encoded component in the range [0, 1].
+///
+/// # Returns
+/// The linear-light component in the range [0, 1].
+///
+/// # Examples
+/// ```
+/// use auto_palette::color::srgb_to_linear;
+///
+/// let linear: f32 = srgb_to_linear(0.5);
+/// assert!((linear - 0.214).abs() < 1e-3);
+/// ```
+#[inline]
+#[must_use]
+pub fn srgb_to_linear<T>(c: T) -> T
+where
+    T: FloatNumber,
+{
+    if c <= T::from_f32(0.04045) {
+        c / T::from_f32(12.92)
+    } else {
+        ((c + T::from_f32(0.055)) / T::from_f32(1.055)).powf(T::from_f32(2.4))
+    }
+}
+
+/// Converts the linear-light component to the gamma-encoded sRGB component.
+///
+/// See the following for more details:
+/// [sRGB - Wikipedia](https://en.wikipedia.org/wiki/SRGB#Transfer_function_(%22gamma%22))
+///
+/// # Type Parameters
+/// * `T` - The floating point type.
+///
+/// # Arguments
+/// * `c` - The linear-light component in the range [0, 1].
+///
+/// # Returns
+/// The gamma-encoded component in the range [0, 1].
+///
+/// # Examples
+/// ```
+/// use auto_palette::color::linear_to_srgb;
+///
+/// let srgb: f32 = linear_to_srgb(0.214);
+/// assert!((srgb - 0.5).abs() < 1e-3);
+/// ```
+#[inline]
+#[must_use]
+pub fn linear_to_srgb<T>(c: T) -> T
+where
+    T: FloatNumber,
+{
+    if c > T::from_f32(0.003_130_8) {
+        T::from_f32(1.055) * c.powf(T::from_f32(1.0 / 2.4)) - T::from_f32(0.055)
+    } else {
+        T::from_f32(12.92) * c
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case::black(0.0, 0.0)]
+    #[case::white(1.0, 1.0)]
+    #[case::half(0.5, 0.214_041)]
+    #[case::linear_segment(0.04, 0.003_095_98)]
+    #[case::threshold(0.04045, 0.003_130_8)]
+    #[case::gray(0.737_254_9, 0.502_886)]
+    fn test_srgb_to_linear(#[case] input: f64, #[case] expected: f64) {
+        // Act
+        let actual = srgb_to_linear(input);
+
+        // Assert
+        assert!((actual - expected).abs() < 1e-6, "{actual} != {expected}");
+    }
+
+    #[rstest]
+    #[case::black(0.0, 0.0)]
+    #[case::white(1.0, 1.0)]
+    #[case::half(0.214_041, 0.5)]
+    #[case::linear_segment(0.003, 0.038_76)]
+    #[case::gray(0.502_886, 0.737_254_9)]
+    fn test_linear_to_srgb(#[case] input: f64, #[case] expected: f64) {
+        // Act
+        let actual = linear_to_srgb(input);
+
+        // Assert
+        assert!((actual - expected).abs() < 1e-6, "{actual} != {expected}");
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for value in 0..=255 {
+            // Arrange
+            let input = value as f64 / 255.0;
+
+            // Act
+            let actual = linear_to_srgb(srgb_to_linear(input));
+
+            // Assert
+            assert!((actual - input).abs() < 1e-6);
+        }
+    }
+}
@@ -0,0 +1,67 @@
+use crate::math::FloatNumber;
+
+/// The WCAG 2 conformance level for the contrast between text and its background.
+///
+/// See the following for more details:
+/// [Understanding Success Criterion 1.4.3: Contrast (Minimum)](https://www.w3.org/WAI/WCAG21/Understanding/contrast-minimum.html)
+///
+/// # Examples
+/// ```
+/// use std::str::FromStr;
+///
+/// use auto_palette::color::{Color, WcagLevel};
+///
+/// let black: Color<f32> = Color::from_str("#000000").unwrap();
+/// let white: Color<f32> = Color::from_str("#FFFFFF").unwrap();
+/// assert!(black.passes_wcag(&white, WcagLevel::AAA));
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum WcagLevel {
+    /// The level AA for normal text. The minimum contrast ratio is 4.5:1.
+    #[default]
+    AA,
+    /// The level AAA for normal text. The minimum contrast ratio is 7:1.
+    AAA,
+    /// The level AA for large text. The minimum contrast ratio is 3:1.
+    AALarge,
+    /// The level AAA for large text. The minimum contrast ratio is 4.5:1.
+    AAALarge,
+}
+
+impl WcagLevel {
+    /// Returns the minimum contrast ratio required by this level.
+    ///
+    /// # Returns
+    /// The minimum contrast ratio.
+    #[must_use]
+    pub(crate) fn min_contrast_ratio<T>(&self) -> T
+    where
+        T: FloatNumber,
+    {
+        match self {
+            Self::AA | Self::AAALarge => T::from_f32(4.5),
+            Self::AAA => T::from_f32(7.0),
+            Self::AALarge => T::from_f32(3.0),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case::aa(WcagLevel::AA, 4.5)]
+    #[case::aaa(WcagLevel::AAA, 7.0)]
+    #[case::aa_large(WcagLevel::AALarge, 3.0)]
+    #[case::aaa_large(WcagLevel::AAALarge, 4.5)]
+    fn test_min_contrast_ratio(#[case] level: WcagLevel, #[case] expected: f32) {
+        // Act
+        let actual: f32 = level.min_contrast_ratio();
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+}
@@ -110,8 +110,70 @@ impl WhitePoint for D65 {
     }
 }
 
+/// The Bradford cone response matrix converting the CIE XYZ color space to the LMS cone space.
+///
+/// See the following for more details:
+/// [Chromatic Adaptation - Bruce Lindbloom](http://www.brucelindbloom.com/index.html?Eqn_ChromAdapt.html)
+pub(crate) const BRADFORD: [[f64; 3]; 3] = [
+    [0.895_1, 0.266_4, -0.161_4],
+    [-0.750_2, 1.713_5, 0.036_7],
+    [0.038_9, -0.068_5, 1.029_6],
+];
+
+/// The inverse of the Bradford cone response matrix converting the LMS cone space to the CIE XYZ color space.
+pub(crate) const BRADFORD_INVERSE: [[f64; 3]; 3] = [
+    [0.986_992_9, -0.147_054_3, 0.159_962_7],
+    [0.432_305_3, 0.518_360_3, 0.049_291_2],
+    [-0.008_528_7, 0.040_042_8, 0.968_486_7],
+];
+
+/// Adapts the CIE XYZ color from the source white point to the destination white point.
+///
+/// This implements the Bradford chromatic adaptation transform.
+///
+/// # Type Parameters
+/// * `T` - The floating point type.
+/// * `S` - The source white point.
+/// * `D` - The destination white point.
+///
+/// # Arguments
+/// * `x` - The X component of the XYZ color under the source white point.
+/// * `y` - The Y component of the XYZ color under the source white point.
+/// * `z` - The Z component of the XYZ color under the source white point.
+///
+/// # Returns
+/// The XYZ color under the destination white point. The tuple contains the X, Y, and Z components.
+#[must_use]
+pub(crate) fn bradford_adapt<T, S, D>(x: T, y: T, z: T) -> (T, T, T)
+where
+    T: FloatNumber,
+    S: WhitePoint,
+    D: WhitePoint,
+{
+    let multiply = |matrix: &[[f64; 3]; 3], (x, y, z): (T, T, T)| -> (T, T, T) {
+        let row =
+            |r: &[f64; 3]| T::from_f64(r[0]) * x + T::from_f64(r[1]) * y + T::from_f64(r[2]) * z;
+        (row(&matrix[0]), row(&matrix[1]), row(&matrix[2]))
+    };
+
+    let (source_l, source_m, source_s) = multiply(&BRADFORD, (S::x(), S::y(), S::z()));
+    let (dest_l, dest_m, dest_s) = multiply(&BRADFORD, (D::x(), D::y(), D::z()));
+
+    let (l, m, s) = multiply(&BRADFORD, (x, y, z));
+    multiply(
+        &BRADFORD_INVERSE,
+        (
+            l * dest_l / source_l,
+            m * dest_m / source_m,
+            s * dest_s / source_s,
+        ),
+    )
+}
+
 #[cfg(test)]
 mod tests {
+    use rstest::rstest;
+
     use super::*;
 
     #[test]
@@ -137,4 +199,31 @@ mod tests {
         let z: f32 = D65::z();
         assert_eq!(z, 1.088_83);
     }
+
+    #[rstest]
+    #[case::white((0.950_47, 1.0, 1.088_83), (0.964_22, 1.0, 0.825_21))]
+    #[case::red((0.412_456, 0.212_673, 0.019_334), (0.436_075, 0.222_504, 0.013_932))]
+    #[case::green((0.357_576, 0.715_152, 0.119_192), (0.385_065, 0.716_879, 0.097_105))]
+    #[case::blue((0.180_438, 0.072_175, 0.950_304), (0.143_080, 0.060_617, 0.714_173))]
+    fn test_bradford_adapt(#[case] input: (f32, f32, f32), #[case] expected: (f32, f32, f32)) {
+        // Act
+        let (x, y, z) = bradford_adapt::<f32, D65, D50>(input.0, input.1, input.2);
+
+        // Assert
+        assert!((x - expected.0).abs() < 1e-4, "{x} != {}", expected.0);
+        assert!((y - expected.1).abs() < 1e-4, "{y} != {}", expected.1);
+        assert!((z - expected.2).abs() < 1e-4, "{z} != {}", expected.2);
+    }
+
+    #[test]
+    fn test_bradford_adapt_round_trip() {
+        // Act
+        let (x, y, z) = bradford_adapt::<f64, D65, D50>(0.3, 0.4, 0.5);
+        let (x, y, z) = bradford_adapt::<f64, D50, D65>(x, y, z);
+
+        // Assert
+        assert!((x - 0.3).abs() < 1e-6);
+        assert!((y - 0.4).abs() < 1e-6);
+        assert!((z - 0.5).abs() < 1e-6);
+    }
 }
@@ -3,7 +3,7 @@ use std::fmt::Display;
 use num_traits::clamp;
 
 use crate::{
-    color::{lab::Lab, luv::Luv, rgb::RGB, white_point::WhitePoint, Oklab},
+    color::{lab::Lab, luv::Luv, rgb::RGB, srgb_to_linear, white_point::WhitePoint, Oklab},
     math::FloatNumber,
 };
 
@@ -35,6 +35,7 @@ use crate::{
 /// assert_eq!(format!("{}", oklab), "Oklab(0.70, 0.27, -0.17)");
 /// ```
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct XYZ<T>
 where
     T: FloatNumber,
@@ -132,7 +133,12 @@ where
     T: FloatNumber,
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "XYZ({:.2}, {:.2}, {:.2})", self.x, self.y, self.z)
+        let precision = f.precision().unwrap_or(2);
+        write!(
+            f,
+            "XYZ({:.*}, {:.*}, {:.*})",
+            precision, self.x, precision, self.y, precision, self.z
+        )
     }
 }
 
@@ -245,22 +251,11 @@ where
 {
     // This implementation is based on the algorithm described in the following link:
     // http://www.brucelindbloom.com/index.html?Eqn_RGB_to_XYZ.html
-    let f = |t: T| -> T {
-        if t <= T::from_f32(0.04045) {
-            t / T::from_f32(12.92)
-        } else {
-            ((t + T::from_f32(0.055)) / T::from_f32(1.055)).powf(T::from_f32(2.4))
-        }
-    };
-
-    let r = f(T::from_u8(r) / RGB::max_value());
-    let g = f(T::from_u8(g) / RGB::max_value());
-    let b = f(T::from_u8(b) / RGB::max_value());
-
-    let x = T::from_f32(0.412_391) * r + T::from_f32(0.357_584) * g + T::from_f32(0.180_481) * b;
-    let y = T::from_f32(0.212_639) * r + T::from_f32(0.715_169) * g + T::from_f32(0.072_192) * b;
-    let z = T::from_f32(0.019_331) * r + T::from_f32(0.119_195) * g + T::from_f32(0.950_532) * b;
+    let r = srgb_to_linear(T::from_u8(r) / RGB::max_value());
+    let g = srgb_to_linear(T::from_u8(g) / RGB::max_value());
+    let b = srgb_to_linear(T::from_u8(b) / RGB::max_value());
 
+    let (x, y, z) = linear_rgb_to_xyz(r, g, b);
     (
         clamp(x, XYZ::min_x(), XYZ::max_x()),
         clamp(y, XYZ::min_y(), XYZ::max_y()),
@@ -268,6 +263,27 @@ where
     )
 }
 
+/// Converts the linear-light RGB color space to the CIE XYZ color space.
+///
+/// # Arguments
+/// * `r` - The linear red component in the range [0, 1].
+/// * `g` - The linear green component in the range [0, 1].
+/// * `b` - The linear blue component in the range [0, 1].
+///
+/// # Returns
+/// The XYZ color space representation of the linear RGB color. The tuple contains the X, Y, and Z components.
+#[inline]
+#[must_use]
+pub(crate) fn linear_rgb_to_xyz<T>(r: T, g: T, b: T) -> (T, T, T)
+where
+    T: FloatNumber,
+{
+    let x = T::from_f32(0.412_391) * r + T::from_f32(0.357_584) * g + T::from_f32(0.180_481) * b;
+    let y = T::from_f32(0.212_639) * r + T::from_f32(0.715_169) * g + T::from_f32(0.072_192) * b;
+    let z = T::from_f32(0.019_331) * r + T::from_f32(0.119_195) * g + T::from_f32(0.950_532) * b;
+    (x, y, z)
+}
+
 /// Converts the CIE L*a*b* color space to the CIE XYZ color space.
 ///
 /// # Type Parameters
@@ -284,6 +300,35 @@ where
 #[inline]
 #[must_use]
 pub fn lab_to_xyz<T, WP>(l: T, a: T, b: T) -> (T, T, T)
+where
+    T: FloatNumber,
+    WP: WhitePoint,
+{
+    let (x, y, z) = lab_to_xyz_unclamped::<T, WP>(l, a, b);
+    // The upper bounds are widened to the white point so that colors under a white point other than D65 are not clipped.
+    (
+        clamp(x, XYZ::min_x(), XYZ::<T>::max_x().max(WP::x())),
+        clamp(y, XYZ::min_y(), XYZ::<T>::max_y().max(WP::y())),
+        clamp(z, XYZ::min_z(), XYZ::<T>::max_z().max(WP::z())),
+    )
+}
+
+/// Converts the CIE L*a*b* color space to the CIE XYZ color space without clamping the result.
+///
+/// # Type Parameters
+/// * `T` - The floating point type.
+/// * `WP` - The white point.
+///
+/// # Arguments
+/// * `l` - The L component of the L*a*b* color.
+/// * `a` - The a component of the L*a*b* color.
+/// * `b` - The b component of the L*a*b* color.
+///
+/// # Returns
+/// The XYZ components, which may be negative for imaginary colors.
+#[inline]
+#[must_use]
+pub(crate) fn lab_to_xyz_unclamped<T, WP>(l: T, a: T, b: T) -> (T, T, T)
 where
     T: FloatNumber,
     WP: WhitePoint,
@@ -308,11 +353,7 @@ where
     let x = WP::x::<T>() * fx;
     let y = WP::y::<T>() * fy;
     let z = WP::z::<T>() * fz;
-    (
-        clamp(x, XYZ::min_x(), XYZ::max_x()),
-        clamp(y, XYZ::min_y(), XYZ::max_y()),
-        clamp(z, XYZ::min_z(), XYZ::max_z()),
-    )
+    (x, y, z)
 }
 
 #[cfg(test)]
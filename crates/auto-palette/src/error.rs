@@ -19,6 +19,14 @@ pub enum Error {
     /// The name provides more information about the unsupported algorithm.
     UnsupportedAlgorithm { name: String },
 
+    /// The color components are out of range.
+    /// The details provide more information about the error.
+    InvalidColor { details: String },
+
+    /// The palette text is not in the expected format.
+    /// The details provide more information about the error.
+    InvalidPaletteFormat { details: String },
+
     /// The theme is not supported.
     /// The name provides more information about the unsupported theme.
     UnsupportedTheme { name: String },
@@ -50,6 +58,12 @@ impl std::fmt::Display for Error {
             Error::UnsupportedAlgorithm { name } => {
                 write!(f, "The algorithm '{}' is not supported.", name)
             }
+            Error::InvalidColor { details } => {
+                write!(f, "The color is invalid: {}", details)
+            }
+            Error::InvalidPaletteFormat { details } => {
+                write!(f, "The palette format is invalid: {}", details)
+            }
             Error::UnsupportedTheme { name } => {
                 write!(f, "The theme '{}' is not supported.", name)
             }
@@ -134,6 +148,34 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_fmt_invalid_color() {
+        // Act
+        let actual = Error::InvalidColor {
+            details: "The L component 120 is out of range [0, 100].".to_string(),
+        };
+
+        // Assert
+        assert_eq!(
+            actual.to_string(),
+            "The color is invalid: The L component 120 is out of range [0, 100]."
+        );
+    }
+
+    #[test]
+    fn test_fmt_invalid_palette_format() {
+        // Act
+        let actual = Error::InvalidPaletteFormat {
+            details: "The header 'GIMP Palette' is missing.".to_string(),
+        };
+
+        // Assert
+        assert_eq!(
+            actual.to_string(),
+            "The palette format is invalid: The header 'GIMP Palette' is missing."
+        );
+    }
+
     #[test]
     #[cfg(feature = "image")]
     fn test_fmt_image_load_error() {
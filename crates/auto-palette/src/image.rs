@@ -1,11 +1,15 @@
 use std::borrow::Cow;
 #[cfg(feature = "image")]
-use std::path::Path;
+use std::{ops::Deref, path::Path};
 
 #[cfg(feature = "image")]
-use image::{DynamicImage, RgbImage, RgbaImage};
+use image::{DynamicImage, ImageBuffer, Luma, Rgb, Rgba};
 
-use crate::Error;
+use crate::{
+    color::{rgb_to_xyz, xyz_to_lab, Color, D65},
+    math::FloatNumber,
+    Error,
+};
 
 /// The image data representing the pixel data of an image.
 ///
@@ -111,6 +115,150 @@ impl<'a> ImageData<'a> {
     pub fn data(&self) -> &[u8] {
         &self.data
     }
+
+    /// Creates a thumbnail of the image data that fits within the given maximum dimension.
+    ///
+    /// The thumbnail is sampled with the nearest-neighbor method, so it never contains colors
+    /// that are not present in the original image. The aspect ratio is preserved.
+    ///
+    /// # Arguments
+    /// * `max_dim` - The maximum width and height of the thumbnail.
+    ///
+    /// # Returns
+    /// The thumbnail image data. If the image already fits, a copy of the image data is returned.
+    #[must_use]
+    pub fn thumbnail(&self, max_dim: u32) -> ImageData<'static> {
+        let longest = self.width.max(self.height);
+        if longest <= max_dim {
+            return ImageData {
+                width: self.width,
+                height: self.height,
+                data: Cow::Owned(self.data.to_vec()),
+            };
+        }
+        if max_dim == 0 {
+            return ImageData {
+                width: 0,
+                height: 0,
+                data: Cow::Owned(Vec::new()),
+            };
+        }
+
+        let scale =
+            |size: u32| -> u32 { (size as u64 * max_dim as u64 / longest as u64).max(1) as u32 };
+        let width = scale(self.width);
+        let height = scale(self.height);
+        let source = |position: u32, size: u32, original: u32| -> usize {
+            ((2 * position as u64 + 1) * original as u64 / (2 * size as u64)) as usize
+        };
+
+        let mut data = Vec::with_capacity((width * height * 4) as usize);
+        for y in 0..height {
+            let source_y = source(y, height, self.height);
+            for x in 0..width {
+                let source_x = source(x, width, self.width);
+                let offset = (source_y * self.width as usize + source_x) * 4;
+                data.extend_from_slice(&self.data[offset..offset + 4]);
+            }
+        }
+        ImageData {
+            width,
+            height,
+            data: Cow::Owned(data),
+        }
+    }
+
+    /// Computes the mean color of the image data without extracting a palette.
+    ///
+    /// The pixels are averaged in the CIE L*a*b* color space, and fully transparent pixels are ignored.
+    /// This is a cheap summary of the overall tint, e.g. for placeholder backgrounds.
+    ///
+    /// # Type Parameters
+    /// * `T` - The floating point type.
+    ///
+    /// # Returns
+    /// The mean color of the image data, or black if the image data has no opaque pixels.
+    #[must_use]
+    pub fn mean_color<T>(&self) -> Color<T>
+    where
+        T: FloatNumber,
+    {
+        let mut sum = [T::zero(); 3];
+        let mut count = 0_usize;
+        for pixel in self.data.chunks_exact(4) {
+            if pixel[3] == 0 {
+                continue;
+            }
+            let (x, y, z) = rgb_to_xyz::<T>(pixel[0], pixel[1], pixel[2]);
+            let (l, a, b) = xyz_to_lab::<T, D65>(x, y, z);
+            sum[0] += l;
+            sum[1] += a;
+            sum[2] += b;
+            count += 1;
+        }
+        if count == 0 {
+            return Color::new(T::zero(), T::zero(), T::zero());
+        }
+
+        let count = T::from_usize(count);
+        Color::new(sum[0] / count, sum[1] / count, sum[2] / count)
+    }
+
+    /// Computes the perceptual hash of the image data.
+    ///
+    /// The hash is a difference hash (dHash) of the image downscaled to 9x8 grayscale cells.
+    /// Each bit represents whether the brightness increases between horizontally adjacent cells,
+    /// so visually similar images have hashes with a small Hamming distance.
+    ///
+    /// # Returns
+    /// The 64-bit perceptual hash of the image data, or zero if the image data is empty.
+    #[must_use]
+    pub fn perceptual_hash(&self) -> u64 {
+        const COLUMNS: usize = 9;
+        const ROWS: usize = 8;
+
+        let width = self.width as usize;
+        let height = self.height as usize;
+        if width == 0 || height == 0 {
+            return 0;
+        }
+
+        let range = |cell: usize, cells: usize, size: usize| -> (usize, usize) {
+            let start = cell * size / cells;
+            let end = ((cell + 1) * size / cells).max(start + 1).min(size);
+            (start.min(size - 1), end)
+        };
+
+        let mut cells = [[0_u64; COLUMNS]; ROWS];
+        for (row, cells) in cells.iter_mut().enumerate() {
+            let (top, bottom) = range(row, ROWS, height);
+            for (column, cell) in cells.iter_mut().enumerate() {
+                let (left, right) = range(column, COLUMNS, width);
+                let mut sum = 0;
+                for y in top..bottom {
+                    for x in left..right {
+                        let offset = (y * width + x) * 4;
+                        let pixel = &self.data[offset..offset + 4];
+                        sum +=
+                            299 * pixel[0] as u64 + 587 * pixel[1] as u64 + 114 * pixel[2] as u64;
+                    }
+                }
+                *cell = sum / ((bottom - top) * (right - left)) as u64;
+            }
+        }
+
+        cells
+            .iter()
+            .flat_map(|cells| cells.windows(2))
+            .enumerate()
+            .fold(0, |hash, (bit, pair)| {
+                if pair[0] < pair[1] {
+                    hash | (1 << bit)
+                } else {
+                    hash
+                }
+            })
+    }
 }
 
 #[cfg(feature = "image")]
@@ -119,6 +267,7 @@ impl TryFrom<&DynamicImage> for ImageData<'_> {
 
     fn try_from(image: &DynamicImage) -> Result<Self, Self::Error> {
         match image {
+            DynamicImage::ImageLuma8(image) => Ok(Self::from(image)),
             DynamicImage::ImageRgb8(image) => Ok(Self::from(image)),
             DynamicImage::ImageRgba8(image) => Ok(Self::from(image)),
             _ => Err(Error::UnsupportedImage),
@@ -127,8 +276,33 @@ impl TryFrom<&DynamicImage> for ImageData<'_> {
 }
 
 #[cfg(feature = "image")]
-impl From<&RgbImage> for ImageData<'_> {
-    fn from(image: &RgbImage) -> Self {
+impl<C> From<&ImageBuffer<Luma<u8>, C>> for ImageData<'_>
+where
+    C: Deref<Target = [u8]>,
+{
+    fn from(image: &ImageBuffer<Luma<u8>, C>) -> Self {
+        let (width, height) = image.dimensions();
+        let size = (width * height) as usize;
+        let data = image
+            .pixels()
+            .fold(Vec::with_capacity(size * 4), |mut pixels, pixel| {
+                pixels.extend_from_slice(&[pixel[0], pixel[0], pixel[0], 255]);
+                pixels
+            });
+        Self {
+            width,
+            height,
+            data: data.into(),
+        }
+    }
+}
+
+#[cfg(feature = "image")]
+impl<C> From<&ImageBuffer<Rgb<u8>, C>> for ImageData<'_>
+where
+    C: Deref<Target = [u8]>,
+{
+    fn from(image: &ImageBuffer<Rgb<u8>, C>) -> Self {
         let (width, height) = image.dimensions();
         let size = (width * height) as usize;
         let data = image
@@ -146,10 +320,14 @@ impl From<&RgbImage> for ImageData<'_> {
 }
 
 #[cfg(feature = "image")]
-impl From<&RgbaImage> for ImageData<'_> {
-    fn from(image: &RgbaImage) -> Self {
+impl<C> From<&ImageBuffer<Rgba<u8>, C>> for ImageData<'_>
+where
+    C: Deref<Target = [u8]>,
+{
+    fn from(image: &ImageBuffer<Rgba<u8>, C>) -> Self {
         let (width, height) = image.dimensions();
-        let data = image.to_vec();
+        // The container may be larger than the image, so only the pixels of the image are copied.
+        let data = image.as_raw()[..(width * height * 4) as usize].to_vec();
         Self {
             width,
             height,
@@ -160,6 +338,10 @@ impl From<&RgbaImage> for ImageData<'_> {
 
 #[cfg(test)]
 mod tests {
+    use std::str::FromStr;
+
+    use rstest::rstest;
+
     use super::*;
 
     #[test]
@@ -181,6 +363,22 @@ mod tests {
         assert_eq!(actual.data(), &pixels);
     }
 
+    #[cfg(feature = "image")]
+    #[test]
+    fn test_from_rgba_image_with_larger_container() {
+        // Arrange
+        let pixels = [255, 0, 0, 255, 0, 0, 255, 128, 1, 2, 3, 4];
+        let image = ImageBuffer::<Rgba<u8>, &[u8]>::from_raw(2, 1, &pixels[..]).unwrap();
+
+        // Act
+        let actual = ImageData::from(&image);
+
+        // Assert
+        assert_eq!(actual.width(), 2);
+        assert_eq!(actual.height(), 1);
+        assert_eq!(actual.data(), &pixels[..8]);
+    }
+
     #[test]
     fn test_new_empty_data() {
         // Arrange
@@ -205,6 +403,160 @@ mod tests {
         assert!(actual.is_err());
     }
 
+    #[test]
+    fn test_thumbnail() {
+        // Arrange
+        let pixels: Vec<u8> = (0..60)
+            .flat_map(|y| {
+                (0..80).flat_map(move |x| {
+                    if (x / 3 + y / 3) % 2 == 0 {
+                        [255, 0, 0, 255]
+                    } else {
+                        [0, 0, 255, 255]
+                    }
+                })
+            })
+            .collect();
+        let image_data = ImageData::new(80, 60, &pixels).unwrap();
+
+        // Act
+        let actual = image_data.thumbnail(16);
+
+        // Assert
+        assert_eq!(actual.width(), 16);
+        assert_eq!(actual.height(), 12);
+        assert_eq!(actual.data().len(), 16 * 12 * 4);
+        assert!(
+            actual
+                .data()
+                .chunks_exact(4)
+                .all(|pixel| pixel == [255, 0, 0, 255] || pixel == [0, 0, 255, 255])
+        );
+        assert!(actual.data().chunks_exact(4).any(|pixel| pixel[0] == 255));
+        assert!(actual.data().chunks_exact(4).any(|pixel| pixel[2] == 255));
+    }
+
+    #[rstest]
+    #[case::fits(4, 2, 8, (4, 2))]
+    #[case::exact(4, 2, 4, (4, 2))]
+    #[case::landscape(64, 48, 16, (16, 12))]
+    #[case::portrait(48, 64, 16, (12, 16))]
+    #[case::thin(100, 1, 10, (10, 1))]
+    #[case::zero(4, 2, 0, (0, 0))]
+    fn test_thumbnail_size(
+        #[case] width: u32,
+        #[case] height: u32,
+        #[case] max_dim: u32,
+        #[case] expected: (u32, u32),
+    ) {
+        // Arrange
+        let pixels = gradient_pixels(width, height, false);
+        let image_data = ImageData::new(width, height, &pixels).unwrap();
+
+        // Act
+        let actual = image_data.thumbnail(max_dim);
+
+        // Assert
+        assert_eq!((actual.width(), actual.height()), expected);
+        assert_eq!(actual.data().len(), (expected.0 * expected.1 * 4) as usize);
+    }
+
+    #[test]
+    fn test_mean_color() {
+        // Arrange
+        let pixels = [
+            [255, 0, 0, 255].repeat(8), // Red
+            [0, 0, 255, 255].repeat(8), // Blue
+            [0, 255, 0, 0].repeat(8),   // Transparent green
+        ]
+        .concat();
+        let image_data = ImageData::new(6, 4, &pixels).unwrap();
+
+        // Act
+        let actual: Color<f32> = image_data.mean_color();
+
+        // Assert
+        let red = Color::<f32>::from_str("#FF0000").unwrap();
+        let blue = Color::<f32>::from_str("#0000FF").unwrap();
+        assert!((actual.lightness() - (red.lightness() + blue.lightness()) / 2.0).abs() < 1e-3);
+        let hue = actual.hue().to_degrees();
+        assert!(hue > 270.0 && hue < 360.0, "hue: {}", hue);
+        let rgb = actual.to_rgb();
+        assert!(rgb.r > rgb.g && rgb.b > rgb.g);
+    }
+
+    #[test]
+    fn test_mean_color_transparent() {
+        // Arrange
+        let pixels = [0, 0, 0, 0].repeat(4);
+        let image_data = ImageData::new(2, 2, &pixels).unwrap();
+
+        // Act
+        let actual: Color<f32> = image_data.mean_color();
+
+        // Assert
+        assert_eq!(actual.to_hex_string(), "#000000");
+    }
+
+    #[must_use]
+    fn gradient_pixels(width: u32, height: u32, reversed: bool) -> Vec<u8> {
+        (0..height)
+            .flat_map(|_| 0..width)
+            .flat_map(|x| {
+                let value = (x * 255 / (width - 1)) as u8;
+                let value = if reversed { 255 - value } else { value };
+                [value, value, value, 255]
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_perceptual_hash() {
+        // Arrange
+        let pixels1 = gradient_pixels(64, 48, false);
+        let pixels2 = gradient_pixels(64, 48, false);
+        let pixels3 = gradient_pixels(64, 48, true);
+        let image_data1 = ImageData::new(64, 48, &pixels1).unwrap();
+        let image_data2 = ImageData::new(64, 48, &pixels2).unwrap();
+        let image_data3 = ImageData::new(64, 48, &pixels3).unwrap();
+
+        // Act
+        let hash1 = image_data1.perceptual_hash();
+        let hash2 = image_data2.perceptual_hash();
+        let hash3 = image_data3.perceptual_hash();
+
+        // Assert
+        assert_eq!(hash1, hash2);
+        assert_eq!(hash1, u64::MAX);
+        assert!((hash1 ^ hash3).count_ones() > 32);
+    }
+
+    #[test]
+    fn test_perceptual_hash_small_image() {
+        // Arrange
+        let pixels = gradient_pixels(4, 2, false);
+        let image_data = ImageData::new(4, 2, &pixels).unwrap();
+
+        // Act
+        let actual = image_data.perceptual_hash();
+
+        // Assert
+        assert_ne!(actual, 0);
+    }
+
+    #[test]
+    fn test_perceptual_hash_empty() {
+        // Arrange
+        let pixels = [];
+        let image_data = ImageData::new(0, 0, &pixels).unwrap();
+
+        // Act
+        let actual = image_data.perceptual_hash();
+
+        // Assert
+        assert_eq!(actual, 0);
+    }
+
     #[cfg(feature = "image")]
     #[test]
     fn test_load_rgba_image() {
@@ -229,6 +581,52 @@ mod tests {
         assert_eq!(actual.data().len(), 480 * 722 * 4);
     }
 
+    #[cfg(feature = "image")]
+    #[test]
+    fn test_from_gray_image() {
+        // Arrange
+        let image = image::GrayImage::from_raw(2, 1, vec![0, 128]).unwrap();
+
+        // Act
+        let actual = ImageData::from(&image);
+
+        // Assert
+        assert_eq!(actual.width(), 2);
+        assert_eq!(actual.height(), 1);
+        assert_eq!(actual.data(), &[0, 0, 0, 255, 128, 128, 128, 255]);
+    }
+
+    #[cfg(feature = "image")]
+    #[test]
+    fn test_from_rgb_image() {
+        // Arrange
+        let image = image::RgbImage::from_raw(2, 1, vec![255, 0, 0, 0, 0, 255]).unwrap();
+
+        // Act
+        let actual = ImageData::from(&image);
+
+        // Assert
+        assert_eq!(actual.width(), 2);
+        assert_eq!(actual.height(), 1);
+        assert_eq!(actual.data(), &[255, 0, 0, 255, 0, 0, 255, 255]);
+    }
+
+    #[cfg(feature = "image")]
+    #[test]
+    fn test_from_rgba_image_with_slice() {
+        // Arrange
+        let pixels = [255, 0, 0, 255, 0, 0, 255, 128];
+        let image = ImageBuffer::<Rgba<u8>, &[u8]>::from_raw(2, 1, &pixels[..]).unwrap();
+
+        // Act
+        let actual = ImageData::from(&image);
+
+        // Assert
+        assert_eq!(actual.width(), 2);
+        assert_eq!(actual.height(), 1);
+        assert_eq!(actual.data(), &pixels);
+    }
+
     #[cfg(feature = "image")]
     #[test]
     fn test_load_invalid_path() {
@@ -1,16 +1,27 @@
+mod accumulator;
 mod algorithm;
 pub mod color;
 mod error;
 mod image;
 mod math;
 mod palette;
+mod palette_builder;
+mod palette_diff;
 mod swatch;
 mod theme;
 
+/// The clustering algorithms for grouping points into clusters.
+pub mod clustering {
+    pub use crate::math::clustering::{Cluster, ClusteringAlgorithm, DBSCAN};
+}
+
+pub use accumulator::PaletteAccumulator;
 pub use algorithm::Algorithm;
 pub use error::Error;
 pub use image::ImageData;
-pub use math::FloatNumber;
+pub use math::{DistanceMetric, FloatNumber, Point};
 pub use palette::Palette;
+pub use palette_builder::PaletteBuilder;
+pub use palette_diff::PaletteDiff;
 pub use swatch::Swatch;
 pub use theme::Theme;
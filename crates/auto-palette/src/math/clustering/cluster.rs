@@ -95,6 +95,15 @@ where
     }
 }
 
+impl<T, const N: usize> Default for Cluster<T, N>
+where
+    T: FloatNumber,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -107,7 +116,10 @@ mod tests {
         // Assert
         assert!(actual.is_empty());
         assert_eq!(actual.len(), 0);
-        assert_eq!(actual.members().copied().collect::<Vec<_>>(), vec![]);
+        assert_eq!(
+            actual.members().copied().collect::<Vec<_>>(),
+            Vec::<usize>::new()
+        );
         assert_eq!(actual.centroid(), &[0.0, 0.0]);
     }
 
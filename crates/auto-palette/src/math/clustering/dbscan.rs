@@ -16,6 +16,39 @@ const UNCLASSIFIED: i32 = -3;
 ///
 /// # Type Parameters
 /// * `T` - The floating point type.
+///
+/// # Examples
+/// ```
+/// use auto_palette::{clustering::DBSCAN, DistanceMetric, Point};
+///
+/// let points: Vec<Point<f32, 2>> = vec![
+///     [0.0, 0.0],
+///     [0.0, 1.0],
+///     [1.0, 0.0],
+///     [1.0, 1.0],
+///     [10.0, 10.0],
+///     [10.0, 11.0],
+///     [11.0, 10.0],
+///     [11.0, 11.0],
+///     [20.0, 0.0],
+///     [20.0, 1.0],
+///     [21.0, 0.0],
+///     [21.0, 1.0],
+/// ];
+/// let dbscan = DBSCAN::new(3, 1.5, DistanceMetric::Euclidean).unwrap();
+/// let mut clusters = dbscan.cluster(&points);
+/// clusters
+///     .sort_by(|cluster1, cluster2| cluster1.centroid()[0].total_cmp(&cluster2.centroid()[0]));
+///
+/// assert_eq!(clusters.len(), 3);
+/// assert_eq!(clusters[0].centroid(), &[0.5, 0.5]);
+/// assert_eq!(clusters[1].centroid(), &[10.5, 10.5]);
+/// assert_eq!(clusters[2].centroid(), &[20.5, 0.5]);
+///
+/// let mut members: Vec<usize> = clusters[1].members().copied().collect();
+/// members.sort_unstable();
+/// assert_eq!(members, vec![4, 5, 6, 7]);
+/// ```
 #[derive(Debug, PartialEq)]
 #[allow(clippy::upper_case_acronyms)]
 pub struct DBSCAN<T>
@@ -58,6 +91,20 @@ where
         })
     }
 
+    /// Clusters the given points.
+    ///
+    /// This is the same as [`ClusteringAlgorithm::fit`], without importing the trait.
+    ///
+    /// # Arguments
+    /// * `points` - The points to cluster.
+    ///
+    /// # Returns
+    /// The clusters with their centroids and the indices of their member points. Outliers belong to no cluster.
+    #[must_use]
+    pub fn cluster<const N: usize>(&self, points: &[Point<T, N>]) -> Vec<Cluster<T, N>> {
+        self.fit(points)
+    }
+
     #[inline]
     #[must_use]
     fn expand_cluster<const N: usize, NS>(
@@ -155,6 +202,8 @@ where
 
 #[cfg(test)]
 mod tests {
+    use std::cmp::Reverse;
+
     use rstest::rstest;
 
     use super::*;
@@ -233,7 +282,7 @@ mod tests {
         let dbscan = DBSCAN::new(4, 2.0, DistanceMetric::Euclidean).unwrap();
 
         let mut actual = dbscan.fit(&points);
-        actual.sort_by(|cluster1, cluster2| cluster2.len().cmp(&cluster1.len()));
+        actual.sort_by_key(|cluster| Reverse(cluster.len()));
 
         // Assert
         assert_eq!(actual.len(), 3);
@@ -245,6 +294,41 @@ mod tests {
         assert_eq!(actual[2].centroid(), &[0.5, 7.5]);
     }
 
+    #[test]
+    fn test_cluster() {
+        // Arrange
+        let points: Vec<Point<f32, 2>> = [(0.0, 0.0), (50.0, 50.0), (100.0, 0.0)]
+            .iter()
+            .flat_map(|&(x, y)| {
+                [
+                    [x - 1.0, y - 1.0],
+                    [x - 1.0, y + 1.0],
+                    [x + 1.0, y - 1.0],
+                    [x + 1.0, y + 1.0],
+                    [x, y],
+                ]
+            })
+            .collect();
+        let dbscan = DBSCAN::new(3, 2.0, DistanceMetric::Euclidean).unwrap();
+
+        // Act
+        let mut actual = dbscan.cluster(&points);
+        actual.sort_by(|cluster1, cluster2| {
+            cluster1.centroid()[0].total_cmp(&cluster2.centroid()[0])
+        });
+
+        // Assert
+        assert_eq!(actual.len(), 3);
+        let expected_centroids = [[0.0, 0.0], [50.0, 50.0], [100.0, 0.0]];
+        for (group, (cluster, expected)) in actual.iter().zip(expected_centroids).enumerate() {
+            assert!((cluster.centroid()[0] - expected[0]).abs() < 1e-4);
+            assert!((cluster.centroid()[1] - expected[1]).abs() < 1e-4);
+            let mut members: Vec<usize> = cluster.members().copied().collect();
+            members.sort_unstable();
+            assert_eq!(members, (group * 5..group * 5 + 5).collect::<Vec<_>>());
+        }
+    }
+
     #[test]
     fn test_fit_empty() {
         // Act
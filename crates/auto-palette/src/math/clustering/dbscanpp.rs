@@ -222,6 +222,8 @@ where
 
 #[cfg(test)]
 mod tests {
+    use std::cmp::Reverse;
+
     use rstest::rstest;
 
     use super::*;
@@ -314,7 +316,7 @@ mod tests {
         let points = sample_points();
 
         let mut actual = dbscanpp.fit(&points);
-        actual.sort_by(|cluster1, cluster2| cluster2.len().cmp(&cluster1.len()));
+        actual.sort_by_key(|cluster| Reverse(cluster.len()));
 
         // Assert
         assert_eq!(actual.len(), 3);
@@ -0,0 +1,201 @@
+use crate::math::{
+    clustering::{Cluster, ClusteringAlgorithm},
+    FloatNumber,
+    Point,
+};
+
+/// Median cut clustering algorithm.
+///
+/// The algorithm recursively splits the box with the largest spread at the median of the points along the widest axis.
+/// It is fast and deterministic.
+#[derive(Debug, PartialEq)]
+pub struct MedianCut {
+    max_clusters: usize,
+}
+
+impl MedianCut {
+    /// Creates a new `MedianCut` instance.
+    ///
+    /// # Arguments
+    /// * `max_clusters` - The maximum number of clusters.
+    ///
+    /// # Returns
+    /// A new `MedianCut` instance.
+    ///
+    /// # Errors
+    /// Returns an error if the maximum number of clusters is zero.
+    pub fn new(max_clusters: usize) -> Result<Self, &'static str> {
+        if max_clusters == 0 {
+            return Err("The maximum number of clusters must be greater than zero.");
+        }
+        Ok(Self { max_clusters })
+    }
+}
+
+/// Finds the axis with the largest spread of the points in the box.
+///
+/// The spread is the sum of squared deviations from the mean along the axis.
+///
+/// # Arguments
+/// * `points` - The points.
+/// * `indices` - The indices of the points in the box.
+///
+/// # Returns
+/// The axis and the spread along the axis.
+#[must_use]
+fn widest_axis<T, const N: usize>(points: &[Point<T, N>], indices: &[usize]) -> (usize, T)
+where
+    T: FloatNumber,
+{
+    let size = T::from_usize(indices.len());
+    (0..N)
+        .map(|axis| {
+            let mean = indices
+                .iter()
+                .fold(T::zero(), |sum, &index| sum + points[index][axis])
+                / size;
+            let spread = indices.iter().fold(T::zero(), |sum, &index| {
+                let diff = points[index][axis] - mean;
+                sum + diff * diff
+            });
+            (axis, spread)
+        })
+        .fold((0, T::zero()), |widest, axis| {
+            if axis.1 > widest.1 { axis } else { widest }
+        })
+}
+
+impl<T, const N: usize> ClusteringAlgorithm<T, N> for MedianCut
+where
+    T: FloatNumber,
+{
+    fn fit(&self, points: &[Point<T, N>]) -> Vec<Cluster<T, N>> {
+        if points.is_empty() {
+            return Vec::new();
+        }
+
+        let mut boxes = vec![(0..points.len()).collect::<Vec<_>>()];
+        while boxes.len() < self.max_clusters {
+            let candidate = boxes
+                .iter()
+                .enumerate()
+                .filter(|(_, indices)| indices.len() > 1)
+                .map(|(index, indices)| (index, widest_axis(points, indices)))
+                .filter(|(_, (_, spread))| *spread > T::zero())
+                .max_by(|(_, (_, spread1)), (_, (_, spread2))| {
+                    spread1.partial_cmp(spread2).unwrap()
+                });
+            let Some((index, (axis, _))) = candidate else {
+                break;
+            };
+
+            let mut indices = boxes.swap_remove(index);
+            indices.sort_by(|&index1, &index2| {
+                points[index1][axis]
+                    .partial_cmp(&points[index2][axis])
+                    .unwrap()
+            });
+            let upper = indices.split_off(indices.len() / 2);
+            boxes.push(indices);
+            boxes.push(upper);
+        }
+
+        boxes
+            .iter()
+            .map(|indices| {
+                let mut cluster = Cluster::new();
+                for &index in indices {
+                    cluster.add_member(index, &points[index]);
+                }
+                cluster
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cmp::Reverse;
+
+    use super::*;
+
+    #[must_use]
+    fn sample_points() -> Vec<Point<f32, 2>> {
+        vec![
+            [0.0, 0.0], // 0
+            [0.0, 1.0], // 0
+            [1.0, 0.0], // 0
+            [1.0, 1.0], // 0
+            [8.0, 8.0], // 1
+            [8.0, 9.0], // 1
+            [9.0, 8.0], // 1
+            [9.0, 9.0], // 1
+        ]
+    }
+
+    #[test]
+    fn test_new() {
+        // Act
+        let actual = MedianCut::new(16).unwrap();
+
+        // Assert
+        assert_eq!(actual.max_clusters, 16);
+    }
+
+    #[test]
+    fn test_new_error() {
+        // Act
+        let actual = MedianCut::new(0);
+
+        // Assert
+        assert_eq!(
+            actual,
+            Err("The maximum number of clusters must be greater than zero.")
+        );
+    }
+
+    #[test]
+    fn test_fit() {
+        // Arrange
+        let points = sample_points();
+        let median_cut = MedianCut::new(2).unwrap();
+
+        // Act
+        let mut actual = median_cut.fit(&points);
+        actual.sort_by_key(|cluster| Reverse(cluster.centroid()[0] as usize));
+
+        // Assert
+        assert_eq!(actual.len(), 2);
+        assert_eq!(actual[0].len(), 4);
+        assert_eq!(actual[0].centroid(), &[8.5, 8.5]);
+        assert_eq!(actual[1].len(), 4);
+        assert_eq!(actual[1].centroid(), &[0.5, 0.5]);
+    }
+
+    #[test]
+    fn test_fit_identical_points() {
+        // Arrange
+        let points: Vec<Point<f32, 2>> = vec![[1.0, 1.0]; 8];
+        let median_cut = MedianCut::new(4).unwrap();
+
+        // Act
+        let actual = median_cut.fit(&points);
+
+        // Assert
+        assert_eq!(actual.len(), 1);
+        assert_eq!(actual[0].len(), 8);
+    }
+
+    #[test]
+    fn test_fit_empty() {
+        // Arrange
+        let points: Vec<Point<f32, 2>> = Vec::new();
+        let median_cut = MedianCut::new(4).unwrap();
+
+        // Act
+        let actual = median_cut.fit(&points);
+
+        // Assert
+        assert!(actual.is_empty());
+    }
+}
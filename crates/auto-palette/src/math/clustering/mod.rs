@@ -3,9 +3,13 @@ mod cluster;
 mod dbscan;
 mod dbscanpp;
 mod kmeans;
+mod median_cut;
+mod octree;
 
 pub use algorithm::ClusteringAlgorithm;
 pub use cluster::Cluster;
 pub use dbscan::DBSCAN;
 pub use dbscanpp::DBSCANPlusPlus;
 pub use kmeans::KMeans;
+pub use median_cut::MedianCut;
+pub use octree::Octree;
@@ -0,0 +1,274 @@
+use std::collections::HashMap;
+
+use crate::math::{
+    clustering::{Cluster, ClusteringAlgorithm},
+    FloatNumber,
+    Point,
+};
+
+/// Octree quantization algorithm.
+///
+/// The algorithm inserts the points into a tree that halves the bounding box along every axis at each level,
+/// so each node has up to 2^N children (8 for three dimensions). The nodes with the fewest points are merged
+/// from the deepest level until the number of leaves is at most the maximum number of clusters.
+#[derive(Debug, PartialEq)]
+pub struct Octree {
+    max_clusters: usize,
+    max_depth: usize,
+}
+
+/// The node of the tree.
+#[derive(Debug, Default)]
+struct Node {
+    level: usize,
+    population: usize,
+    children: HashMap<usize, usize>,
+    members: Vec<usize>,
+}
+
+impl Octree {
+    /// Creates a new `Octree` instance.
+    ///
+    /// # Arguments
+    /// * `max_clusters` - The maximum number of clusters.
+    /// * `max_depth` - The maximum depth of the tree.
+    ///
+    /// # Returns
+    /// A new `Octree` instance.
+    ///
+    /// # Errors
+    /// Returns an error if the maximum number of clusters is zero, or the maximum depth is zero or greater than 16.
+    pub fn new(max_clusters: usize, max_depth: usize) -> Result<Self, &'static str> {
+        if max_clusters == 0 {
+            return Err("The maximum number of clusters must be greater than zero.");
+        }
+        if max_depth == 0 || max_depth > 16 {
+            return Err("The maximum depth must be in the range [1, 16].");
+        }
+        Ok(Self {
+            max_clusters,
+            max_depth,
+        })
+    }
+
+    /// Builds the tree from the given points.
+    ///
+    /// # Arguments
+    /// * `points` - The points to insert.
+    ///
+    /// # Returns
+    /// The nodes of the tree. The first node is the root node.
+    #[must_use]
+    fn build<T, const N: usize>(&self, points: &[Point<T, N>]) -> Vec<Node>
+    where
+        T: FloatNumber,
+    {
+        let mut min = [T::infinity(); N];
+        let mut max = [T::neg_infinity(); N];
+        for point in points {
+            for axis in 0..N {
+                min[axis] = min[axis].min(point[axis]);
+                max[axis] = max[axis].max(point[axis]);
+            }
+        }
+
+        let cells = 1_usize << self.max_depth;
+        let scale = T::from_usize(cells);
+        let mut nodes = vec![Node::default()];
+        for (index, point) in points.iter().enumerate() {
+            let mut cell = [0_usize; N];
+            for axis in 0..N {
+                let range = max[axis] - min[axis];
+                if range > T::zero() {
+                    let value = ((point[axis] - min[axis]) / range * scale).to_usize_unsafe();
+                    cell[axis] = value.min(cells - 1);
+                }
+            }
+
+            let mut current = 0;
+            nodes[current].population += 1;
+            for level in 0..self.max_depth {
+                let shift = self.max_depth - level - 1;
+                let key = cell.iter().enumerate().fold(0, |key, (axis, &value)| {
+                    key | (((value >> shift) & 1) << axis)
+                });
+                current = match nodes[current].children.get(&key) {
+                    Some(&child) => child,
+                    None => {
+                        let child = nodes.len();
+                        nodes.push(Node {
+                            level: level + 1,
+                            ..Node::default()
+                        });
+                        nodes[current].children.insert(key, child);
+                        child
+                    }
+                };
+                nodes[current].population += 1;
+            }
+            nodes[current].members.push(index);
+        }
+        nodes
+    }
+
+    /// Reduces the leaves of the tree until the number of leaves is at most the maximum number of clusters.
+    ///
+    /// # Arguments
+    /// * `nodes` - The nodes of the tree.
+    fn reduce(&self, nodes: &mut [Node]) {
+        let mut leaves = nodes.iter().filter(|node| node.children.is_empty()).count();
+        for level in (0..self.max_depth).rev() {
+            if leaves <= self.max_clusters {
+                break;
+            }
+
+            let mut parents: Vec<usize> = (0..nodes.len())
+                .filter(|&index| nodes[index].level == level && !nodes[index].children.is_empty())
+                .collect();
+            parents.sort_by_key(|&index| (nodes[index].population, index));
+            for parent in parents {
+                if leaves <= self.max_clusters {
+                    break;
+                }
+
+                let mut children: Vec<usize> = nodes[parent].children.values().copied().collect();
+                children.sort_unstable();
+                leaves -= children.len() - 1;
+                nodes[parent].children.clear();
+                for child in children {
+                    let members = std::mem::take(&mut nodes[child].members);
+                    nodes[parent].members.extend(members);
+                }
+            }
+        }
+    }
+}
+
+impl<T, const N: usize> ClusteringAlgorithm<T, N> for Octree
+where
+    T: FloatNumber,
+{
+    fn fit(&self, points: &[Point<T, N>]) -> Vec<Cluster<T, N>> {
+        if points.is_empty() {
+            return Vec::new();
+        }
+
+        let mut nodes = self.build(points);
+        self.reduce(&mut nodes);
+
+        let mut clusters = Vec::new();
+        let mut stack = vec![0];
+        while let Some(index) = stack.pop() {
+            let node = &nodes[index];
+            if node.children.is_empty() {
+                let mut cluster = Cluster::new();
+                for &member in &node.members {
+                    cluster.add_member(member, &points[member]);
+                }
+                clusters.push(cluster);
+            } else {
+                let mut children: Vec<usize> = node.children.values().copied().collect();
+                children.sort_unstable();
+                stack.extend(children);
+            }
+        }
+        clusters
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cmp::Reverse;
+
+    use rstest::rstest;
+
+    use super::*;
+
+    #[must_use]
+    fn sample_points() -> Vec<Point<f32, 2>> {
+        vec![
+            [0.0, 0.0], // 0
+            [0.0, 1.0], // 0
+            [1.0, 0.0], // 0
+            [1.0, 1.0], // 0
+            [1.0, 2.0], // 0
+            [8.0, 8.0], // 1
+            [8.0, 9.0], // 1
+            [9.0, 9.0], // 1
+            [0.0, 9.0], // 2
+        ]
+    }
+
+    #[test]
+    fn test_new() {
+        // Act
+        let actual = Octree::new(16, 4).unwrap();
+
+        // Assert
+        assert_eq!(actual.max_clusters, 16);
+        assert_eq!(actual.max_depth, 4);
+    }
+
+    #[rstest]
+    #[case::invalid_max_clusters(0, 4, "The maximum number of clusters must be greater than zero.")]
+    #[case::zero_depth(16, 0, "The maximum depth must be in the range [1, 16].")]
+    #[case::too_deep(16, 17, "The maximum depth must be in the range [1, 16].")]
+    fn test_new_error(
+        #[case] max_clusters: usize,
+        #[case] max_depth: usize,
+        #[case] expected: &'static str,
+    ) {
+        // Act
+        let actual = Octree::new(max_clusters, max_depth);
+
+        // Assert
+        assert_eq!(actual, Err(expected));
+    }
+
+    #[test]
+    fn test_fit() {
+        // Arrange
+        let points = sample_points();
+        let octree = Octree::new(3, 4).unwrap();
+
+        // Act
+        let mut actual = octree.fit(&points);
+        actual.sort_by_key(|cluster| Reverse(cluster.len()));
+
+        // Assert
+        assert_eq!(actual.len(), 3);
+        assert_eq!(actual[0].len(), 5);
+        assert!((actual[0].centroid()[0] - 0.6).abs() < 1e-6);
+        assert!((actual[0].centroid()[1] - 0.8).abs() < 1e-6);
+        assert_eq!(actual[1].len(), 3);
+        assert_eq!(actual[2].len(), 1);
+        assert_eq!(actual[2].centroid(), &[0.0, 9.0]);
+    }
+
+    #[test]
+    fn test_fit_single_cluster() {
+        // Arrange
+        let points = sample_points();
+        let octree = Octree::new(1, 4).unwrap();
+
+        // Act
+        let actual = octree.fit(&points);
+
+        // Assert
+        assert_eq!(actual.len(), 1);
+        assert_eq!(actual[0].len(), points.len());
+    }
+
+    #[test]
+    fn test_fit_empty() {
+        // Arrange
+        let points: Vec<Point<f32, 2>> = Vec::new();
+        let octree = Octree::new(4, 4).unwrap();
+
+        // Act
+        let actual = octree.fit(&points);
+
+        // Assert
+        assert!(actual.is_empty());
+    }
+}
@@ -1,6 +1,6 @@
 pub mod clustering;
 mod metrics;
-mod neighbors;
+pub(crate) mod neighbors;
 mod number;
 mod point;
 mod sampling;
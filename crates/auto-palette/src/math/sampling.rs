@@ -39,6 +39,35 @@ where
     /// # Returns
     /// The indices of the sampled points.
     pub fn sample<const N: usize>(&self, points: &[Point<T, N>], n: usize) -> HashSet<usize> {
+        let initial_index = match self {
+            SamplingStrategy::FarthestPointSampling => 0,
+            SamplingStrategy::WeightedFarthestPointSampling(weights) => weights
+                .iter()
+                .enumerate()
+                .max_by(|(_, weight1), (_, weight2)| weight1.partial_cmp(weight2).unwrap())
+                .map_or(0, |(index, _)| index),
+        };
+        self.sample_from(points, n, initial_index)
+    }
+
+    /// Samples points from the given set of points starting from the given point.
+    ///
+    /// # Type Parameters
+    /// * `N` - The number of dimensions of the points.
+    ///
+    /// # Arguments
+    /// * `points` - The set of points to sample from.
+    /// * `n` - The number of points to sample.
+    /// * `initial_index` - The index of the first point to sample.
+    ///
+    /// # Returns
+    /// The indices of the sampled points.
+    pub fn sample_from<const N: usize>(
+        &self,
+        points: &[Point<T, N>],
+        n: usize,
+        initial_index: usize,
+    ) -> HashSet<usize> {
         if n == 0 || points.is_empty() {
             return HashSet::new();
         }
@@ -47,10 +76,14 @@ where
             return (0..points.len()).collect();
         }
 
+        debug_assert!(
+            initial_index < points.len(),
+            "The initial index must be less than the number of points."
+        );
         let metric = DistanceMetric::SquaredEuclidean;
         match self {
             SamplingStrategy::FarthestPointSampling => {
-                sample_with_distance_fn(points, n, 0, |_, point1, point2| {
+                sample_with_distance_fn(points, n, initial_index, |_, point1, point2| {
                     metric.measure(point1, point2)
                 })
             }
@@ -60,11 +93,6 @@ where
                     weights.len(),
                     "The number of points and weights must be equal."
                 );
-                let (initial_index, _) = weights
-                    .iter()
-                    .enumerate()
-                    .max_by(|(_, weight1), (_, weight2)| weight1.partial_cmp(weight2).unwrap())
-                    .unwrap();
                 sample_with_distance_fn(points, n, initial_index, |index, point1, point2| {
                     metric.measure(point1, point2) * weights[index]
                 })
@@ -212,6 +240,26 @@ mod tests {
         assert!(sampled.is_empty());
     }
 
+    #[rstest]
+    #[case(0, 5, vec ! [])]
+    #[case(1, 5, vec ! [5])]
+    #[case(3, 5, vec ! [0, 3, 5])]
+    #[case(3, 8, vec ! [5, 6, 8])]
+    #[case(10, 5, vec ! [0, 1, 2, 3, 4, 5, 6, 7, 8])]
+    fn test_sample_from_farthest_point_sampling(
+        #[case] n: usize,
+        #[case] initial_index: usize,
+        #[case] expected: Vec<usize>,
+    ) {
+        // Act
+        let points = sample_points();
+        let sampling = SamplingStrategy::FarthestPointSampling;
+        let sampled = sampling.sample_from(&points, n, initial_index);
+
+        // Assert
+        assert_eq!(sampled, expected.into_iter().collect());
+    }
+
     #[rstest]
     #[case(0, vec ! [])]
     #[case(1, vec ! [8])]
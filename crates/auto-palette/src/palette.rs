@@ -1,13 +1,23 @@
-use std::cmp::Reverse;
+use std::{
+    cmp::{Ordering, Reverse},
+    collections::HashMap,
+    fmt::Write,
+    ops::Index,
+};
+
+#[cfg(feature = "image")]
+use image::{Rgb, RgbImage};
+use num_traits::clamp;
 
 use crate::{
     algorithm::Algorithm,
-    color::{rgb_to_xyz, xyz_to_lab, Color, Lab, D65},
+    color::{rgb_to_xyz, xyz_to_lab, Color, Lab, D65, RGB, XYZ},
     error::Error,
     image::ImageData,
     math::{
         clustering::{Cluster, ClusteringAlgorithm, DBSCAN},
         denormalize,
+        neighbors::{kdtree::KDTreeSearch, search::NeighborSearch},
         normalize,
         DistanceMetric,
         FloatNumber,
@@ -15,11 +25,36 @@ use crate::{
         SamplingStrategy,
     },
     theme::Theme,
+    PaletteBuilder,
+    PaletteDiff,
     Swatch,
 };
 
+/// The chroma threshold below which a color is considered neutral.
+const NEUTRAL_CHROMA_THRESHOLD: f32 = 20.0;
+
+/// The maximum CIE76 color difference for two swatches to be matched in a palette diff.
+const DIFF_MATCH_THRESHOLD: f32 = 10.0;
+
+/// The minimum fraction of the image border that the background must cover contiguously.
+const BACKGROUND_BORDER_THRESHOLD: f32 = 0.5;
+
+/// The hue names with the upper bound of the hue angle in the CIE LCH(ab) color space.
+const HUE_NAMES: [(f32, &str); 8] = [
+    (15.0, "Pink"),
+    (50.0, "Red"),
+    (75.0, "Orange"),
+    (110.0, "Yellow"),
+    (165.0, "Green"),
+    (225.0, "Cyan"),
+    (310.0, "Blue"),
+    (345.0, "Purple"),
+];
+
 /// The color palette representation extracted from the image data.
 ///
+/// Two palettes are equal if they contain the same swatches, regardless of the order of the swatches.
+///
 /// # Type Parameters
 /// * `T` - The floating point type.
 ///
@@ -41,7 +76,7 @@ use crate::{
 ///     assert_eq!(swatches[2].color().to_hex_string(), "#E03C31");
 /// }
 /// ```
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone)]
 pub struct Palette<T>
 where
     T: FloatNumber,
@@ -92,344 +127,2699 @@ where
         &self.swatches
     }
 
-    /// Finds the swatches in the palette based on the theme.
+    /// Returns the colors of the swatches paired with their representative pixel positions.
+    ///
+    /// # Returns
+    /// The pairs of the color and the position in the order of the swatches.
+    /// The position is a tuple of the x and y coordinates in the image.
+    #[must_use]
+    pub fn sample_positions(&self) -> Vec<(Color<T>, (u32, u32))> {
+        self.swatches
+            .iter()
+            .map(|swatch| (*swatch.color(), swatch.position()))
+            .collect()
+    }
+
+    /// Returns an iterator over the swatches in the order defined by the given comparator.
+    ///
+    /// The swatches are not copied. Only the indices of the swatches are sorted.
     ///
     /// # Arguments
-    /// * `n` - The number of swatches to find.
+    /// * `compare` - The comparator to order the swatches.
     ///
     /// # Returns
-    /// The swatches in the palette.
+    /// An iterator over the references to the swatches in the sorted order.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::str::FromStr;
+    ///
+    /// use auto_palette::{color::Color, Palette, Swatch};
+    ///
+    /// let palette: Palette<f32> = Palette::new(vec![
+    ///     Swatch::new(Color::from_str("#FFFFFF").unwrap(), (0, 0), 10, 0.1),
+    ///     Swatch::new(Color::from_str("#000000").unwrap(), (0, 0), 90, 0.9),
+    /// ]);
+    /// let mut swatches =
+    ///     palette.iter_sorted_by(|swatch1, swatch2| swatch1.population().cmp(&swatch2.population()));
+    /// assert_eq!(swatches.next().unwrap().population(), 10);
+    /// assert_eq!(swatches.next().unwrap().population(), 90);
+    /// assert!(swatches.next().is_none());
+    /// ```
+    pub fn iter_sorted_by<F>(&self, mut compare: F) -> impl Iterator<Item = &Swatch<T>>
+    where
+        F: FnMut(&Swatch<T>, &Swatch<T>) -> Ordering,
+    {
+        let mut indices: Vec<usize> = (0..self.swatches.len()).collect();
+        indices.sort_by(|&index1, &index2| compare(&self.swatches[index1], &self.swatches[index2]));
+        indices.into_iter().map(|index| &self.swatches[index])
+    }
+
+    /// Returns the swatches sorted by the hue of their colors.
+    ///
+    /// The hue is normalized to the range [0, 360), so reddish colors on either side of 0 degrees end up at both ends.
+    /// Swatches with the same hue keep their order in the palette.
+    ///
+    /// # Returns
+    /// The swatches sorted by hue in ascending order.
     #[must_use]
-    pub fn find_swatches(&self, n: usize) -> Vec<Swatch<T>> {
-        self.find_swatches_with_theme(n, Theme::default())
+    pub fn sorted_by_hue(&self) -> Vec<Swatch<T>> {
+        self.iter_sorted_by(|swatch1, swatch2| {
+            let hue1 = swatch1.color().hue().to_degrees();
+            let hue2 = swatch2.color().hue().to_degrees();
+            hue1.partial_cmp(&hue2).unwrap_or(Ordering::Equal)
+        })
+        .copied()
+        .collect()
     }
 
-    /// Finds the swatches in the palette based on the theme.
+    /// Returns the swatches sorted by the lightness of their colors.
+    ///
+    /// Swatches with the same lightness keep their order in the palette.
+    ///
+    /// # Returns
+    /// The swatches sorted by lightness in ascending order.
+    #[must_use]
+    pub fn sorted_by_lightness(&self) -> Vec<Swatch<T>> {
+        self.iter_sorted_by(|swatch1, swatch2| {
+            let lightness1 = swatch1.color().lightness();
+            let lightness2 = swatch2.color().lightness();
+            lightness1
+                .partial_cmp(&lightness2)
+                .unwrap_or(Ordering::Equal)
+        })
+        .copied()
+        .collect()
+    }
+
+    /// Returns the swatches sorted by their population.
+    ///
+    /// Swatches with the same population keep their order in the palette.
+    ///
+    /// # Returns
+    /// The swatches sorted by population in descending order.
+    #[must_use]
+    pub fn sorted_by_population(&self) -> Vec<Swatch<T>> {
+        self.iter_sorted_by(|swatch1, swatch2| swatch2.population().cmp(&swatch1.population()))
+            .copied()
+            .collect()
+    }
+
+    /// Selects the swatches spreading across the lightness range.
+    ///
+    /// The selection starts with the most populous swatch and repeatedly adds the swatch whose lightness
+    /// is farthest from the lightness of the selected swatches, so the result is not all mid-tones.
+    /// Ties are broken by population.
     ///
     /// # Arguments
-    /// * `n` - The number of swatches to find.
-    /// * `theme` - The theme to use.
+    /// * `n` - The number of swatches to select.
     ///
     /// # Returns
-    /// The swatches in the palette based on the theme.
+    /// The selected swatches in the order of selection. If `n` exceeds the number of swatches, all swatches are returned.
     #[must_use]
-    pub fn find_swatches_with_theme(&self, n: usize, theme: Theme) -> Vec<Swatch<T>> {
-        let mut colors = Vec::with_capacity(self.swatches.len());
-        let mut weights = Vec::with_capacity(self.swatches.len());
-        for swatch in &self.swatches {
-            let color = swatch.color();
-            colors.push([color.l, color.a, color.b]);
+    pub fn balanced_selection(&self, n: usize) -> Vec<Swatch<T>> {
+        let mut candidates = self.sorted_by_population();
+        let mut selected: Vec<Swatch<T>> = Vec::with_capacity(n.min(candidates.len()));
+        while selected.len() < n && !candidates.is_empty() {
+            let distance = |swatch: &Swatch<T>| {
+                selected
+                    .iter()
+                    .map(|other| (swatch.color().lightness() - other.color().lightness()).abs())
+                    .fold(T::infinity(), T::min)
+            };
+            // The candidates are sorted by population, so the first of the farthest ones is the most populous.
+            let (index, _) = candidates.iter().enumerate().fold(
+                (0, T::neg_infinity()),
+                |(best_index, best_distance), (index, swatch)| {
+                    let distance = distance(swatch);
+                    if distance > best_distance {
+                        (index, distance)
+                    } else {
+                        (best_index, best_distance)
+                    }
+                },
+            );
+            selected.push(candidates.remove(index));
+        }
+        selected
+    }
 
-            let weight = theme.score(swatch);
-            weights.push(weight);
+    /// Returns the dominant swatch of the palette.
+    ///
+    /// # Returns
+    /// The swatch with the largest population, or `None` if the palette is empty.
+    /// If several swatches have the largest population, the first one is returned.
+    #[must_use]
+    pub fn dominant_color(&self) -> Option<&Swatch<T>> {
+        self.swatches
+            .iter()
+            .min_by_key(|swatch| Reverse(swatch.population()))
+    }
+
+    /// Returns the accent color of the palette.
+    ///
+    /// The accent color is the color of the most saturated swatch whose chroma exceeds the neutral threshold.
+    ///
+    /// # Returns
+    /// The accent color of the palette, or `None` if the palette contains only neutral colors.
+    #[must_use]
+    pub fn accent_color(&self) -> Option<Color<T>> {
+        let threshold = T::from_f32(NEUTRAL_CHROMA_THRESHOLD);
+        self.swatches
+            .iter()
+            .map(|swatch| *swatch.color())
+            .filter(|color| color.chroma() > threshold)
+            .max_by(|color1, color2| color1.chroma().partial_cmp(&color2.chroma()).unwrap())
+    }
+
+    /// Returns the WCAG contrast ratios of the swatch colors against the given color.
+    ///
+    /// # Arguments
+    /// * `against` - The color to compare against, typically the background color.
+    ///
+    /// # Returns
+    /// The pairs of the swatch color and its contrast ratio, sorted by the contrast ratio in descending order.
+    #[must_use]
+    pub fn contrast_report(&self, against: &Color<T>) -> Vec<(Color<T>, T)> {
+        let mut report: Vec<(Color<T>, T)> = self
+            .swatches
+            .iter()
+            .map(|swatch| (*swatch.color(), swatch.color().contrast_ratio(against)))
+            .collect();
+        report.sort_by(|(_, ratio1), (_, ratio2)| {
+            ratio2.partial_cmp(ratio1).unwrap_or(Ordering::Equal)
+        });
+        report
+    }
+
+    /// Returns the Shannon entropy of the swatch ratio distribution in bits.
+    ///
+    /// A high entropy means the colors are evenly distributed, and a low entropy means a few colors dominate.
+    ///
+    /// # Returns
+    /// The entropy of the palette, or zero if the palette is empty.
+    #[must_use]
+    pub fn entropy(&self) -> T {
+        let total: T = self.swatches.iter().map(|swatch| swatch.ratio()).sum();
+        if total <= T::zero() {
+            return T::zero();
         }
 
-        let mut swatches = self.find_swatches_with_weights(n, colors, weights);
-        swatches.sort_by_key(|swatch| Reverse(swatch.population()));
-        swatches
+        self.swatches
+            .iter()
+            .map(|swatch| swatch.ratio() / total)
+            .filter(|&probability| probability > T::zero())
+            .fold(T::zero(), |entropy, probability| {
+                entropy - probability * probability.log2()
+            })
     }
 
+    /// Returns the gradient stops of the palette positioned by the cumulative coverage of the swatches.
+    ///
+    /// The swatches are ordered along a perceptual path that starts from the darkest swatch and repeatedly
+    /// visits the nearest remaining swatch in the CIE L*a*b* color space. Each stop is positioned at the end
+    /// of the cumulative ratio of the swatches up to it, normalized to the range [0, 1].
+    ///
+    /// # Returns
+    /// The positions and colors of the gradient stops.
     #[must_use]
-    fn find_swatches_with_weights(
-        &self,
-        n: usize,
-        colors: Vec<Point<T, 3>>,
-        weights: Vec<T>,
-    ) -> Vec<Swatch<T>> {
-        let sampling = SamplingStrategy::WeightedFarthestPointSampling::<T>(weights);
-        sampling
-            .sample(&colors, n)
+    pub fn cumulative_stops(&self) -> Vec<(T, Color<T>)> {
+        let total: T = self.swatches.iter().map(|swatch| swatch.ratio()).sum();
+        if total <= T::zero() {
+            return Vec::new();
+        }
+
+        let mut remaining: Vec<&Swatch<T>> = self.swatches.iter().collect();
+        let mut path = Vec::with_capacity(remaining.len());
+        let mut current = remaining
             .iter()
-            .map(|&index| self.swatches[index])
+            .enumerate()
+            .min_by(|(_, swatch1), (_, swatch2)| {
+                swatch1
+                    .color()
+                    .lightness()
+                    .partial_cmp(&swatch2.color().lightness())
+                    .unwrap()
+            })
+            .map(|(index, _)| index);
+        while let Some(index) = current {
+            let swatch = remaining.swap_remove(index);
+            let color = swatch.color();
+            current = remaining
+                .iter()
+                .map(|candidate| {
+                    let other = candidate.color();
+                    let dl = other.l - color.l;
+                    let da = other.a - color.a;
+                    let db = other.b - color.b;
+                    dl * dl + da * da + db * db
+                })
+                .enumerate()
+                .min_by(|(_, distance1), (_, distance2)| distance1.partial_cmp(distance2).unwrap())
+                .map(|(index, _)| index);
+            path.push(swatch);
+        }
+
+        let mut cumulative = T::zero();
+        path.into_iter()
+            .map(|swatch| {
+                cumulative += swatch.ratio();
+                (
+                    clamp(cumulative / total, T::zero(), T::one()),
+                    *swatch.color(),
+                )
+            })
             .collect()
     }
 
-    /// Extracts the palette from the image data. The default clustering algorithm is DBSCAN.
+    /// Returns a string previewing the swatches as colored blocks using ANSI escape sequences.
     ///
     /// # Arguments
-    /// * `image_data` - The image data to extract the palette from.
+    /// * `truecolor` - Whether to use 24-bit true color escape sequences instead of ANSI 256 colors.
     ///
     /// # Returns
-    /// The extracted palette.
-    pub fn extract(image_data: &ImageData) -> Result<Self, Error> {
-        Self::extract_with_algorithm(image_data, Algorithm::DBSCAN)
+    /// The preview string containing one colored block per swatch.
+    #[must_use]
+    pub fn to_ansi_preview(&self, truecolor: bool) -> String {
+        self.swatches
+            .iter()
+            .map(|swatch| {
+                let color = swatch.color();
+                if truecolor {
+                    let rgb = color.to_rgb();
+                    format!("\x1b[48;2;{};{};{}m  \x1b[0m", rgb.r, rgb.g, rgb.b)
+                } else {
+                    let ansi256 = color.to_ansi256();
+                    format!("\x1b[48;5;{}m  \x1b[0m", ansi256.code())
+                }
+            })
+            .collect()
     }
 
-    /// Extracts the palette from the image data with the given algorithm.
+    /// Returns a Tailwind CSS config snippet defining the colors of the swatches.
+    ///
+    /// The snippet extends `theme.extend.colors` with one entry per swatch, named `<prefix>-<n>` where `n` starts at 1.
+    /// Quotes and backslashes in the prefix are escaped, so the names are always valid JavaScript strings.
     ///
     /// # Arguments
-    /// * `image_data` - The image data to extract the palette from.
-    /// * `algorithm` - The clustering algorithm to use.
+    /// * `prefix` - The prefix of the color names.
     ///
     /// # Returns
-    /// The extracted palette.
-    pub fn extract_with_algorithm(
-        image_data: &ImageData,
-        algorithm: Algorithm,
-    ) -> Result<Self, Error> {
-        let pixels = image_data.data();
-        if pixels.is_empty() {
-            return Err(Error::EmptyImageData);
+    /// The Tailwind CSS config snippet.
+    #[must_use]
+    pub fn to_tailwind(&self, prefix: &str) -> String {
+        let prefix = prefix.replace('\\', "\\\\").replace('\'', "\\'");
+        let mut output =
+            String::from("module.exports = {\n  theme: {\n    extend: {\n      colors: {\n");
+        for (index, swatch) in self.swatches.iter().enumerate() {
+            let _ = writeln!(
+                output,
+                "        '{}-{}': '{}',",
+                prefix,
+                index + 1,
+                swatch.color().to_hex_string()
+            );
         }
+        output.push_str("      },\n    },\n  },\n};\n");
+        output
+    }
 
-        let width = image_data.width();
-        let height = image_data.height();
-        let pixel_clusters = cluster_foo(width as usize, height as usize, pixels, algorithm);
-        let color_clusters = cluster_foo_bar(&pixel_clusters);
+    /// Returns a CSS block defining the colors of the swatches as custom properties.
+    ///
+    /// The block defines one variable per swatch in `:root`, named `--<prefix>-<n>` where `n` starts at 1,
+    /// ordered by population in descending order.
+    ///
+    /// # Arguments
+    /// * `prefix` - The prefix of the variable names.
+    ///
+    /// # Returns
+    /// The CSS block, e.g. `:root {\n  --brand-1: #FF0000;\n}\n`.
+    #[must_use]
+    pub fn to_css_variables(&self, prefix: &str) -> String {
+        self.to_css_variables_with(|index, _| format!("{}-{}", prefix, index + 1))
+    }
 
-        let mut swatches = convert_to_swatches(
-            T::from_u32(width),
-            T::from_u32(height),
-            &color_clusters,
-            &pixel_clusters,
-        );
-        swatches.sort_by_key(|swatch| Reverse(swatch.population()));
-        Ok(Self { swatches })
+    /// Returns a CSS block defining the colors of the swatches as custom properties with custom names.
+    ///
+    /// # Arguments
+    /// * `name` - The function returning the variable name without the leading `--`,
+    ///   given the index of the swatch in population order and the swatch.
+    ///
+    /// # Returns
+    /// The CSS block with one variable per swatch in `:root`.
+    #[must_use]
+    pub fn to_css_variables_with<F>(&self, mut name: F) -> String
+    where
+        F: FnMut(usize, &Swatch<T>) -> String,
+    {
+        let mut output = String::from(":root {\n");
+        for (index, swatch) in self.sorted_by_population().iter().enumerate() {
+            let _ = writeln!(
+                output,
+                "  --{}: {};",
+                name(index, swatch),
+                swatch.color().to_hex_string()
+            );
+        }
+        output.push_str("}\n");
+        output
     }
-}
 
-#[must_use]
-fn cluster_foo<T>(
-    width: usize,
-    height: usize,
-    data: &[u8],
-    algorithm: Algorithm,
-) -> Vec<Cluster<T, 5>>
-where
-    T: FloatNumber,
-{
-    let width_f = T::from_usize(width);
-    let height_f = T::from_usize(height);
-    let points = data
-        .chunks(4)
-        .enumerate()
-        .filter_map(|(index, pixel)| {
-            // Ignore transparent pixels.
-            if pixel[3] == 0 {
-                None
-            } else {
-                let (x, y, z) = rgb_to_xyz::<T>(pixel[0], pixel[1], pixel[2]);
-                let (l, a, b) = xyz_to_lab::<T, D65>(x, y, z);
-                let x = T::from_usize(index % width);
-                let y = T::from_usize(index / width);
-                Some([
-                    normalize(l, Lab::<T>::min_l(), Lab::<T>::max_l()),
-                    normalize(a, Lab::<T>::min_a(), Lab::<T>::max_a()),
-                    normalize(b, Lab::<T>::min_b(), Lab::<T>::max_b()),
-                    normalize(x, T::zero(), width_f),
-                    normalize(y, T::zero(), height_f),
-                ])
-            }
-        })
-        .collect::<Vec<_>>();
-    algorithm.cluster::<T>(&points)
-}
+    /// Returns the swatches in the GIMP palette (`.gpl`) format.
+    ///
+    /// Each swatch is written as a row of its RGB components followed by its hexadecimal string as the color name.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the palette.
+    ///
+    /// # Returns
+    /// The GIMP palette text.
+    #[must_use]
+    pub fn to_gpl(&self, name: &str) -> String {
+        let mut output = format!("GIMP Palette\nName: {}\n#\n", name);
+        for swatch in &self.swatches {
+            let rgb = swatch.color().to_rgb();
+            let _ = writeln!(
+                output,
+                "{:>3} {:>3} {:>3}\t{}",
+                rgb.r,
+                rgb.g,
+                rgb.b,
+                swatch.color().to_hex_string()
+            );
+        }
+        output
+    }
 
-#[must_use]
-fn cluster_foo_bar<T>(pixel_clusters: &[Cluster<T, 5>]) -> Vec<Cluster<T, 3>>
-where
-    T: FloatNumber,
-{
-    let colors = pixel_clusters
-        .iter()
-        .map(|cluster| -> Point<T, 3> {
-            let centroid = cluster.centroid();
-            [
-                denormalize(centroid[0], Lab::<T>::min_l(), Lab::<T>::max_l()),
-                denormalize(centroid[1], Lab::<T>::min_a(), Lab::<T>::max_a()),
-                denormalize(centroid[2], Lab::<T>::min_b(), Lab::<T>::max_b()),
-            ]
-        })
-        .collect::<Vec<_>>();
-    let algorithm = DBSCAN::new(1, T::from_f32(2.5), DistanceMetric::Euclidean).unwrap();
-    algorithm.fit(&colors)
-}
+    /// Parses a palette from the GIMP palette (`.gpl`) format.
+    ///
+    /// Comment lines starting with `#`, the `Name:` and `Columns:` lines, and the color names are ignored.
+    /// The swatches of the parsed palette have zero population and ratio.
+    ///
+    /// # Arguments
+    /// * `text` - The GIMP palette text.
+    ///
+    /// # Returns
+    /// The parsed palette.
+    ///
+    /// # Errors
+    /// Returns an error if the header is missing or a color row is invalid.
+    pub fn from_gpl(text: &str) -> Result<Self, Error> {
+        let mut lines = text.lines().map(str::trim).filter(|line| !line.is_empty());
+        if lines.next() != Some("GIMP Palette") {
+            return Err(Error::InvalidPaletteFormat {
+                details: "The header 'GIMP Palette' is missing.".to_string(),
+            });
+        }
 
-#[must_use]
-fn convert_to_swatches<T>(
-    width: T,
-    height: T,
-    color_clusters: &[Cluster<T, 3>],
-    pixel_clusters: &[Cluster<T, 5>],
-) -> Vec<Swatch<T>>
-where
-    T: FloatNumber,
-{
-    color_clusters
-        .iter()
-        .fold(Vec::new(), |mut acc, color_cluster| {
-            let mut best_color = [T::zero(); 3];
-            let mut best_position = (0, 0);
-            let mut best_population = 0;
-            let mut total_population = 0;
-            for &member in color_cluster.members() {
-                let Some(pixel_cluster) = pixel_clusters.get(member) else {
-                    continue;
-                };
+        let mut swatches = Vec::new();
+        for line in lines {
+            if line.starts_with('#') || line.starts_with("Name:") || line.starts_with("Columns:") {
+                continue;
+            }
 
-                if pixel_cluster.is_empty() {
-                    continue;
-                }
+            let mut components = line.split_whitespace().map(str::parse::<u8>);
+            let (Some(Ok(r)), Some(Ok(g)), Some(Ok(b))) =
+                (components.next(), components.next(), components.next())
+            else {
+                return Err(Error::InvalidPaletteFormat {
+                    details: format!("The color row '{}' is invalid.", line),
+                });
+            };
+            let color = Color::from_xyz(&XYZ::from(&RGB::new(r, g, b)));
+            swatches.push(Swatch::new(color, (0, 0), 0, T::zero()));
+        }
+        Ok(Self::new(swatches))
+    }
 
-                let fraction = T::from_usize(pixel_cluster.len())
-                    / T::from_usize(pixel_cluster.len() + best_population);
-                let centroid = pixel_cluster.centroid();
-                best_color[0] += fraction * (centroid[0] - best_color[0]);
-                best_color[1] += fraction * (centroid[1] - best_color[1]);
-                best_color[2] += fraction * (centroid[2] - best_color[2]);
+    /// Returns the swatches in the Adobe Swatch Exchange (`.ase`) format.
+    ///
+    /// Each swatch is written as a color entry of the RGB color model named after its hexadecimal string.
+    ///
+    /// # Returns
+    /// The bytes of the Adobe Swatch Exchange file.
+    #[must_use]
+    pub fn to_ase_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(b"ASEF");
+        // Version 1.0
+        bytes.extend_from_slice(&1_u16.to_be_bytes());
+        bytes.extend_from_slice(&0_u16.to_be_bytes());
+        bytes.extend_from_slice(&(self.swatches.len() as u32).to_be_bytes());
+        for swatch in &self.swatches {
+            let rgb = swatch.color().to_rgb();
+            // The name is a null-terminated UTF-16BE string prefixed by its length in code units.
+            let name: Vec<u16> = swatch
+                .color()
+                .to_hex_string()
+                .encode_utf16()
+                .chain(std::iter::once(0))
+                .collect();
+            let length = 2 + name.len() * 2 + 4 + 3 * 4 + 2;
 
-                if fraction >= T::from_f32(0.5) {
-                    best_position.0 = denormalize(centroid[3], T::zero(), width).to_u32_unsafe();
-                    best_position.1 = denormalize(centroid[4], T::zero(), height).to_u32_unsafe();
-                    best_population = pixel_cluster.len();
-                }
-                total_population += pixel_cluster.len();
+            // Color entry block
+            bytes.extend_from_slice(&0x0001_u16.to_be_bytes());
+            bytes.extend_from_slice(&(length as u32).to_be_bytes());
+            bytes.extend_from_slice(&(name.len() as u16).to_be_bytes());
+            for unit in name {
+                bytes.extend_from_slice(&unit.to_be_bytes());
             }
+            bytes.extend_from_slice(b"RGB ");
+            for component in [rgb.r, rgb.g, rgb.b] {
+                bytes.extend_from_slice(&(component as f32 / 255.0).to_be_bytes());
+            }
+            // Normal color type
+            bytes.extend_from_slice(&2_u16.to_be_bytes());
+        }
+        bytes
+    }
 
-            let l = denormalize(best_color[0], Lab::<T>::min_l(), Lab::<T>::max_l());
-            let a = denormalize(best_color[1], Lab::<T>::min_a(), Lab::<T>::max_a());
-            let b = denormalize(best_color[2], Lab::<T>::min_b(), Lab::<T>::max_b());
-            acc.push(Swatch::new(
-                Color::new(l, a, b),
-                best_position,
-                total_population,
-                T::from_usize(total_population) / (width * height),
-            ));
-            acc
+    /// Returns the colors of the swatches as a Vega categorical color scheme.
+    ///
+    /// The colors are ordered so that adjacent colors are as distinct as possible.
+    ///
+    /// # Returns
+    /// The hexadecimal strings of the colors, e.g. `["#FF0000", "#0000FF"]`.
+    #[must_use]
+    pub fn to_vega_scheme(&self) -> Vec<String> {
+        self.distinct_colors()
+            .iter()
+            .map(Color::to_hex_string)
+            .collect()
+    }
+
+    /// Returns the colors of the swatches as the range of a D3 ordinal color scale.
+    ///
+    /// The colors are ordered so that adjacent colors are as distinct as possible,
+    /// and are lowercase as in the built-in D3 schemes such as `d3.schemeCategory10`.
+    ///
+    /// # Returns
+    /// The hexadecimal strings of the colors, e.g. `["#ff0000", "#0000ff"]`.
+    #[must_use]
+    pub fn to_d3_scale(&self) -> Vec<String> {
+        self.distinct_colors()
+            .iter()
+            .map(|color| color.to_hex_string().to_lowercase())
+            .collect()
+    }
+
+    /// Orders the colors of the swatches so that adjacent colors are as distinct as possible.
+    ///
+    /// The order starts with the first swatch, and each next color is the remaining color farthest from the previous one.
+    ///
+    /// # Returns
+    /// The ordered colors.
+    #[must_use]
+    fn distinct_colors(&self) -> Vec<Color<T>> {
+        let mut remaining: Vec<Color<T>> =
+            self.swatches.iter().map(|swatch| *swatch.color()).collect();
+        let mut colors = Vec::with_capacity(remaining.len());
+        while !remaining.is_empty() {
+            let index = colors.last().map_or(0, |previous: &Color<T>| {
+                remaining
+                    .iter()
+                    .enumerate()
+                    .max_by(|(_, color1), (_, color2)| {
+                        color1
+                            .delta_e(previous)
+                            .partial_cmp(&color2.delta_e(previous))
+                            .unwrap()
+                    })
+                    .map_or(0, |(index, _)| index)
+            });
+            colors.push(remaining.remove(index));
+        }
+        colors
+    }
+
+    /// Renders the swatches as an image with a block of the given size per swatch.
+    ///
+    /// The blocks are laid out horizontally in the order of the swatches.
+    ///
+    /// # Arguments
+    /// * `swatch_width` - The width of each swatch block.
+    /// * `swatch_height` - The height of each swatch block.
+    ///
+    /// # Returns
+    /// The rendered image.
+    #[cfg(feature = "image")]
+    #[must_use]
+    pub fn to_image(&self, swatch_width: u32, swatch_height: u32) -> RgbImage {
+        let width = swatch_width * self.swatches.len() as u32;
+        RgbImage::from_fn(width, swatch_height, |x, _| {
+            let rgb = self.swatches[(x / swatch_width) as usize].color().to_rgb();
+            Rgb([rgb.r, rgb.g, rgb.b])
+        })
+    }
+
+    /// Returns a new palette with the color of each swatch transformed by the given function.
+    ///
+    /// The position, population, and ratio of each swatch are preserved.
+    ///
+    /// # Arguments
+    /// * `f` - The function to transform the color of each swatch.
+    ///
+    /// # Returns
+    /// A new palette with the transformed colors.
+    #[must_use]
+    pub fn map_colors<F>(&self, f: F) -> Self
+    where
+        F: Fn(&Color<T>) -> Color<T>,
+    {
+        let swatches = self
+            .swatches
+            .iter()
+            .map(|swatch| {
+                Swatch::new(
+                    f(swatch.color()),
+                    swatch.position(),
+                    swatch.population(),
+                    swatch.ratio(),
+                )
+            })
+            .collect();
+        Self::new(swatches)
+    }
+
+    /// Returns a new palette without the swatches similar to the given color.
+    ///
+    /// # Arguments
+    /// * `color` - The color to remove.
+    /// * `threshold` - The maximum CIE76 color difference for a swatch to be removed.
+    ///
+    /// # Returns
+    /// A new palette without the swatches within the threshold of the given color.
+    #[must_use]
+    pub fn without(&self, color: &Color<T>, threshold: T) -> Self {
+        let swatches = self
+            .swatches
+            .iter()
+            .filter(|swatch| swatch.color().delta_e(color) > threshold)
+            .copied()
+            .collect();
+        Self::new(swatches)
+    }
+
+    /// Compares this palette with the given palette.
+    ///
+    /// Each swatch is matched with the closest swatch of the other palette within a CIE76 color difference of 10.
+    /// The closest pairs are matched first, and each swatch is matched at most once.
+    ///
+    /// # Arguments
+    /// * `other` - The palette to compare with.
+    ///
+    /// # Returns
+    /// The report of the added, removed, and shifted swatches.
+    #[must_use]
+    pub fn diff(&self, other: &Palette<T>) -> PaletteDiff<T> {
+        let threshold = T::from_f32(DIFF_MATCH_THRESHOLD);
+        let mut pairs: Vec<(usize, usize, T)> = self
+            .swatches
+            .iter()
+            .enumerate()
+            .flat_map(|(i, swatch1)| {
+                other
+                    .swatches
+                    .iter()
+                    .enumerate()
+                    .map(move |(j, swatch2)| (i, j, swatch1.color().delta_e(swatch2.color())))
+            })
+            .filter(|&(_, _, delta)| delta <= threshold)
+            .collect();
+        pairs.sort_by(|(_, _, delta1), (_, _, delta2)| delta1.partial_cmp(delta2).unwrap());
+
+        let mut matches: Vec<Option<(usize, T)>> = vec![None; self.swatches.len()];
+        let mut matched = vec![false; other.swatches.len()];
+        for (i, j, delta) in pairs {
+            if matches[i].is_none() && !matched[j] {
+                matches[i] = Some((j, delta));
+                matched[j] = true;
+            }
+        }
+
+        let mut removed = Vec::new();
+        let mut shifted = Vec::new();
+        for (swatch, found) in self.swatches.iter().zip(matches) {
+            match found {
+                Some((j, delta)) if delta > T::zero() => {
+                    shifted.push((*swatch, other.swatches[j], delta));
+                }
+                Some(_) => {}
+                None => removed.push(*swatch),
+            }
+        }
+        let added = other
+            .swatches
+            .iter()
+            .zip(matched)
+            .filter(|(_, matched)| !matched)
+            .map(|(swatch, _)| *swatch)
+            .collect();
+        PaletteDiff::new(added, removed, shifted)
+    }
+
+    /// Merges this palette with the given palette, collapsing nearly identical colors.
+    ///
+    /// The swatches of both palettes whose colors are connected within the given CIE76 color difference
+    /// are collapsed into a single swatch. Its color is the population-weighted mix of the colors,
+    /// its population is the sum of the populations, and its position is that of the most populous swatch.
+    /// The ratios are recomputed from the combined population.
+    ///
+    /// # Arguments
+    /// * `other` - The palette to merge with.
+    /// * `epsilon` - The maximum CIE76 color difference between swatches to be collapsed.
+    ///
+    /// # Returns
+    /// The merged palette sorted by population in descending order.
+    #[must_use]
+    pub fn merge(&self, other: &Palette<T>, epsilon: T) -> Self {
+        let swatches: Vec<&Swatch<T>> = self.swatches.iter().chain(&other.swatches).collect();
+        let groups: Vec<Vec<usize>> = match DBSCAN::new(1, epsilon, DistanceMetric::Euclidean) {
+            Ok(algorithm) => {
+                let colors: Vec<Point<T, 3>> = swatches
+                    .iter()
+                    .map(|swatch| {
+                        let lab = swatch.color().to_lab();
+                        [lab.l, lab.a, lab.b]
+                    })
+                    .collect();
+                algorithm
+                    .fit(&colors)
+                    .iter()
+                    .map(|cluster| {
+                        let mut members: Vec<usize> = cluster.members().copied().collect();
+                        members.sort_unstable();
+                        members
+                    })
+                    .collect()
+            }
+            // A non-positive epsilon collapses nothing.
+            Err(_) => (0..swatches.len()).map(|index| vec![index]).collect(),
+        };
+
+        let total_population: usize = swatches.iter().map(|swatch| swatch.population()).sum();
+        let mut merged: Vec<Swatch<T>> = groups
+            .iter()
+            .filter_map(|members| {
+                let (first, rest) = members.split_first()?;
+                let mut color = *swatches[*first].color();
+                let mut best = swatches[*first];
+                let mut population = best.population();
+                // Swatches without population, e.g. parsed from a file, are weighted equally.
+                let mut weight = T::from_usize(population.max(1));
+                for &member in rest {
+                    let swatch = swatches[member];
+                    let member_weight = T::from_usize(swatch.population().max(1));
+                    weight += member_weight;
+                    color = color.mix(swatch.color(), member_weight / weight);
+                    population += swatch.population();
+                    if swatch.population() > best.population() {
+                        best = swatch;
+                    }
+                }
+                let ratio = if total_population == 0 {
+                    T::zero()
+                } else {
+                    T::from_usize(population) / T::from_usize(total_population)
+                };
+                Some(Swatch::new(color, best.position(), population, ratio))
+            })
+            .collect();
+        merged.sort_by_key(|swatch| Reverse(swatch.population()));
+        Self::new(merged)
+    }
+
+    /// Detects the background color of the palette in the given image.
+    ///
+    /// Each pixel along the border of the image is assigned to the swatch with the nearest color,
+    /// and the background is the swatch forming the longest contiguous run around the border,
+    /// provided that the run covers a large part of the border. Ties are broken by the coverage of the swatches.
+    ///
+    /// # Arguments
+    /// * `image_data` - The image data that the palette was extracted from.
+    ///
+    /// # Returns
+    /// The background color of the palette, or `None` if no swatch covers a large enough part of the border.
+    #[must_use]
+    pub fn detect_background(&self, image_data: &ImageData) -> Option<Color<T>> {
+        let border = border_indices(image_data.width() as usize, image_data.height() as usize);
+        if self.swatches.is_empty() || border.is_empty() {
+            return None;
+        }
+
+        let pixels = image_data.data();
+        let labels: Vec<Option<usize>> = border
+            .iter()
+            .map(|&index| {
+                let pixel = &pixels[index * 4..index * 4 + 4];
+                // Ignore transparent pixels.
+                if pixel[3] == 0 {
+                    return None;
+                }
+                let (x, y, z) = rgb_to_xyz::<T>(pixel[0], pixel[1], pixel[2]);
+                let (l, a, b) = xyz_to_lab::<T, D65>(x, y, z);
+                let color = Color::new(l, a, b);
+                self.swatches
+                    .iter()
+                    .enumerate()
+                    .min_by(|(_, swatch1), (_, swatch2)| {
+                        let delta1 = swatch1.color().delta_e(&color);
+                        let delta2 = swatch2.color().delta_e(&color);
+                        delta1.partial_cmp(&delta2).unwrap_or(Ordering::Equal)
+                    })
+                    .map(|(label, _)| label)
+            })
+            .collect();
+
+        // The border is a ring, so a run may wrap around from the end to the start.
+        let mut longest_runs = vec![0_usize; self.swatches.len()];
+        let start = labels
+            .iter()
+            .position(|label| *label != labels[labels.len() - 1])
+            .unwrap_or(0);
+        let mut run = (None, 0_usize);
+        for offset in 0..labels.len() {
+            let label = labels[(start + offset) % labels.len()];
+            run = if label == run.0 {
+                (label, run.1 + 1)
+            } else {
+                (label, 1)
+            };
+            if let Some(label) = label {
+                longest_runs[label] = longest_runs[label].max(run.1);
+            }
+        }
+
+        let threshold = T::from_f32(BACKGROUND_BORDER_THRESHOLD) * T::from_usize(labels.len());
+        self.swatches
+            .iter()
+            .zip(longest_runs)
+            .filter(|(_, run)| T::from_usize(*run) >= threshold)
+            .max_by(|(swatch1, run1), (swatch2, run2)| {
+                run1.cmp(run2).then(
+                    swatch1
+                        .ratio()
+                        .partial_cmp(&swatch2.ratio())
+                        .unwrap_or(Ordering::Equal),
+                )
+            })
+            .map(|(swatch, _)| *swatch.color())
+    }
+
+    /// Returns the swatches in the palette with unique descriptive names.
+    ///
+    /// Each name combines the lightness and chroma descriptors with the hue name, e.g. "Dark Muted Blue".
+    /// Neutral colors are named "Black", "White", or "Gray". Duplicate names are suffixed with a number.
+    ///
+    /// # Returns
+    /// The pairs of the name and the swatch in the order of the swatches.
+    #[must_use]
+    pub fn named_swatches(&self) -> Vec<(String, Swatch<T>)> {
+        let mut counts: HashMap<String, usize> = HashMap::new();
+        self.swatches
+            .iter()
+            .map(|swatch| {
+                let name = describe_color(swatch.color());
+                let count = counts.entry(name.clone()).or_default();
+                *count += 1;
+                if *count == 1 {
+                    (name, *swatch)
+                } else {
+                    (format!("{} {}", name, count), *swatch)
+                }
+            })
+            .collect()
+    }
+
+    /// Finds the swatches in the palette based on the theme.
+    ///
+    /// # Arguments
+    /// * `n` - The number of swatches to find.
+    ///
+    /// # Returns
+    /// The swatches in the palette.
+    #[must_use]
+    pub fn find_swatches(&self, n: usize) -> Vec<Swatch<T>> {
+        self.find_swatches_with_theme(n, Theme::default())
+    }
+
+    /// Finds the swatches in the palette based on the theme.
+    ///
+    /// # Arguments
+    /// * `n` - The number of swatches to find.
+    /// * `theme` - The theme to use.
+    ///
+    /// # Returns
+    /// The swatches in the palette based on the theme.
+    #[must_use]
+    pub fn find_swatches_with_theme(&self, n: usize, theme: Theme) -> Vec<Swatch<T>> {
+        let mut colors = Vec::with_capacity(self.swatches.len());
+        let mut weights = Vec::with_capacity(self.swatches.len());
+        for swatch in &self.swatches {
+            let color = swatch.color();
+            colors.push([color.l, color.a, color.b]);
+
+            let weight = theme.score(swatch);
+            weights.push(weight);
+        }
+
+        let mut swatches = self.find_swatches_with_weights(n, colors, weights);
+        swatches.sort_by_key(|swatch| Reverse(swatch.population()));
+        swatches
+    }
+
+    /// Finds the swatches in the palette starting from the swatch nearest to the given anchor color.
+    ///
+    /// Unlike [`Palette::find_swatches`], the sampling is seeded from the anchor, so the selections are stable
+    /// relative to the anchor across palettes extracted from different images.
+    ///
+    /// # Arguments
+    /// * `n` - The number of swatches to find.
+    /// * `anchor` - The anchor color, e.g. the brand color.
+    ///
+    /// # Returns
+    /// The swatches in the palette. The first swatch is the one nearest to the anchor color,
+    /// and the rest are sorted by population in descending order.
+    #[must_use]
+    pub fn find_swatches_from(&self, n: usize, anchor: Color<T>) -> Vec<Swatch<T>> {
+        let Some((anchor_index, _)) = self
+            .swatches
+            .iter()
+            .map(|swatch| swatch.color().delta_e(&anchor))
+            .enumerate()
+            .min_by(|(_, distance1), (_, distance2)| distance1.partial_cmp(distance2).unwrap())
+        else {
+            return Vec::new();
+        };
+
+        let theme = Theme::default();
+        let mut colors = Vec::with_capacity(self.swatches.len());
+        let mut weights = Vec::with_capacity(self.swatches.len());
+        for swatch in &self.swatches {
+            let color = swatch.color();
+            colors.push([color.l, color.a, color.b]);
+            weights.push(theme.score(swatch));
+        }
+
+        let sampling = SamplingStrategy::WeightedFarthestPointSampling::<T>(weights);
+        let mut indices: Vec<usize> = sampling
+            .sample_from(&colors, n, anchor_index)
+            .into_iter()
+            .collect();
+        indices.sort_by_key(|&index| {
+            (
+                index != anchor_index,
+                Reverse(self.swatches[index].population()),
+            )
+        });
+        indices
+            .into_iter()
+            .map(|index| self.swatches[index])
+            .collect()
+    }
+
+    /// Finds the swatches in the palette based on the prominence score.
+    ///
+    /// # Arguments
+    /// * `n` - The number of swatches to find.
+    ///
+    /// # Returns
+    /// The swatches in the palette sorted by the [`Swatch::prominence`] score in descending order.
+    #[must_use]
+    pub fn find_swatches_by_prominence(&self, n: usize) -> Vec<Swatch<T>> {
+        let mut colors = Vec::with_capacity(self.swatches.len());
+        let mut weights = Vec::with_capacity(self.swatches.len());
+        for swatch in &self.swatches {
+            let color = swatch.color();
+            colors.push([color.l, color.a, color.b]);
+            weights.push(swatch.prominence());
+        }
+
+        let mut swatches = self.find_swatches_with_weights(n, colors, weights);
+        swatches.sort_by(|swatch1, swatch2| {
+            swatch2
+                .prominence()
+                .partial_cmp(&swatch1.prominence())
+                .unwrap()
+        });
+        swatches
+    }
+
+    #[must_use]
+    fn find_swatches_with_weights(
+        &self,
+        n: usize,
+        colors: Vec<Point<T, 3>>,
+        weights: Vec<T>,
+    ) -> Vec<Swatch<T>> {
+        let sampling = SamplingStrategy::WeightedFarthestPointSampling::<T>(weights);
+        sampling
+            .sample(&colors, n)
+            .iter()
+            .map(|&index| self.swatches[index])
+            .collect()
+    }
+
+    /// Extracts the palette from the image data. The default clustering algorithm is DBSCAN.
+    ///
+    /// # Arguments
+    /// * `image_data` - The image data to extract the palette from.
+    ///
+    /// # Returns
+    /// The extracted palette.
+    pub fn extract(image_data: &ImageData) -> Result<Self, Error> {
+        Self::extract_with_algorithm(image_data, Algorithm::DBSCAN)
+    }
+
+    /// Extracts the palette from the image data with the given algorithm.
+    ///
+    /// # Arguments
+    /// * `image_data` - The image data to extract the palette from.
+    /// * `algorithm` - The clustering algorithm to use.
+    ///
+    /// # Returns
+    /// The extracted palette.
+    pub fn extract_with_algorithm(
+        image_data: &ImageData,
+        algorithm: Algorithm,
+    ) -> Result<Self, Error> {
+        PaletteBuilder::new().algorithm(algorithm).build(image_data)
+    }
+
+    /// Creates a new `PaletteBuilder` instance to extract a palette with custom options.
+    ///
+    /// # Returns
+    /// A new `PaletteBuilder` instance with the default options.
+    #[must_use]
+    pub fn builder() -> PaletteBuilder<T> {
+        PaletteBuilder::new()
+    }
+}
+
+impl<T> PartialEq for Palette<T>
+where
+    T: FloatNumber,
+{
+    fn eq(&self, other: &Self) -> bool {
+        if self.swatches.len() != other.swatches.len() {
+            return false;
+        }
+
+        // Compare the swatches as multisets because the order of the extracted swatches is not guaranteed.
+        let mut matched = vec![false; other.swatches.len()];
+        self.swatches.iter().all(|swatch| {
+            let found = other
+                .swatches
+                .iter()
+                .enumerate()
+                .position(|(index, candidate)| !matched[index] && candidate == swatch);
+            match found {
+                Some(index) => {
+                    matched[index] = true;
+                    true
+                }
+                None => false,
+            }
         })
+    }
 }
 
-#[cfg(test)]
-mod tests {
-    use std::str::FromStr;
+impl<T> Index<usize> for Palette<T>
+where
+    T: FloatNumber,
+{
+    type Output = Swatch<T>;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.swatches[index]
+    }
+}
+
+impl<'a, T> IntoIterator for &'a Palette<T>
+where
+    T: FloatNumber,
+{
+    type Item = &'a Swatch<T>;
+    type IntoIter = std::slice::Iter<'a, Swatch<T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.swatches.iter()
+    }
+}
+
+/// Returns the indices of the pixels along the border of an image, in order around the border.
+///
+/// # Arguments
+/// * `width` - The width of the image.
+/// * `height` - The height of the image.
+///
+/// # Returns
+/// The pixel indices clockwise from the top-left corner, each listed once.
+#[must_use]
+fn border_indices(width: usize, height: usize) -> Vec<usize> {
+    if width == 0 || height == 0 {
+        return Vec::new();
+    }
+    if width == 1 || height == 1 {
+        return (0..width * height).collect();
+    }
+
+    let top = 0..width;
+    let right = (1..height).map(|y| y * width + width - 1);
+    let bottom = (0..width - 1).rev().map(|x| (height - 1) * width + x);
+    let left = (1..height - 1).rev().map(|y| y * width);
+    top.chain(right).chain(bottom).chain(left).collect()
+}
+
+pub(crate) fn cluster_foo<T>(
+    width: usize,
+    height: usize,
+    data: &[u8],
+    algorithm: Algorithm,
+    seed: Option<u64>,
+) -> Result<Vec<Cluster<T, 5>>, Error>
+where
+    T: FloatNumber,
+{
+    let width_f = T::from_usize(width);
+    let height_f = T::from_usize(height);
+    let points = data
+        .chunks(4)
+        .enumerate()
+        .filter_map(|(index, pixel)| {
+            // Ignore transparent pixels.
+            if pixel[3] == 0 {
+                None
+            } else {
+                let (x, y, z) = rgb_to_xyz::<T>(pixel[0], pixel[1], pixel[2]);
+                let (l, a, b) = xyz_to_lab::<T, D65>(x, y, z);
+                let x = T::from_usize(index % width);
+                let y = T::from_usize(index / width);
+                Some([
+                    normalize(l, Lab::<T>::min_l(), Lab::<T>::max_l()),
+                    normalize(a, Lab::<T>::min_a(), Lab::<T>::max_a()),
+                    normalize(b, Lab::<T>::min_b(), Lab::<T>::max_b()),
+                    normalize(x, T::zero(), width_f),
+                    normalize(y, T::zero(), height_f),
+                ])
+            }
+        })
+        .collect::<Vec<_>>();
+    algorithm.cluster::<T>(&points, seed)
+}
+
+/// Reorders the pixel clusters into a canonical order independent of the clustering internals.
+///
+/// The clusters are ordered by their size in descending order, and ties are broken by their smallest pixel index,
+/// so the index of each cluster is the same across runs for the same clustering.
+///
+/// # Arguments
+/// * `clusters` - The pixel clusters to reorder.
+pub(crate) fn relabel_clusters<T>(clusters: &mut [Cluster<T, 5>])
+where
+    T: FloatNumber,
+{
+    clusters.sort_by_cached_key(|cluster| {
+        let min_member = cluster.members().min().copied().unwrap_or(usize::MAX);
+        (Reverse(cluster.len()), min_member)
+    });
+}
+
+#[must_use]
+pub(crate) fn cluster_foo_bar<T>(
+    pixel_clusters: &[Cluster<T, 5>],
+    epsilon: T,
+    min_points: usize,
+) -> Vec<Cluster<T, 3>>
+where
+    T: FloatNumber,
+{
+    let colors = cluster_colors(pixel_clusters);
+    cluster_color_points(&colors, epsilon, min_points)
+}
+
+/// Merges the CIE L*a*b* colors that are within the given color difference of each other.
+///
+/// # Arguments
+/// * `colors` - The colors to merge.
+/// * `epsilon` - The maximum CIE76 color difference between neighboring colors.
+/// * `min_points` - The minimum number of colors to form a merged cluster.
+///
+/// # Returns
+/// The merged clusters whose members are the indices of the colors.
+#[must_use]
+pub(crate) fn cluster_color_points<T>(
+    colors: &[Point<T, 3>],
+    epsilon: T,
+    min_points: usize,
+) -> Vec<Cluster<T, 3>>
+where
+    T: FloatNumber,
+{
+    let algorithm = DBSCAN::new(min_points, epsilon, DistanceMetric::Euclidean).unwrap();
+    algorithm.fit(colors)
+}
+
+/// Merges the pixel clusters with similar colors, weighting each pixel cluster by its population.
+///
+/// This works like DBSCAN, except that each pixel cluster counts as its population relative to the mean population.
+/// Large pixel clusters therefore anchor the merged clusters, and small ones only join them.
+/// Small pixel clusters not reachable from any large one are merged into the cluster with the nearest anchor
+/// instead of forming their own clusters. As with DBSCAN without core points, no merged clusters are returned
+/// if no pixel cluster has enough weighted neighbors to anchor one.
+///
+/// # Arguments
+/// * `pixel_clusters` - The pixel clusters to merge.
+/// * `epsilon` - The maximum CIE76 color difference between neighboring pixel clusters.
+/// * `min_points` - The minimum weighted number of neighbors for a pixel cluster to anchor a merged cluster.
+///
+/// # Returns
+/// The merged clusters whose members are the indices of the pixel clusters.
+#[must_use]
+pub(crate) fn merge_color_clusters_weighted<T>(
+    pixel_clusters: &[Cluster<T, 5>],
+    epsilon: T,
+    min_points: usize,
+) -> Vec<Cluster<T, 3>>
+where
+    T: FloatNumber,
+{
+    let colors = cluster_colors(pixel_clusters);
+    let total_population: usize = pixel_clusters.iter().map(Cluster::len).sum();
+    if total_population == 0 {
+        return Vec::new();
+    }
+
+    let mean_population = T::from_usize(total_population) / T::from_usize(pixel_clusters.len());
+    let weights: Vec<T> = pixel_clusters
+        .iter()
+        .map(|cluster| T::from_usize(cluster.len()) / mean_population)
+        .collect();
+    let neighbor_search = KDTreeSearch::build(&colors, DistanceMetric::Euclidean, 16);
+    let is_core: Vec<bool> = colors
+        .iter()
+        .map(|color| {
+            let weight = neighbor_search
+                .search_radius(color, epsilon)
+                .iter()
+                .fold(T::zero(), |total, neighbor| total + weights[neighbor.index]);
+            weight >= T::from_usize(min_points)
+        })
+        .collect();
+
+    let mut labels: Vec<Option<usize>> = vec![None; colors.len()];
+    let mut clusters: Vec<Cluster<T, 3>> = Vec::new();
+    for start in 0..colors.len() {
+        if !is_core[start] || labels[start].is_some() {
+            continue;
+        }
+
+        let label = clusters.len();
+        let mut cluster = Cluster::new();
+        let mut queue = vec![start];
+        labels[start] = Some(label);
+        while let Some(index) = queue.pop() {
+            cluster.add_member(index, &colors[index]);
+            if !is_core[index] {
+                continue;
+            }
+            for neighbor in neighbor_search.search_radius(&colors[index], epsilon) {
+                if labels[neighbor.index].is_none() {
+                    labels[neighbor.index] = Some(label);
+                    queue.push(neighbor.index);
+                }
+            }
+        }
+        clusters.push(cluster);
+    }
+
+    // Merge the unreachable pixel clusters into the cluster with the nearest anchor.
+    let (anchor_indices, anchor_colors): (Vec<usize>, Vec<Point<T, 3>>) = colors
+        .iter()
+        .enumerate()
+        .filter(|(index, _)| is_core[*index])
+        .map(|(index, color)| (index, *color))
+        .unzip();
+    let anchor_search = KDTreeSearch::build(&anchor_colors, DistanceMetric::Euclidean, 16);
+    for (index, color) in colors.iter().enumerate() {
+        if labels[index].is_some() {
+            continue;
+        }
+        let nearest = anchor_search
+            .search_nearest(color)
+            .and_then(|neighbor| labels[anchor_indices[neighbor.index]]);
+        if let Some(label) = nearest {
+            clusters[label].add_member(index, color);
+        }
+    }
+    clusters
+}
+
+/// Returns the CIE L*a*b* colors of the centroids of the pixel clusters.
+///
+/// # Arguments
+/// * `pixel_clusters` - The pixel clusters.
+///
+/// # Returns
+/// The colors of the pixel clusters.
+#[must_use]
+pub(crate) fn cluster_colors<T>(pixel_clusters: &[Cluster<T, 5>]) -> Vec<Point<T, 3>>
+where
+    T: FloatNumber,
+{
+    pixel_clusters
+        .iter()
+        .map(|cluster| -> Point<T, 3> {
+            let centroid = cluster.centroid();
+            [
+                denormalize(centroid[0], Lab::<T>::min_l(), Lab::<T>::max_l()),
+                denormalize(centroid[1], Lab::<T>::min_a(), Lab::<T>::max_a()),
+                denormalize(centroid[2], Lab::<T>::min_b(), Lab::<T>::max_b()),
+            ]
+        })
+        .collect()
+}
+
+#[must_use]
+pub(crate) fn convert_to_swatches<T>(
+    dimensions: &[(T, T)],
+    total_pixels: T,
+    color_clusters: &[Cluster<T, 3>],
+    pixel_clusters: &[Cluster<T, 5>],
+) -> Vec<Swatch<T>>
+where
+    T: FloatNumber,
+{
+    color_clusters
+        .iter()
+        .fold(Vec::new(), |mut acc, color_cluster| {
+            let mut best_color = [T::zero(); 3];
+            let mut best_position = (0, 0);
+            let mut best_population = 0;
+            let mut total_population = 0;
+            // Sort the members to make the averaged color independent of the hash set iteration order.
+            let mut members: Vec<usize> = color_cluster.members().copied().collect();
+            members.sort_unstable();
+            for member in members {
+                let Some(pixel_cluster) = pixel_clusters.get(member) else {
+                    continue;
+                };
+
+                if pixel_cluster.is_empty() {
+                    continue;
+                }
+
+                let fraction = T::from_usize(pixel_cluster.len())
+                    / T::from_usize(pixel_cluster.len() + best_population);
+                let centroid = pixel_cluster.centroid();
+                best_color[0] += fraction * (centroid[0] - best_color[0]);
+                best_color[1] += fraction * (centroid[1] - best_color[1]);
+                best_color[2] += fraction * (centroid[2] - best_color[2]);
+
+                if fraction >= T::from_f32(0.5) {
+                    let (width, height) = dimensions[member];
+                    best_position.0 = denormalize(centroid[3], T::zero(), width).to_u32_unsafe();
+                    best_position.1 = denormalize(centroid[4], T::zero(), height).to_u32_unsafe();
+                    best_population = pixel_cluster.len();
+                }
+                total_population += pixel_cluster.len();
+            }
+
+            let l = denormalize(best_color[0], Lab::<T>::min_l(), Lab::<T>::max_l());
+            let a = denormalize(best_color[1], Lab::<T>::min_a(), Lab::<T>::max_a());
+            let b = denormalize(best_color[2], Lab::<T>::min_b(), Lab::<T>::max_b());
+            acc.push(Swatch::new(
+                Color::new(l, a, b),
+                best_position,
+                total_population,
+                T::from_usize(total_population) / total_pixels,
+            ));
+            acc
+        })
+}
+
+#[must_use]
+fn describe_color<T>(color: &Color<T>) -> String
+where
+    T: FloatNumber,
+{
+    let lightness = color.lightness();
+    let chroma = color.chroma();
+    if chroma < T::from_f32(NEUTRAL_CHROMA_THRESHOLD) {
+        return if lightness < T::from_f32(15.0) {
+            "Black".to_string()
+        } else if lightness > T::from_f32(90.0) {
+            "White".to_string()
+        } else if lightness < T::from_f32(35.0) {
+            "Dark Gray".to_string()
+        } else if lightness > T::from_f32(70.0) {
+            "Light Gray".to_string()
+        } else {
+            "Gray".to_string()
+        };
+    }
+
+    let mut words = Vec::with_capacity(3);
+    if lightness < T::from_f32(35.0) {
+        words.push("Dark");
+    } else if lightness > T::from_f32(70.0) {
+        words.push("Light");
+    }
+    if chroma < T::from_f32(40.0) {
+        words.push("Muted");
+    } else if chroma > T::from_f32(80.0) {
+        words.push("Vivid");
+    }
+
+    let hue = color.hue().to_degrees();
+    let hue_name = HUE_NAMES
+        .iter()
+        .find(|(bound, _)| hue < T::from_f32(*bound))
+        .map_or("Pink", |(_, name)| name);
+    words.push(hue_name);
+    words.join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use rstest::rstest;
+
+    use super::*;
+
+    #[must_use]
+    fn sample_swatches<T>() -> Vec<Swatch<T>>
+    where
+        T: FloatNumber,
+    {
+        vec![
+            Swatch::new(
+                Color::from_str("#FFFFFF").unwrap(),
+                (159, 106),
+                61228,
+                T::from_f64(0.9214),
+            ),
+            Swatch::new(
+                Color::from_str("#EE334E").unwrap(),
+                (238, 89),
+                1080,
+                T::from_f64(0.0163),
+            ),
+            Swatch::new(
+                Color::from_str("#0081C8").unwrap(),
+                (82, 88),
+                1064,
+                T::from_f64(0.0160),
+            ),
+            Swatch::new(
+                Color::from_str("#00A651").unwrap(),
+                (197, 123),
+                1037,
+                T::from_f64(0.0156),
+            ),
+            Swatch::new(
+                Color::from_str("#000000").unwrap(),
+                (157, 95),
+                1036,
+                T::from_f64(0.0156),
+            ),
+            Swatch::new(
+                Color::from_str("#FCB131").unwrap(),
+                (119, 123),
+                1005,
+                T::from_f64(0.0151),
+            ),
+        ]
+    }
+
+    #[must_use]
+    fn empty_swatches<T>() -> Vec<Swatch<T>>
+    where
+        T: FloatNumber,
+    {
+        vec![]
+    }
+
+    #[test]
+    fn test_new() {
+        // Act
+        let swatches = vec![
+            Swatch::<f32>::new(Color::from_str("#FFFFFF").unwrap(), (5, 10), 256, 0.5714),
+            Swatch::<f32>::new(Color::from_str("#C8102E").unwrap(), (15, 20), 128, 0.2857),
+            Swatch::<f32>::new(Color::from_str("#012169").unwrap(), (30, 30), 64, 0.1429),
+        ];
+        let actual = Palette::new(swatches.clone());
+
+        // Assert
+        assert!(!actual.is_empty());
+        assert_eq!(actual.len(), 3);
+        assert_eq!(actual.swatches, swatches);
+    }
+
+    #[test]
+    fn test_new_empty() {
+        // Act
+        let swatches = vec![];
+        let actual: Palette<f32> = Palette::new(swatches.clone());
+
+        // Assert
+        assert!(actual.is_empty());
+        assert_eq!(actual.len(), 0);
+    }
+
+    #[test]
+    fn test_eq_ignores_order() {
+        // Arrange
+        let swatches = sample_swatches::<f32>();
+        let mut reversed = swatches.clone();
+        reversed.reverse();
+
+        // Act
+        let palette1 = Palette::new(swatches);
+        let palette2 = Palette::new(reversed);
+
+        // Assert
+        assert_eq!(palette1, palette2);
+    }
+
+    #[test]
+    fn test_eq_different_swatches() {
+        // Arrange
+        let swatches = sample_swatches::<f32>();
+        let mut duplicated = swatches.clone();
+        duplicated[1] = duplicated[0];
+
+        // Act
+        let palette1 = Palette::new(swatches.clone());
+        let palette2 = Palette::new(duplicated);
+        let palette3 = Palette::new(swatches[..3].to_vec());
+
+        // Assert
+        assert_ne!(palette1, palette2);
+        assert_ne!(palette1, palette3);
+        assert_ne!(palette1, Palette::new(empty_swatches()));
+    }
+
+    #[test]
+    #[cfg(feature = "image")]
+    fn test_extract_twice_equal() {
+        // Arrange
+        let image_data = ImageData::load("../../gfx/olympic_logo.png").unwrap();
+
+        // Act
+        let palette1: Palette<f32> = Palette::extract(&image_data).unwrap();
+        let palette2: Palette<f32> = Palette::extract(&image_data).unwrap();
+
+        // Assert
+        assert_eq!(palette1, palette2);
+    }
+
+    #[test]
+    #[cfg(feature = "image")]
+    fn test_extract() {
+        // Act
+        let image_data = ImageData::load("../../gfx/olympic_logo.png").unwrap();
+        let actual: Palette<f32> = Palette::extract(&image_data).unwrap();
+
+        // Assert
+        assert!(!actual.is_empty());
+        assert!(actual.len() >= 3);
+    }
+
+    #[cfg(feature = "image")]
+    #[rstest]
+    #[case::kmeans("kmeans")]
+    #[case::dbscan("dbscan")]
+    #[case::dbscanpp("dbscan++")]
+    #[case::median_cut("mediancut")]
+    #[case::octree("octree")]
+    fn test_extract_with_algorithm(#[case] name: &str) {
+        // Act
+        let image_data = ImageData::load("../../gfx/olympic_logo.png").unwrap();
+        let algorithm = Algorithm::from_str(name).unwrap();
+        let actual: Palette<f32> = Palette::extract_with_algorithm(&image_data, algorithm).unwrap();
+
+        // Assert
+        assert!(!actual.is_empty());
+        assert!(actual.len() >= 5);
+    }
+
+    #[test]
+    fn test_extract_with_uniform_grid() {
+        // Arrange
+        let pixels: Vec<u8> = (0..64u32)
+            .flat_map(|y| (0..64u32).flat_map(move |x| [(x * 4) as u8, (y * 4) as u8, 128, 255]))
+            .collect();
+        let image_data = ImageData::new(64, 64, &pixels).unwrap();
+
+        // Act
+        let algorithm = Algorithm::UniformGrid {
+            bits_per_channel: 2,
+        };
+        let actual: Palette<f32> = Palette::extract_with_algorithm(&image_data, algorithm).unwrap();
+
+        // Assert
+        assert!(actual.len() > 1);
+        assert!(actual.len() <= 64);
+        assert_eq!(
+            actual
+                .swatches()
+                .iter()
+                .map(Swatch::population)
+                .sum::<usize>(),
+            64 * 64
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "image")]
+    fn test_extract_grayscale_image() {
+        // Arrange
+        let image = image::GrayImage::from_fn(64, 64, |x, _| image::Luma([(x * 4) as u8]));
+        let image_data = ImageData::from(&image);
+
+        // Act
+        let actual: Palette<f32> = Palette::extract_with_algorithm(
+            &image_data,
+            Algorithm::UniformGrid {
+                bits_per_channel: 4,
+            },
+        )
+        .unwrap();
+
+        // Assert
+        assert!(actual.len() > 1);
+        assert!(
+            actual
+                .swatches()
+                .iter()
+                .all(|swatch| swatch.color().chroma() < 1.0)
+        );
+        let lightness = actual
+            .swatches()
+            .iter()
+            .map(|swatch| swatch.color().lightness());
+        let min = lightness.clone().fold(f32::MAX, f32::min);
+        let max = lightness.fold(f32::MIN, f32::max);
+        assert!(max - min > 50.0);
+    }
+
+    #[test]
+    fn test_relabel_clusters() {
+        // Arrange
+        let mut small = Cluster::<f32, 5>::new();
+        small.add_member(7, &[0.0; 5]);
+        let mut large_late = Cluster::<f32, 5>::new();
+        large_late.add_member(5, &[0.0; 5]);
+        large_late.add_member(3, &[0.0; 5]);
+        let mut large_early = Cluster::<f32, 5>::new();
+        large_early.add_member(4, &[0.0; 5]);
+        large_early.add_member(1, &[0.0; 5]);
+        let empty = Cluster::<f32, 5>::new();
+        let mut clusters = vec![empty, small, large_late, large_early];
+
+        // Act
+        relabel_clusters(&mut clusters);
+
+        // Assert
+        let actual: Vec<Vec<usize>> = clusters
+            .iter()
+            .map(|cluster| {
+                let mut members: Vec<usize> = cluster.members().copied().collect();
+                members.sort_unstable();
+                members
+            })
+            .collect();
+        assert_eq!(actual, vec![vec![1, 4], vec![3, 5], vec![7], vec![]]);
+    }
+
+    #[test]
+    #[cfg(feature = "image")]
+    fn test_extract_twice_identical_order() {
+        // Arrange
+        let image_data = ImageData::load("../../gfx/olympic_logo.png").unwrap();
+
+        // Act
+        let palette1: Palette<f32> = Palette::extract(&image_data).unwrap();
+        let palette2: Palette<f32> = Palette::extract(&image_data).unwrap();
+
+        // Assert
+        assert_eq!(palette1.swatches(), palette2.swatches());
+    }
+
+    #[test]
+    fn test_extract_empty_image_data() {
+        // Act
+        let data = Vec::<u8>::new();
+        let image_data = ImageData::new(0, 0, &data).unwrap();
+        let result = Palette::<f32>::extract(&image_data);
+
+        // Assert
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "The image data is empty and cannot be processed."
+        );
+    }
+
+    #[test]
+    fn test_sorted_by_hue() {
+        // Arrange
+        let palette = Palette::new(sample_swatches::<f32>());
+
+        // Act
+        let actual = palette.sorted_by_hue();
+
+        // Assert
+        let colors: Vec<String> = actual
+            .iter()
+            .map(|swatch| swatch.color().to_hex_string())
+            .collect();
+        assert_eq!(
+            colors,
+            vec![
+                "#000000", "#EE334E", "#FCB131", "#FFFFFF", "#00A651", "#0081C8"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_sorted_by_hue_wraps_around() {
+        // Arrange
+        let swatches = vec![
+            Swatch::<f32>::new(Color::from_str("#FF00FF").unwrap(), (0, 0), 1, 0.25),
+            Swatch::<f32>::new(Color::from_str("#FF8000").unwrap(), (0, 0), 1, 0.25),
+            Swatch::<f32>::new(Color::from_str("#FF0000").unwrap(), (0, 0), 1, 0.25),
+            Swatch::<f32>::new(Color::from_str("#0000FF").unwrap(), (0, 0), 1, 0.25),
+        ];
+        let palette = Palette::new(swatches);
+
+        // Act
+        let actual = palette.sorted_by_hue();
+
+        // Assert
+        let hues: Vec<f32> = actual
+            .iter()
+            .map(|swatch| swatch.color().hue().to_degrees())
+            .collect();
+        assert!(hues.iter().all(|hue| (0.0..360.0).contains(hue)));
+        assert!(hues.windows(2).all(|pair| pair[0] <= pair[1]));
+        assert_eq!(actual[0].color().to_hex_string(), "#FF0000");
+        assert_eq!(actual[3].color().to_hex_string(), "#FF00FF");
+    }
+
+    #[test]
+    fn test_sorted_by_lightness() {
+        // Arrange
+        let palette = Palette::new(sample_swatches::<f32>());
+
+        // Act
+        let actual = palette.sorted_by_lightness();
+
+        // Assert
+        let colors: Vec<String> = actual
+            .iter()
+            .map(|swatch| swatch.color().to_hex_string())
+            .collect();
+        assert_eq!(
+            colors,
+            vec![
+                "#000000", "#0081C8", "#EE334E", "#00A651", "#FCB131", "#FFFFFF"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_sorted_by_population() {
+        // Arrange
+        let mut swatches = sample_swatches::<f32>();
+        swatches.reverse();
+        let palette = Palette::new(swatches);
+
+        // Act
+        let actual = palette.sorted_by_population();
+
+        // Assert
+        assert_eq!(actual, sample_swatches::<f32>());
+    }
+
+    #[test]
+    fn test_sorted_by_population_stable() {
+        // Arrange
+        let swatches = vec![
+            Swatch::<f32>::new(Color::from_str("#FF0000").unwrap(), (0, 0), 10, 0.25),
+            Swatch::<f32>::new(Color::from_str("#00FF00").unwrap(), (0, 0), 20, 0.25),
+            Swatch::<f32>::new(Color::from_str("#0000FF").unwrap(), (0, 0), 10, 0.25),
+        ];
+        let palette = Palette::new(swatches);
+
+        // Act
+        let actual = palette.sorted_by_population();
+
+        // Assert
+        let colors: Vec<String> = actual
+            .iter()
+            .map(|swatch| swatch.color().to_hex_string())
+            .collect();
+        assert_eq!(colors, vec!["#00FF00", "#FF0000", "#0000FF"]);
+    }
+
+    #[test]
+    fn test_index() {
+        // Arrange
+        let palette = Palette::new(sample_swatches::<f32>());
+
+        // Act
+        let actual = &palette[1];
+
+        // Assert
+        assert_eq!(actual, &palette.swatches()[1]);
+        assert_eq!(actual.color().to_hex_string(), "#EE334E");
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_index_out_of_bounds() {
+        // Arrange
+        let palette = Palette::<f32>::new(empty_swatches());
+
+        // Act
+        let _ = &palette[0];
+    }
+
+    #[test]
+    fn test_into_iter() {
+        // Arrange
+        let palette = Palette::new(sample_swatches::<f32>());
+
+        // Act
+        let mut actual = Vec::new();
+        for swatch in &palette {
+            actual.push(*swatch);
+        }
+
+        // Assert
+        assert_eq!(actual, palette.swatches());
+    }
+
+    #[test]
+    fn test_merge() {
+        // Arrange
+        let palette1 = Palette::new(vec![
+            Swatch::<f32>::new(Color::from_str("#FF0000").unwrap(), (1, 1), 300, 0.3),
+            Swatch::<f32>::new(Color::from_str("#0000FF").unwrap(), (2, 2), 700, 0.7),
+        ]);
+        let palette2 = Palette::new(vec![
+            Swatch::<f32>::new(Color::from_str("#FE0101").unwrap(), (3, 3), 900, 0.9),
+            Swatch::<f32>::new(Color::from_str("#00FF00").unwrap(), (4, 4), 100, 0.1),
+        ]);
+
+        // Act
+        let actual = palette1.merge(&palette2, 2.5);
+
+        // Assert
+        assert_eq!(actual.len(), 3);
+
+        let red = &actual[0];
+        assert_eq!(red.population(), 1200);
+        assert_eq!(red.position(), (3, 3));
+        assert!((red.ratio() - 0.6).abs() < 1e-6);
+        let expected = Color::from_str("#FF0000")
+            .unwrap()
+            .mix(&Color::from_str("#FE0101").unwrap(), 0.75);
+        assert!(red.color().delta_e(&expected) < 1e-3);
+
+        assert_eq!(actual[1].color().to_hex_string(), "#0000FF");
+        assert_eq!(actual[2].color().to_hex_string(), "#00FF00");
+        assert_eq!(
+            actual
+                .swatches()
+                .iter()
+                .map(Swatch::population)
+                .sum::<usize>(),
+            2000
+        );
+    }
+
+    #[test]
+    fn test_merge_without_collapsing() {
+        // Arrange
+        let palette = Palette::new(sample_swatches::<f32>());
+
+        // Act
+        let actual = palette.merge(&palette, 0.0);
+
+        // Assert
+        assert_eq!(actual.len(), 12);
+        assert!(
+            actual
+                .swatches()
+                .windows(2)
+                .all(|pair| pair[0].population() >= pair[1].population())
+        );
+    }
+
+    #[test]
+    fn test_merge_with_itself() {
+        // Arrange
+        let palette = Palette::new(sample_swatches::<f32>());
+
+        // Act
+        let actual = palette.merge(&palette, 1.0);
+
+        // Assert
+        assert_eq!(actual.len(), palette.len());
+        for (actual, expected) in actual.swatches().iter().zip(palette.swatches()) {
+            assert_eq!(actual.population(), expected.population() * 2);
+            assert!(actual.color().delta_e(expected.color()) < 1e-3);
+        }
+    }
+
+    #[test]
+    fn test_balanced_selection() {
+        // Arrange
+        let swatches = vec![
+            Swatch::<f32>::new(Color::from_lab(50.0, 20.0, 0.0), (0, 0), 400, 0.4),
+            Swatch::<f32>::new(Color::from_lab(55.0, -20.0, 10.0), (0, 0), 250, 0.25),
+            Swatch::<f32>::new(Color::from_lab(45.0, 0.0, -30.0), (0, 0), 200, 0.2),
+            Swatch::<f32>::new(Color::from_lab(52.0, 10.0, 30.0), (0, 0), 100, 0.1),
+            Swatch::<f32>::new(Color::from_lab(10.0, 5.0, 5.0), (0, 0), 30, 0.03),
+            Swatch::<f32>::new(Color::from_lab(95.0, 0.0, 5.0), (0, 0), 20, 0.02),
+        ];
+        let palette = Palette::new(swatches);
+
+        // Act
+        let actual = palette.balanced_selection(3);
+
+        // Assert
+        let spread = |swatches: &[Swatch<f32>]| {
+            let lightness = swatches.iter().map(|swatch| swatch.color().lightness());
+            let min = lightness.clone().fold(f32::MAX, f32::min);
+            let max = lightness.fold(f32::MIN, f32::max);
+            max - min
+        };
+        let top = &palette.sorted_by_population()[..3];
+        assert_eq!(actual.len(), 3);
+        assert_eq!(actual[0].population(), 400);
+        assert!(spread(&actual) > spread(top));
+        assert!((spread(&actual) - 85.0).abs() < 1e-3);
+    }
+
+    #[rstest]
+    #[case::zero(0, 0)]
+    #[case::all(6, 6)]
+    #[case::more_than_all(10, 6)]
+    fn test_balanced_selection_count(#[case] n: usize, #[case] expected: usize) {
+        // Arrange
+        let palette = Palette::new(sample_swatches::<f32>());
+
+        // Act
+        let actual = palette.balanced_selection(n);
+
+        // Assert
+        assert_eq!(actual.len(), expected);
+    }
+
+    #[test]
+    fn test_dominant_color() {
+        // Arrange
+        let mut swatches = sample_swatches::<f32>();
+        swatches.reverse();
+        let palette = Palette::new(swatches);
+
+        // Act
+        let actual = palette.dominant_color();
+
+        // Assert
+        assert!(actual.is_some());
+        assert_eq!(actual.unwrap().color().to_hex_string(), "#FFFFFF");
+        assert_eq!(actual.unwrap().population(), 61228);
+    }
+
+    #[test]
+    fn test_dominant_color_empty() {
+        // Arrange
+        let palette = Palette::<f32>::new(empty_swatches());
+
+        // Act
+        let actual = palette.dominant_color();
+
+        // Assert
+        assert!(actual.is_none());
+    }
+
+    #[test]
+    fn test_contrast_report() {
+        // Arrange
+        let palette = Palette::new(sample_swatches::<f32>());
+        let white = Color::from_str("#FFFFFF").unwrap();
+
+        // Act
+        let actual = palette.contrast_report(&white);
+
+        // Assert
+        let colors: Vec<String> = actual
+            .iter()
+            .map(|(color, _)| color.to_hex_string())
+            .collect();
+        assert_eq!(
+            colors,
+            vec![
+                "#000000", "#0081C8", "#EE334E", "#00A651", "#FCB131", "#FFFFFF"
+            ]
+        );
+        assert!((actual[0].1 - 21.0).abs() < 1e-3);
+        assert!((actual[5].1 - 1.0).abs() < 1e-3);
+        assert!(actual.windows(2).all(|pair| pair[0].1 >= pair[1].1));
+    }
+
+    #[test]
+    fn test_contrast_report_empty() {
+        // Arrange
+        let palette = Palette::<f32>::new(empty_swatches());
+
+        // Act
+        let actual = palette.contrast_report(&Color::from_str("#FFFFFF").unwrap());
+
+        // Assert
+        assert!(actual.is_empty());
+    }
+
+    #[test]
+    fn test_accent_color() {
+        // Arrange
+        let swatches = vec![
+            Swatch::<f32>::new(Color::from_str("#808080").unwrap(), (5, 10), 896, 0.875),
+            Swatch::<f32>::new(Color::from_str("#FF0000").unwrap(), (15, 20), 96, 0.094),
+            Swatch::<f32>::new(Color::from_str("#A05A5A").unwrap(), (30, 30), 32, 0.031),
+        ];
+        let palette = Palette::new(swatches);
+
+        // Act
+        let actual = palette.accent_color();
+
+        // Assert
+        assert!(actual.is_some());
+        assert_eq!(actual.unwrap().to_hex_string(), "#FF0000");
+    }
+
+    #[test]
+    fn test_accent_color_neutral() {
+        // Arrange
+        let swatches = vec![
+            Swatch::<f32>::new(Color::from_str("#FFFFFF").unwrap(), (5, 10), 256, 0.5),
+            Swatch::<f32>::new(Color::from_str("#808080").unwrap(), (15, 20), 128, 0.25),
+            Swatch::<f32>::new(Color::from_str("#000000").unwrap(), (30, 30), 128, 0.25),
+        ];
+        let palette = Palette::new(swatches);
+
+        // Act
+        let actual = palette.accent_color();
+
+        // Assert
+        assert!(actual.is_none());
+    }
+
+    /// Returns the pixels of a 32x32 image with a border of the given color around a red center.
+    fn bordered_pixels(border: impl Fn(usize, usize) -> [u8; 4]) -> Vec<u8> {
+        (0..32)
+            .flat_map(|y| (0..32).map(move |x| (x, y)))
+            .flat_map(|(x, y)| {
+                if (4..28).contains(&x) && (4..28).contains(&y) {
+                    [255, 0, 0, 255]
+                } else {
+                    border(x, y)
+                }
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_detect_background() {
+        // Arrange
+        let pixels = bordered_pixels(|_, _| [255, 255, 255, 255]);
+        let image_data = ImageData::new(32, 32, &pixels).unwrap();
+        let palette: Palette<f32> = Palette::extract_with_algorithm(
+            &image_data,
+            Algorithm::UniformGrid {
+                bits_per_channel: 4,
+            },
+        )
+        .unwrap();
+
+        // Act
+        let actual = palette.detect_background(&image_data);
+
+        // Assert
+        assert_eq!(palette[0].color().to_hex_string(), "#FF0000");
+        assert!(actual.is_some());
+        assert_eq!(actual.unwrap().to_hex_string(), "#FFFFFF");
+    }
+
+    #[test]
+    fn test_detect_background_fragmented_border() {
+        // Arrange
+        let pixels = bordered_pixels(|x, y| {
+            if (x / 4 + y / 4) % 2 == 0 {
+                [255, 255, 255, 255]
+            } else {
+                [0, 0, 255, 255]
+            }
+        });
+        let image_data = ImageData::new(32, 32, &pixels).unwrap();
+        let palette: Palette<f32> = Palette::extract_with_algorithm(
+            &image_data,
+            Algorithm::UniformGrid {
+                bits_per_channel: 4,
+            },
+        )
+        .unwrap();
+
+        // Act
+        let actual = palette.detect_background(&image_data);
+
+        // Assert
+        assert!(actual.is_none());
+    }
+
+    #[rstest]
+    #[case::empty_palette(vec![], 32)]
+    #[case::empty_image(vec![Swatch::new(Color::from_str("#FFFFFF").unwrap(), (0, 0), 1, 1.0)], 0)]
+    fn test_detect_background_none(#[case] swatches: Vec<Swatch<f32>>, #[case] size: u32) {
+        // Arrange
+        let pixels = [255, 255, 255, 255].repeat((size * size) as usize);
+        let image_data = ImageData::new(size, size, &pixels).unwrap();
+        let palette = Palette::new(swatches);
+
+        // Act
+        let actual = palette.detect_background(&image_data);
+
+        // Assert
+        assert!(actual.is_none());
+    }
+
+    #[rstest]
+    #[case::empty(0, 0, vec![])]
+    #[case::single_row(3, 1, vec![0, 1, 2])]
+    #[case::square(3, 3, vec![0, 1, 2, 5, 8, 7, 6, 3])]
+    fn test_border_indices(
+        #[case] width: usize,
+        #[case] height: usize,
+        #[case] expected: Vec<usize>,
+    ) {
+        // Act
+        let actual = border_indices(width, height);
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_map_colors() {
+        // Arrange
+        let palette = Palette::new(sample_swatches::<f32>());
+
+        // Act
+        let actual = palette.map_colors(|color| Color::new(color.l, 0.0, 0.0));
+
+        // Assert
+        assert_eq!(actual.len(), palette.len());
+        for (actual, original) in actual.swatches().iter().zip(palette.swatches()) {
+            assert!(actual.color().chroma() < 1e-6);
+            assert_eq!(actual.color().lightness(), original.color().lightness());
+            assert_eq!(actual.position(), original.position());
+            assert_eq!(actual.population(), original.population());
+            assert_eq!(actual.ratio(), original.ratio());
+        }
+    }
+
+    #[test]
+    fn test_sample_positions() {
+        // Arrange
+        let palette = Palette::new(sample_swatches::<f32>());
+
+        // Act
+        let actual = palette.sample_positions();
+
+        // Assert
+        assert_eq!(actual.len(), palette.len());
+        assert_eq!(actual[0].0.to_hex_string(), "#FFFFFF");
+        assert_eq!(actual[0].1, (159, 106));
+        assert_eq!(actual[5].0.to_hex_string(), "#FCB131");
+        assert_eq!(actual[5].1, (119, 123));
+    }
+
+    #[test]
+    #[cfg(feature = "image")]
+    fn test_sample_positions_extracted() {
+        // Arrange
+        let image_data = ImageData::load("../../gfx/olympic_logo.png").unwrap();
+        let palette: Palette<f32> = Palette::extract(&image_data).unwrap();
+
+        // Act
+        let actual = palette.sample_positions();
+
+        // Assert
+        assert_eq!(actual.len(), palette.len());
+        assert!(
+            actual
+                .iter()
+                .all(|(_, (x, y))| *x < image_data.width() && *y < image_data.height())
+        );
+    }
+
+    #[test]
+    fn test_iter_sorted_by() {
+        // Arrange
+        let palette = Palette::new(sample_swatches::<f32>());
+
+        // Act
+        let actual: Vec<&Swatch<f32>> = palette
+            .iter_sorted_by(|swatch1, swatch2| {
+                swatch1
+                    .color()
+                    .lightness()
+                    .partial_cmp(&swatch2.color().lightness())
+                    .unwrap()
+            })
+            .collect();
+
+        // Assert
+        assert_eq!(actual.len(), palette.len());
+        let hex: Vec<String> = actual
+            .iter()
+            .map(|swatch| swatch.color().to_hex_string())
+            .collect();
+        assert_eq!(
+            hex,
+            vec![
+                "#000000", "#0081C8", "#EE334E", "#00A651", "#FCB131", "#FFFFFF"
+            ]
+        );
+        assert!(actual.iter().all(|swatch| {
+            palette
+                .swatches()
+                .iter()
+                .any(|other| std::ptr::eq(*swatch, other))
+        }));
+    }
+
+    #[test]
+    fn test_without() {
+        // Arrange
+        let palette = Palette::new(sample_swatches::<f32>());
+        let white = Color::from_str("#FFFFFF").unwrap();
+        let black = Color::from_str("#000000").unwrap();
+
+        // Act
+        let actual = palette.without(&white, 5.0).without(&black, 5.0);
+
+        // Assert
+        assert_eq!(palette.len(), 6);
+        assert_eq!(actual.len(), 4);
+        assert!(
+            actual
+                .swatches()
+                .iter()
+                .all(|swatch| swatch.color().delta_e(&white) > 5.0)
+        );
+    }
+
+    #[test]
+    fn test_diff() {
+        // Arrange
+        let palette = Palette::new(sample_swatches::<f32>());
+        let mut swatches = sample_swatches::<f32>();
+        let removed = swatches.remove(1);
+        swatches[1] = Swatch::new(Color::from_str("#0586C8").unwrap(), (82, 88), 1064, 0.0160);
+        let added = Swatch::new(Color::from_str("#FFD700").unwrap(), (10, 10), 500, 0.0075);
+        swatches.push(added);
+        let other = Palette::new(swatches);
+
+        // Act
+        let actual = palette.diff(&other);
+
+        // Assert
+        assert_eq!(actual.added(), &[added]);
+        assert_eq!(actual.removed(), &[removed]);
+        assert_eq!(actual.shifted().len(), 1);
+
+        let (before, after, delta) = actual.shifted()[0];
+        assert_eq!(before.color().to_hex_string(), "#0081C8");
+        assert_eq!(after.color().to_hex_string(), "#0586C8");
+        assert_eq!(delta, before.color().delta_e(after.color()));
+        assert!(delta > 0.0 && delta < 10.0);
+        assert!(!actual.is_empty());
+    }
+
+    #[test]
+    fn test_diff_same() {
+        // Arrange
+        let palette = Palette::new(sample_swatches::<f32>());
+
+        // Act
+        let actual = palette.diff(&palette.clone());
+
+        // Assert
+        assert!(actual.is_empty());
+    }
+
+    #[test]
+    #[cfg(feature = "image")]
+    fn test_without_extracted() {
+        // Arrange
+        let image_data = ImageData::load("../../gfx/olympic_logo.png").unwrap();
+        let palette: Palette<f32> = Palette::extract(&image_data).unwrap();
+        let white = Color::from_str("#FFFFFF").unwrap();
+
+        // Act
+        let actual = palette.without(&white, 5.0);
+
+        // Assert
+        assert_eq!(actual.len(), palette.len() - 1);
+        assert!(
+            actual
+                .swatches()
+                .iter()
+                .all(|swatch| swatch.color().delta_e(&white) > 5.0)
+        );
+    }
+
+    #[test]
+    fn test_cumulative_stops() {
+        // Arrange
+        let palette = Palette::new(sample_swatches::<f32>());
+
+        // Act
+        let actual = palette.cumulative_stops();
+
+        // Assert
+        assert_eq!(actual.len(), 6);
+        assert_eq!(actual[0].1.to_hex_string(), "#000000");
+        for window in actual.windows(2) {
+            assert!(window[0].0 < window[1].0);
+        }
+        let last = actual.last().unwrap().0;
+        assert!(last <= 1.0);
+        assert!((last - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_cumulative_stops_empty() {
+        // Arrange
+        let palette: Palette<f32> = Palette::new(empty_swatches());
+
+        // Act
+        let actual = palette.cumulative_stops();
+
+        // Assert
+        assert!(actual.is_empty());
+    }
+
+    #[test]
+    fn test_entropy_uniform() {
+        // Arrange
+        let swatches = vec![
+            Swatch::<f32>::new(Color::from_str("#FF0000").unwrap(), (5, 10), 50, 0.5),
+            Swatch::<f32>::new(Color::from_str("#0000FF").unwrap(), (15, 20), 50, 0.5),
+        ];
+        let palette = Palette::new(swatches);
+
+        // Act
+        let actual = palette.entropy();
+
+        // Assert
+        assert!((actual - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_entropy_dominant() {
+        // Arrange
+        let swatches = vec![
+            Swatch::<f32>::new(Color::from_str("#FF0000").unwrap(), (5, 10), 99, 0.99),
+            Swatch::<f32>::new(Color::from_str("#0000FF").unwrap(), (15, 20), 1, 0.01),
+        ];
+        let palette = Palette::new(swatches);
+
+        // Act
+        let actual = palette.entropy();
+
+        // Assert
+        assert!(actual < 0.1);
+    }
+
+    #[test]
+    fn test_entropy_empty() {
+        // Arrange
+        let palette: Palette<f32> = Palette::new(vec![]);
+
+        // Act
+        let actual = palette.entropy();
+
+        // Assert
+        assert_eq!(actual, 0.0);
+    }
+
+    #[rstest]
+    #[case::truecolor(true, "\x1b[48;2;255;0;0m  \x1b[0m")]
+    #[case::ansi256(false, "\x1b[48;5;196m  \x1b[0m")]
+    fn test_to_ansi_preview(#[case] truecolor: bool, #[case] expected_block: &str) {
+        // Arrange
+        let swatches = vec![
+            Swatch::<f32>::new(Color::from_str("#FF0000").unwrap(), (5, 10), 896, 0.875),
+            Swatch::<f32>::new(Color::from_str("#00FF00").unwrap(), (15, 20), 96, 0.094),
+            Swatch::<f32>::new(Color::from_str("#0000FF").unwrap(), (30, 30), 32, 0.031),
+        ];
+        let palette = Palette::new(swatches);
+
+        // Act
+        let actual = palette.to_ansi_preview(truecolor);
+
+        // Assert
+        assert_eq!(actual.matches("\x1b[48;").count(), 3);
+        assert_eq!(actual.matches("\x1b[0m").count(), 3);
+        assert!(actual.starts_with(expected_block));
+    }
+
+    #[test]
+    fn test_to_ansi_preview_empty() {
+        // Arrange
+        let palette: Palette<f32> = Palette::new(vec![]);
+
+        // Act
+        let actual = palette.to_ansi_preview(true);
+
+        // Assert
+        assert!(actual.is_empty());
+    }
+
+    #[test]
+    fn test_to_tailwind() {
+        // Arrange
+        let swatches = vec![
+            Swatch::<f32>::new(Color::from_str("#FF0000").unwrap(), (5, 10), 896, 0.875),
+            Swatch::<f32>::new(Color::from_str("#00FF00").unwrap(), (15, 20), 96, 0.094),
+            Swatch::<f32>::new(Color::from_str("#0000FF").unwrap(), (30, 30), 32, 0.031),
+        ];
+        let palette = Palette::new(swatches);
+
+        // Act
+        let actual = palette.to_tailwind("brand");
+
+        // Assert
+        assert_eq!(
+            actual,
+            r#"module.exports = {
+  theme: {
+    extend: {
+      colors: {
+        'brand-1': '#FF0000',
+        'brand-2': '#00FF00',
+        'brand-3': '#0000FF',
+      },
+    },
+  },
+};
+"#
+        );
+        assert_eq!(actual.matches('{').count(), actual.matches('}').count());
+    }
+
+    #[test]
+    fn test_to_tailwind_empty() {
+        // Arrange
+        let palette: Palette<f32> = Palette::new(vec![]);
+
+        // Act
+        let actual = palette.to_tailwind("brand");
+
+        // Assert
+        assert!(actual.contains("colors: {\n      },"));
+        assert_eq!(actual.matches('{').count(), actual.matches('}').count());
+    }
+
+    #[rstest]
+    #[case::quote("it's", r"'it\'s-1': '#FF0000',")]
+    #[case::backslash(r"a\b", r"'a\\b-1': '#FF0000',")]
+    #[case::escaped_quote(r"\'", r"'\\\'-1': '#FF0000',")]
+    fn test_to_tailwind_escaped_prefix(#[case] prefix: &str, #[case] expected: &str) {
+        // Arrange
+        let swatches = vec![Swatch::<f32>::new(
+            Color::from_str("#FF0000").unwrap(),
+            (5, 10),
+            896,
+            0.875,
+        )];
+        let palette = Palette::new(swatches);
+
+        // Act
+        let actual = palette.to_tailwind(prefix);
+
+        // Assert
+        assert!(actual.contains(expected), "{}", actual);
+    }
 
-    use rstest::rstest;
+    #[test]
+    fn test_to_css_variables() {
+        // Arrange
+        let swatches = vec![
+            Swatch::<f32>::new(Color::from_str("#00FF00").unwrap(), (15, 20), 96, 0.094),
+            Swatch::<f32>::new(Color::from_str("#FF0000").unwrap(), (5, 10), 896, 0.875),
+            Swatch::<f32>::new(Color::from_str("#0000FF").unwrap(), (30, 30), 32, 0.031),
+        ];
+        let palette = Palette::new(swatches);
 
-    use super::*;
+        // Act
+        let actual = palette.to_css_variables("brand");
 
-    #[must_use]
-    fn sample_swatches<T>() -> Vec<Swatch<T>>
-    where
-        T: FloatNumber,
-    {
-        vec![
-            Swatch::new(
-                Color::from_str("#FFFFFF").unwrap(),
-                (159, 106),
-                61228,
-                T::from_f64(0.9214),
-            ),
-            Swatch::new(
-                Color::from_str("#EE334E").unwrap(),
-                (238, 89),
-                1080,
-                T::from_f64(0.0163),
-            ),
-            Swatch::new(
-                Color::from_str("#0081C8").unwrap(),
-                (82, 88),
-                1064,
-                T::from_f64(0.0160),
-            ),
-            Swatch::new(
-                Color::from_str("#00A651").unwrap(),
-                (197, 123),
-                1037,
-                T::from_f64(0.0156),
-            ),
-            Swatch::new(
-                Color::from_str("#000000").unwrap(),
-                (157, 95),
-                1036,
-                T::from_f64(0.0156),
-            ),
-            Swatch::new(
-                Color::from_str("#FCB131").unwrap(),
-                (119, 123),
-                1005,
-                T::from_f64(0.0151),
-            ),
-        ]
+        // Assert
+        assert_eq!(
+            actual,
+            ":root {\n  --brand-1: #FF0000;\n  --brand-2: #00FF00;\n  --brand-3: #0000FF;\n}\n"
+        );
+        assert_eq!(actual.matches('{').count(), actual.matches('}').count());
+        let declarations: Vec<&str> = actual
+            .trim()
+            .strip_prefix(":root {")
+            .and_then(|body| body.strip_suffix('}'))
+            .unwrap()
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .collect();
+        assert_eq!(declarations.len(), 3);
+        assert!(declarations.iter().all(|declaration| {
+            let Some((name, value)) = declaration
+                .strip_suffix(';')
+                .and_then(|declaration| declaration.split_once(": "))
+            else {
+                return false;
+            };
+            name.starts_with("--") && Color::<f32>::from_str(value).is_ok()
+        }));
     }
 
-    #[must_use]
-    fn empty_swatches<T>() -> Vec<Swatch<T>>
-    where
-        T: FloatNumber,
-    {
-        vec![]
+    #[test]
+    fn test_to_css_variables_with() {
+        // Arrange
+        let palette = Palette::new(sample_swatches::<f32>());
+
+        // Act
+        let actual = palette.to_css_variables_with(|index, swatch| {
+            format!("color-{}-{}", index, swatch.position().0)
+        });
+
+        // Assert
+        assert!(actual.starts_with(":root {\n  --color-0-159: #FFFFFF;\n"));
+        assert!(actual.ends_with("  --color-5-119: #FCB131;\n}\n"));
     }
 
     #[test]
-    fn test_new() {
+    fn test_to_css_variables_empty() {
+        // Arrange
+        let palette: Palette<f32> = Palette::new(vec![]);
+
         // Act
+        let actual = palette.to_css_variables("brand");
+
+        // Assert
+        assert_eq!(actual, ":root {\n}\n");
+    }
+
+    #[test]
+    fn test_to_gpl() {
+        // Arrange
         let swatches = vec![
-            Swatch::<f32>::new(Color::from_str("#FFFFFF").unwrap(), (5, 10), 256, 0.5714),
-            Swatch::<f32>::new(Color::from_str("#C8102E").unwrap(), (15, 20), 128, 0.2857),
-            Swatch::<f32>::new(Color::from_str("#012169").unwrap(), (30, 30), 64, 0.1429),
+            Swatch::<f32>::new(Color::from_str("#FF0000").unwrap(), (5, 10), 896, 0.875),
+            Swatch::<f32>::new(Color::from_str("#00A651").unwrap(), (15, 20), 96, 0.094),
+            Swatch::<f32>::new(Color::from_str("#0000FF").unwrap(), (30, 30), 32, 0.031),
         ];
-        let actual = Palette::new(swatches.clone());
+        let palette = Palette::new(swatches);
+
+        // Act
+        let actual = palette.to_gpl("Sample");
 
         // Assert
-        assert!(!actual.is_empty());
-        assert_eq!(actual.len(), 3);
-        assert_eq!(actual.swatches, swatches);
+        assert_eq!(
+            actual,
+            "GIMP Palette\nName: Sample\n#\n255   0   0\t#FF0000\n  0 166  81\t#00A651\n  0   0 255\t#0000FF\n"
+        );
     }
 
     #[test]
-    fn test_new_empty() {
+    fn test_gpl_round_trip() {
+        // Arrange
+        let palette = Palette::new(sample_swatches::<f32>());
+
         // Act
-        let swatches = vec![];
-        let actual: Palette<f32> = Palette::new(swatches.clone());
+        let actual = Palette::<f32>::from_gpl(&palette.to_gpl("Olympic")).unwrap();
 
         // Assert
-        assert!(actual.is_empty());
-        assert_eq!(actual.len(), 0);
+        assert_eq!(actual.len(), palette.len());
+        for (actual, expected) in actual.swatches().iter().zip(palette.swatches()) {
+            assert_eq!(
+                actual.color().to_hex_string(),
+                expected.color().to_hex_string()
+            );
+            assert_eq!(actual.population(), 0);
+            assert_eq!(actual.ratio(), 0.0);
+        }
     }
 
     #[test]
-    #[cfg(feature = "image")]
-    fn test_extract() {
+    fn test_from_gpl_with_comments_and_missing_names() {
+        // Arrange
+        let text = "GIMP Palette\nName: Custom\nColumns: 4\n# A comment\n\n255 128   0\n 16  32  64\tNavy-ish\n";
+
         // Act
-        let image_data = ImageData::load("../../gfx/olympic_logo.png").unwrap();
-        let actual: Palette<f32> = Palette::extract(&image_data).unwrap();
+        let actual = Palette::<f32>::from_gpl(text).unwrap();
 
         // Assert
-        assert!(!actual.is_empty());
-        assert!(actual.len() >= 3);
+        let colors: Vec<String> = actual
+            .swatches()
+            .iter()
+            .map(|swatch| swatch.color().to_hex_string())
+            .collect();
+        assert_eq!(colors, vec!["#FF8000", "#102040"]);
     }
 
-    #[cfg(feature = "image")]
     #[rstest]
-    #[case::kmeans("kmeans")]
-    #[case::dbscan("dbscan")]
-    #[case::dbscanpp("dbscan++")]
-    fn test_extract_with_algorithm(#[case] name: &str) {
+    #[case::empty("")]
+    #[case::missing_header("255 0 0\tRed\n")]
+    #[case::missing_component("GIMP Palette\n255 0\n")]
+    #[case::out_of_range("GIMP Palette\n256 0 0\tRed\n")]
+    #[case::not_a_number("GIMP Palette\nred green blue\n")]
+    fn test_from_gpl_error(#[case] text: &str) {
         // Act
-        let image_data = ImageData::load("../../gfx/olympic_logo.png").unwrap();
-        let algorithm = Algorithm::from_str(name).unwrap();
-        let actual: Palette<f32> = Palette::extract_with_algorithm(&image_data, algorithm).unwrap();
+        let actual = Palette::<f32>::from_gpl(text);
 
         // Assert
-        assert!(!actual.is_empty());
-        assert!(actual.len() >= 5);
+        assert!(matches!(actual, Err(Error::InvalidPaletteFormat { .. })));
     }
 
     #[test]
-    fn test_extract_empty_image_data() {
+    fn test_to_ase_bytes() {
+        // Arrange
+        let swatches = vec![Swatch::<f32>::new(
+            Color::from_str("#FF8000").unwrap(),
+            (5, 10),
+            896,
+            0.875,
+        )];
+        let palette = Palette::new(swatches);
+
         // Act
-        let data = Vec::<u8>::new();
-        let image_data = ImageData::new(0, 0, &data).unwrap();
-        let result = Palette::<f32>::extract(&image_data);
+        let actual = palette.to_ase_bytes();
 
         // Assert
-        assert!(result.is_err());
+        let mut expected = vec![
+            b'A', b'S', b'E', b'F', // Signature
+            0x00, 0x01, 0x00, 0x00, // Version 1.0
+            0x00, 0x00, 0x00, 0x01, // Number of blocks
+            0x00, 0x01, // Color entry
+            0x00, 0x00, 0x00, 0x24, // Block length
+            0x00, 0x08, // Name length
+        ];
+        for unit in "#FF8000\0".encode_utf16() {
+            expected.extend_from_slice(&unit.to_be_bytes());
+        }
+        expected.extend_from_slice(b"RGB ");
+        expected.extend_from_slice(&1.0_f32.to_be_bytes());
+        expected.extend_from_slice(&(128.0_f32 / 255.0).to_be_bytes());
+        expected.extend_from_slice(&0.0_f32.to_be_bytes());
+        expected.extend_from_slice(&[0x00, 0x02]);
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_to_ase_bytes_block_count() {
+        // Arrange
+        let palette = Palette::new(sample_swatches::<f32>());
+
+        // Act
+        let actual = palette.to_ase_bytes();
+
+        // Assert
+        assert_eq!(&actual[8..12], &6_u32.to_be_bytes());
+        assert_eq!(actual.len(), 12 + 6 * (6 + 36));
+    }
+
+    #[test]
+    fn test_to_vega_scheme() {
+        // Arrange
+        let swatches = vec![
+            Swatch::<f32>::new(Color::from_str("#FF0000").unwrap(), (5, 10), 896, 0.875),
+            Swatch::<f32>::new(Color::from_str("#FF1010").unwrap(), (15, 20), 96, 0.094),
+            Swatch::<f32>::new(Color::from_str("#0000FF").unwrap(), (30, 30), 32, 0.031),
+        ];
+        let palette = Palette::new(swatches);
+
+        // Act
+        let actual = palette.to_vega_scheme();
+
+        // Assert
+        assert_eq!(actual, vec!["#FF0000", "#0000FF", "#FF1010"]);
+    }
+
+    #[test]
+    fn test_to_d3_scale() {
+        // Arrange
+        let swatches = sample_swatches::<f32>();
+        let palette = Palette::new(swatches.clone());
+
+        // Act
+        let actual = palette.to_d3_scale();
+
+        // Assert
+        assert_eq!(actual.len(), swatches.len());
+        for hex in &actual {
+            assert_eq!(hex.len(), 7);
+            assert!(hex.starts_with('#'));
+            assert!(
+                hex[1..]
+                    .chars()
+                    .all(|c| c.is_ascii_digit() || ('a'..='f').contains(&c))
+            );
+        }
         assert_eq!(
-            result.unwrap_err().to_string(),
-            "The image data is empty and cannot be processed."
+            actual[0],
+            swatches[0].color().to_hex_string().to_lowercase()
         );
     }
 
+    #[test]
+    fn test_to_vega_scheme_empty() {
+        // Arrange
+        let palette: Palette<f32> = Palette::new(vec![]);
+
+        // Act
+        let actual = palette.to_vega_scheme();
+
+        // Assert
+        assert!(actual.is_empty());
+    }
+
     #[test]
     fn test_find_swatches() {
         // Arrange
@@ -479,4 +2869,167 @@ mod tests {
         assert_eq!(actual[0].color().to_hex_string(), expected[0]);
         assert_eq!(actual[1].color().to_hex_string(), expected[1]);
     }
+
+    #[test]
+    fn test_find_swatches_by_prominence() {
+        // Arrange
+        let swatches = vec![
+            Swatch::<f32>::new(Color::from_str("#808080").unwrap(), (5, 10), 900, 0.9),
+            Swatch::<f32>::new(Color::from_str("#F0F0F0").unwrap(), (15, 20), 50, 0.05),
+            Swatch::<f32>::new(Color::from_str("#39FF14").unwrap(), (30, 30), 50, 0.05),
+        ];
+        let palette = Palette::new(swatches);
+
+        // Act
+        let actual = palette.find_swatches_by_prominence(2);
+
+        // Assert
+        assert_eq!(actual.len(), 2);
+        assert_eq!(actual[0].color().to_hex_string(), "#39FF14");
+        assert_eq!(actual[1].color().to_hex_string(), "#808080");
+    }
+
+    #[test]
+    fn test_find_swatches_by_prominence_empty() {
+        // Arrange
+        let palette: Palette<f32> = Palette::new(vec![]);
+
+        // Act
+        let actual = palette.find_swatches_by_prominence(3);
+
+        // Assert
+        assert!(actual.is_empty());
+    }
+
+    #[test]
+    fn test_named_swatches() {
+        // Arrange
+        let swatches = sample_swatches::<f32>();
+        let palette = Palette::new(swatches.clone());
+
+        // Act
+        let actual = palette.named_swatches();
+
+        // Assert
+        let names: Vec<&str> = actual.iter().map(|(name, _)| name.as_str()).collect();
+        assert_eq!(
+            names,
+            vec!["White", "Red", "Blue", "Green", "Black", "Light Yellow"]
+        );
+        let swatches_actual: Vec<Swatch<f32>> = actual.iter().map(|(_, swatch)| *swatch).collect();
+        assert_eq!(swatches_actual, swatches);
+    }
+
+    #[test]
+    fn test_named_swatches_duplicate() {
+        // Arrange
+        let swatches = vec![
+            Swatch::<f32>::new(Color::from_str("#1A237E").unwrap(), (5, 10), 384, 0.5),
+            Swatch::<f32>::new(Color::from_str("#FF0000").unwrap(), (15, 20), 256, 0.3),
+            Swatch::<f32>::new(Color::from_str("#E00000").unwrap(), (30, 30), 128, 0.2),
+            Swatch::<f32>::new(Color::from_str("#FFF0F0").unwrap(), (40, 40), 64, 0.1),
+        ];
+        let palette = Palette::new(swatches);
+
+        // Act
+        let actual = palette.named_swatches();
+
+        // Assert
+        let names: Vec<&str> = actual.iter().map(|(name, _)| name.as_str()).collect();
+        assert_eq!(
+            names,
+            vec!["Dark Blue", "Vivid Red", "Vivid Red 2", "White"]
+        );
+    }
+
+    #[rstest]
+    #[case::black("#000000", "Black")]
+    #[case::white("#FFFFFF", "White")]
+    #[case::gray("#808080", "Gray")]
+    #[case::dark_gray("#333333", "Dark Gray")]
+    #[case::light_gray("#C0C0C0", "Light Gray")]
+    #[case::dark_muted_blue("#1E3A6E", "Dark Muted Blue")]
+    #[case::light_vivid_green("#00FF00", "Light Vivid Green")]
+    #[case::pink("#FF0080", "Vivid Pink")]
+    #[case::purple("#800080", "Dark Purple")]
+    #[case::orange("#FF8000", "Vivid Orange")]
+    #[case::cyan("#00FFFF", "Light Cyan")]
+    fn test_describe_color(#[case] hex: &str, #[case] expected: &str) {
+        // Arrange
+        let color: Color<f32> = Color::from_str(hex).unwrap();
+
+        // Act
+        let actual = describe_color(&color);
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    #[cfg(feature = "image")]
+    fn test_to_image() {
+        // Arrange
+        let swatches = sample_swatches::<f32>();
+        let palette = Palette::new(swatches.clone());
+
+        // Act
+        let actual = palette.to_image(16, 8);
+
+        // Assert
+        assert_eq!(actual.dimensions(), (96, 8));
+        for (index, swatch) in swatches.iter().enumerate() {
+            let rgb = swatch.color().to_rgb();
+            let x = index as u32 * 16;
+            assert_eq!(actual.get_pixel(x, 0), &Rgb([rgb.r, rgb.g, rgb.b]));
+            assert_eq!(actual.get_pixel(x + 15, 7), &Rgb([rgb.r, rgb.g, rgb.b]));
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "image")]
+    fn test_to_image_empty() {
+        // Arrange
+        let palette: Palette<f32> = Palette::new(vec![]);
+
+        // Act
+        let actual = palette.to_image(16, 8);
+
+        // Assert
+        assert_eq!(actual.dimensions(), (0, 8));
+    }
+
+    #[rstest]
+    #[case::blue("#1050C0", 3, "#0081C8")]
+    #[case::orange("#FFA040", 3, "#FCB131")]
+    #[case::black("#202020", 1, "#000000")]
+    #[case::all("#00C060", 10, "#00A651")]
+    fn test_find_swatches_from(#[case] anchor: &str, #[case] n: usize, #[case] expected: &str) {
+        // Arrange
+        let swatches = sample_swatches::<f32>();
+        let palette = Palette::new(swatches.clone());
+        let anchor: Color<f32> = Color::from_str(anchor).unwrap();
+
+        // Act
+        let actual = palette.find_swatches_from(n, anchor);
+
+        // Assert
+        assert_eq!(actual.len(), n.min(swatches.len()));
+        assert_eq!(actual[0].color().to_hex_string(), expected);
+        for window in actual[1..].windows(2) {
+            assert!(window[0].population() >= window[1].population());
+        }
+    }
+
+    #[test]
+    fn test_find_swatches_from_empty() {
+        // Arrange
+        let palette: Palette<f32> = Palette::new(vec![]);
+        let anchor: Color<f32> = Color::from_str("#FF0000").unwrap();
+
+        // Act
+        let actual = palette.find_swatches_from(3, anchor);
+
+        // Assert
+        assert!(actual.is_empty());
+    }
 }
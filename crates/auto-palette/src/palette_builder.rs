@@ -0,0 +1,877 @@
+use std::{borrow::Cow, cmp::Reverse};
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+use crate::{
+    algorithm::Algorithm,
+    error::Error,
+    image::ImageData,
+    math::{clustering::Cluster, FloatNumber},
+    palette::{
+        cluster_foo,
+        cluster_foo_bar,
+        convert_to_swatches,
+        merge_color_clusters_weighted,
+        relabel_clusters,
+    },
+    Palette,
+};
+
+/// The default maximum CIE76 color difference for two pixel clusters to be merged into a swatch.
+pub(crate) const COLOR_MERGE_EPSILON: f32 = 2.5;
+
+/// The default minimum number of pixel clusters to form a merged swatch.
+pub(crate) const COLOR_MERGE_MIN_POINTS: usize = 1;
+
+/// The builder to extract a palette with custom options.
+///
+/// # Type Parameters
+/// * `T` - The floating point type.
+///
+/// # Examples
+/// ```
+/// use auto_palette::{Algorithm, ImageData, Palette, PaletteBuilder};
+///
+/// // A 64x64 red image
+/// let pixels = [255, 0, 0, 255].repeat(64 * 64);
+/// let image_data = ImageData::new(64, 64, &pixels).unwrap();
+///
+/// let palette: Palette<f32> = PaletteBuilder::new()
+///     .algorithm(Algorithm::KMeans)
+///     .merge_epsilon(5.0)
+///     .merge_min_points(1)
+///     .build(&image_data)
+///     .unwrap();
+/// assert_eq!(palette.len(), 1);
+/// assert_eq!(palette.swatches()[0].color().to_hex_string(), "#FF0000");
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct PaletteBuilder<T>
+where
+    T: FloatNumber,
+{
+    algorithm: Algorithm,
+    merge: bool,
+    merge_epsilon: T,
+    merge_min_points: usize,
+    merge_weighted: bool,
+    region: Option<(u32, u32, u32, u32)>,
+    seed: Option<u64>,
+}
+
+impl<T> PaletteBuilder<T>
+where
+    T: FloatNumber,
+{
+    /// Creates a new `PaletteBuilder` instance with the default options.
+    ///
+    /// # Returns
+    /// A new `PaletteBuilder` instance.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            algorithm: Algorithm::DBSCAN,
+            merge: true,
+            merge_epsilon: T::from_f32(COLOR_MERGE_EPSILON),
+            merge_min_points: COLOR_MERGE_MIN_POINTS,
+            merge_weighted: false,
+            region: None,
+            seed: None,
+        }
+    }
+
+    /// Sets the clustering algorithm used to segment the image.
+    ///
+    /// # Arguments
+    /// * `algorithm` - The clustering algorithm to use.
+    ///
+    /// # Returns
+    /// The builder with the algorithm set.
+    #[must_use]
+    pub fn algorithm(mut self, algorithm: Algorithm) -> Self {
+        self.algorithm = algorithm;
+        self
+    }
+
+    /// Sets whether to merge pixel clusters with similar colors into a swatch.
+    ///
+    /// When disabled, each non-empty pixel cluster found by the algorithm becomes a swatch.
+    ///
+    /// # Arguments
+    /// * `merge` - `true` to merge similar colors, otherwise `false`.
+    ///
+    /// # Returns
+    /// The builder with the merge option set.
+    #[must_use]
+    pub fn merge(mut self, merge: bool) -> Self {
+        self.merge = merge;
+        self
+    }
+
+    /// Sets the maximum color difference for pixel clusters to be merged into a swatch.
+    ///
+    /// A larger epsilon merges near-duplicate colors of gradients and photos, while a smaller one keeps the distinct colors of logos.
+    ///
+    /// # Arguments
+    /// * `epsilon` - The maximum CIE76 color difference. Must be greater than zero.
+    ///
+    /// # Returns
+    /// The builder with the merge epsilon set.
+    #[must_use]
+    pub fn merge_epsilon(mut self, epsilon: T) -> Self {
+        self.merge_epsilon = epsilon;
+        self
+    }
+
+    /// Sets the minimum number of pixel clusters to form a merged swatch.
+    ///
+    /// # Arguments
+    /// * `min_points` - The minimum number of pixel clusters. Must be greater than zero.
+    ///
+    /// # Returns
+    /// The builder with the merge minimum points set.
+    #[must_use]
+    pub fn merge_min_points(mut self, min_points: usize) -> Self {
+        self.merge_min_points = min_points;
+        self
+    }
+
+    /// Sets whether to weight the pixel clusters by their population when merging them.
+    ///
+    /// When enabled, each pixel cluster counts as its population relative to the mean population
+    /// towards the merge minimum points. Large pixel clusters then anchor the merged swatches,
+    /// and small noisy ones are merged into the nearest anchored swatch instead of forming their own.
+    /// If no pixel cluster has enough weighted neighbors to anchor a swatch, the palette is empty.
+    ///
+    /// # Arguments
+    /// * `weighted` - `true` to weight the pixel clusters by population, otherwise `false`.
+    ///
+    /// # Returns
+    /// The builder with the merge weighting set.
+    #[must_use]
+    pub fn merge_weighted_by_population(mut self, weighted: bool) -> Self {
+        self.merge_weighted = weighted;
+        self
+    }
+
+    /// Sets the region of the image to extract the palette from.
+    ///
+    /// Only the pixels inside the rectangle are considered, and the pixels outside it are ignored like transparent ones.
+    /// The rectangle is clamped to the image dimensions, and an empty region yields an empty palette.
+    /// The ratios of the swatches are relative to the area of the clamped region.
+    ///
+    /// # Arguments
+    /// * `x` - The x-coordinate of the top-left corner of the region.
+    /// * `y` - The y-coordinate of the top-left corner of the region.
+    /// * `width` - The width of the region.
+    /// * `height` - The height of the region.
+    ///
+    /// # Returns
+    /// The builder with the region set.
+    #[must_use]
+    pub fn region(mut self, x: u32, y: u32, width: u32, height: u32) -> Self {
+        self.region = Some((x, y, width, height));
+        self
+    }
+
+    /// Sets the seed of the random number generator used by the clustering algorithm.
+    ///
+    /// With a fixed seed, [`Algorithm::KMeans`] initializes its centroids in the same way on every run,
+    /// so the same image yields the same palette. Without a seed, the generator is seeded randomly.
+    /// The DBSCAN variants and the other algorithms are already deterministic and ignore the seed.
+    ///
+    /// # Arguments
+    /// * `seed` - The seed of the random number generator.
+    ///
+    /// # Returns
+    /// The builder with the seed set.
+    #[must_use]
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    /// Extracts the palette from the image data with the options of this builder.
+    ///
+    /// # Arguments
+    /// * `image_data` - The image data to extract the palette from.
+    ///
+    /// # Returns
+    /// The extracted palette.
+    ///
+    /// # Errors
+    /// Returns an error if the image data is empty, or if the merge epsilon or the merge minimum points is not greater than zero.
+    pub fn build(&self, image_data: &ImageData) -> Result<Palette<T>, Error> {
+        self.validate()?;
+
+        let pixels = image_data.data();
+        if pixels.is_empty() {
+            return Err(Error::EmptyImageData);
+        }
+
+        let width = image_data.width();
+        let height = image_data.height();
+        let Some((pixels, region_pixels)) = self.region_pixels(image_data) else {
+            return Ok(Palette::new(Vec::new()));
+        };
+        let mut pixel_clusters = cluster_foo(
+            width as usize,
+            height as usize,
+            &pixels,
+            self.algorithm.clone(),
+            self.seed,
+        )?;
+        relabel_clusters(&mut pixel_clusters);
+        let color_clusters = self.merge_clusters(&pixel_clusters);
+
+        let dimensions = vec![(T::from_u32(width), T::from_u32(height)); pixel_clusters.len()];
+        let mut swatches = convert_to_swatches(
+            &dimensions,
+            T::from_usize(region_pixels),
+            &color_clusters,
+            &pixel_clusters,
+        );
+        swatches.sort_by_key(|swatch| Reverse(swatch.population()));
+        Ok(Palette::new(swatches))
+    }
+
+    /// Extracts a single palette from all the given images with the options of this builder.
+    ///
+    /// Each image is segmented separately, and the colors of all segments are merged across the images,
+    /// so the populations and ratios of the swatches reflect the combined images.
+    /// The position of each swatch refers to the image containing its most populous segment.
+    /// Empty images are skipped, and an empty palette is returned if the region is empty in all the images.
+    ///
+    /// # Arguments
+    /// * `images` - The image data to extract the palette from.
+    ///
+    /// # Returns
+    /// The palette extracted from all the images.
+    ///
+    /// # Errors
+    /// Returns an error if all the images are empty, or if the merge epsilon or the merge minimum points is not greater than zero.
+    pub fn build_combined(&self, images: &[&ImageData]) -> Result<Palette<T>, Error> {
+        self.validate()?;
+
+        let mut pixel_clusters = Vec::new();
+        let mut dimensions = Vec::new();
+        let mut total_pixels = 0_usize;
+        let mut has_image_data = false;
+        for image_data in images
+            .iter()
+            .filter(|image_data| !image_data.data().is_empty())
+        {
+            has_image_data = true;
+            let width = image_data.width();
+            let height = image_data.height();
+            let Some((pixels, region_pixels)) = self.region_pixels(image_data) else {
+                continue;
+            };
+            let mut clusters = cluster_foo(
+                width as usize,
+                height as usize,
+                &pixels,
+                self.algorithm.clone(),
+                self.seed,
+            )?;
+            relabel_clusters(&mut clusters);
+            dimensions.extend(
+                std::iter::repeat((T::from_u32(width), T::from_u32(height))).take(clusters.len()),
+            );
+            pixel_clusters.extend(clusters);
+            total_pixels += region_pixels;
+        }
+        if !has_image_data {
+            return Err(Error::EmptyImageData);
+        }
+        if total_pixels == 0 {
+            return Ok(Palette::new(Vec::new()));
+        }
+
+        let color_clusters = self.merge_clusters(&pixel_clusters);
+        let mut swatches = convert_to_swatches(
+            &dimensions,
+            T::from_usize(total_pixels),
+            &color_clusters,
+            &pixel_clusters,
+        );
+        swatches.sort_by_key(|swatch| Reverse(swatch.population()));
+        Ok(Palette::new(swatches))
+    }
+
+    /// Returns the pixels of the image data with the pixels outside the region made transparent.
+    ///
+    /// # Arguments
+    /// * `image_data` - The image data to get the pixels from.
+    ///
+    /// # Returns
+    /// The pixels of the image data and the number of pixels in the region,
+    /// or `None` if the region clamped to the image dimensions is empty.
+    #[must_use]
+    fn region_pixels<'a>(&self, image_data: &'a ImageData) -> Option<(Cow<'a, [u8]>, usize)> {
+        let pixels = image_data.data();
+        let Some((x, y, width, height)) = self.region else {
+            return Some((Cow::Borrowed(pixels), pixels.len() / 4));
+        };
+
+        let image_width = image_data.width();
+        let image_height = image_data.height();
+        let x_range = x.min(image_width)..x.saturating_add(width).min(image_width);
+        let y_range = y.min(image_height)..y.saturating_add(height).min(image_height);
+        if x_range.is_empty() || y_range.is_empty() {
+            return None;
+        }
+
+        let mut masked = pixels.to_vec();
+        masked
+            .chunks_exact_mut(4)
+            .enumerate()
+            .filter(|(index, _)| {
+                let index = *index as u32;
+                !x_range.contains(&(index % image_width))
+                    || !y_range.contains(&(index / image_width))
+            })
+            .for_each(|(_, pixel)| pixel[3] = 0);
+        Some((Cow::Owned(masked), x_range.len() * y_range.len()))
+    }
+
+    /// Validates the merge options of this builder.
+    ///
+    /// # Returns
+    /// `Ok(())` if the options are valid.
+    ///
+    /// # Errors
+    /// Returns an error if the merge epsilon or the merge minimum points is not greater than zero.
+    fn validate(&self) -> Result<(), Error> {
+        if self.merge_epsilon.is_nan() || self.merge_epsilon <= T::zero() {
+            return Err(Error::ExtractionFailure {
+                details: format!(
+                    "The merge epsilon {} must be greater than zero.",
+                    self.merge_epsilon
+                ),
+            });
+        }
+        if self.merge_min_points == 0 {
+            return Err(Error::ExtractionFailure {
+                details: "The merge minimum points must be greater than zero.".to_string(),
+            });
+        }
+        Ok(())
+    }
+
+    /// Merges the pixel clusters into color clusters according to the merge options of this builder.
+    ///
+    /// # Arguments
+    /// * `pixel_clusters` - The pixel clusters to merge.
+    ///
+    /// # Returns
+    /// The color clusters whose members are the indices of the pixel clusters.
+    #[must_use]
+    fn merge_clusters(&self, pixel_clusters: &[Cluster<T, 5>]) -> Vec<Cluster<T, 3>> {
+        if !self.merge {
+            unmerged_clusters(pixel_clusters)
+        } else if self.merge_weighted {
+            merge_color_clusters_weighted(pixel_clusters, self.merge_epsilon, self.merge_min_points)
+        } else {
+            cluster_foo_bar(pixel_clusters, self.merge_epsilon, self.merge_min_points)
+        }
+    }
+
+    /// Extracts the palettes from the given images with the options of this builder.
+    ///
+    /// # Arguments
+    /// * `images` - The image data to extract the palettes from.
+    ///
+    /// # Returns
+    /// The results of the extraction in the same order as the given images.
+    #[cfg(not(feature = "parallel"))]
+    #[must_use]
+    pub fn build_many(&self, images: &[ImageData]) -> Vec<Result<Palette<T>, Error>> {
+        images
+            .iter()
+            .map(|image_data| self.build(image_data))
+            .collect()
+    }
+
+    /// Extracts the palettes from the given images in parallel with the options of this builder.
+    ///
+    /// # Arguments
+    /// * `images` - The image data to extract the palettes from.
+    ///
+    /// # Returns
+    /// The results of the extraction in the same order as the given images.
+    #[cfg(feature = "parallel")]
+    #[must_use]
+    pub fn build_many(&self, images: &[ImageData]) -> Vec<Result<Palette<T>, Error>>
+    where
+        T: Send + Sync,
+    {
+        images
+            .par_iter()
+            .map(|image_data| self.build(image_data))
+            .collect()
+    }
+}
+
+impl<T> Default for PaletteBuilder<T>
+where
+    T: FloatNumber,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Creates a color cluster for each non-empty pixel cluster without merging.
+///
+/// # Arguments
+/// * `pixel_clusters` - The pixel clusters.
+///
+/// # Returns
+/// The color clusters, each of which has the index of a single pixel cluster.
+#[must_use]
+fn unmerged_clusters<T>(pixel_clusters: &[Cluster<T, 5>]) -> Vec<Cluster<T, 3>>
+where
+    T: FloatNumber,
+{
+    pixel_clusters
+        .iter()
+        .enumerate()
+        .filter(|(_, pixel_cluster)| !pixel_cluster.is_empty())
+        .map(|(index, pixel_cluster)| {
+            let centroid = pixel_cluster.centroid();
+            let mut cluster = Cluster::new();
+            cluster.add_member(index, &[centroid[0], centroid[1], centroid[2]]);
+            cluster
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    /// Creates a 64x64 horizontal gradient from black to red.
+    #[must_use]
+    fn gradient_pixels() -> Vec<u8> {
+        (0..64u32)
+            .flat_map(|_| (0..64u32).flat_map(|x| [(x * 4) as u8, 0, 0, 255]))
+            .collect()
+    }
+
+    #[test]
+    fn test_new() {
+        // Act
+        let actual: PaletteBuilder<f32> = PaletteBuilder::new();
+
+        // Assert
+        assert_eq!(
+            actual,
+            PaletteBuilder {
+                algorithm: Algorithm::DBSCAN,
+                merge: true,
+                merge_epsilon: 2.5,
+                merge_min_points: 1,
+                merge_weighted: false,
+                region: None,
+                seed: None,
+            }
+        );
+        assert_eq!(actual, PaletteBuilder::default());
+    }
+
+    #[test]
+    fn test_build_default_equals_extract() {
+        // Arrange
+        let pixels = gradient_pixels();
+        let image_data = ImageData::new(64, 64, &pixels).unwrap();
+
+        // Act
+        let actual: Palette<f32> = PaletteBuilder::new().build(&image_data).unwrap();
+
+        // Assert
+        assert_eq!(actual, Palette::extract(&image_data).unwrap());
+    }
+
+    #[test]
+    fn test_build_with_larger_merge_epsilon() {
+        // Arrange
+        let pixels = gradient_pixels();
+        let image_data = ImageData::new(64, 64, &pixels).unwrap();
+
+        // Act
+        let tight: Palette<f32> = PaletteBuilder::new()
+            .algorithm(Algorithm::UniformGrid {
+                bits_per_channel: 4,
+            })
+            .merge_epsilon(0.5)
+            .build(&image_data)
+            .unwrap();
+        let loose: Palette<f32> = PaletteBuilder::new()
+            .algorithm(Algorithm::UniformGrid {
+                bits_per_channel: 4,
+            })
+            .merge_epsilon(50.0)
+            .build(&image_data)
+            .unwrap();
+
+        // Assert
+        assert!(loose.len() < tight.len());
+    }
+
+    #[test]
+    fn test_build_with_population_weighted_merge() {
+        // Arrange
+        // Red and blue halves sprinkled with single noise pixels of distinct colors.
+        let noise = [
+            [0, 255, 0],
+            [255, 255, 0],
+            [0, 255, 255],
+            [255, 0, 255],
+            [255, 255, 255],
+            [0, 0, 0],
+            [128, 64, 0],
+            [255, 128, 192],
+        ];
+        let pixels: Vec<u8> = (0..64 * 64)
+            .flat_map(|index| {
+                let [r, g, b] = if index % 97 == 0 {
+                    noise[index / 97 % noise.len()]
+                } else if index % 64 < 32 {
+                    [255, 0, 0]
+                } else {
+                    [0, 0, 255]
+                };
+                [r, g, b, 255]
+            })
+            .collect();
+        let image_data = ImageData::new(64, 64, &pixels).unwrap();
+        let builder = PaletteBuilder::new().algorithm(Algorithm::UniformGrid {
+            bits_per_channel: 4,
+        });
+
+        // Act
+        let unweighted: Palette<f32> = builder.build(&image_data).unwrap();
+        let weighted: Palette<f32> = builder
+            .merge_weighted_by_population(true)
+            .build(&image_data)
+            .unwrap();
+
+        // Assert
+        assert_eq!(unweighted.len(), 2 + noise.len());
+        assert_eq!(weighted.len(), 2);
+        let population = |palette: &Palette<f32>| {
+            palette
+                .swatches()
+                .iter()
+                .map(|swatch| swatch.population())
+                .sum::<usize>()
+        };
+        assert_eq!(population(&weighted), 64 * 64);
+        assert_eq!(population(&weighted), population(&unweighted));
+    }
+
+    #[test]
+    fn test_build_with_population_weighted_merge_without_anchor() {
+        // Arrange
+        let pixels = gradient_pixels();
+        let image_data = ImageData::new(64, 64, &pixels).unwrap();
+        let builder = PaletteBuilder::new()
+            .algorithm(Algorithm::UniformGrid {
+                bits_per_channel: 4,
+            })
+            .merge_min_points(1_000_000)
+            .merge_weighted_by_population(true);
+
+        // Act
+        let actual: Palette<f32> = builder.build(&image_data).unwrap();
+
+        // Assert
+        assert!(actual.is_empty());
+    }
+
+    #[test]
+    fn test_build_without_merge() {
+        // Arrange
+        let pixels = gradient_pixels();
+        let image_data = ImageData::new(64, 64, &pixels).unwrap();
+        let algorithm = Algorithm::UniformGrid {
+            bits_per_channel: 4,
+        };
+        let segments = cluster_foo::<f32>(64, 64, &pixels, algorithm.clone(), None)
+            .unwrap()
+            .iter()
+            .filter(|cluster| !cluster.is_empty())
+            .count();
+
+        // Act
+        let actual: Palette<f32> = PaletteBuilder::new()
+            .algorithm(algorithm)
+            .merge(false)
+            .build(&image_data)
+            .unwrap();
+
+        // Assert
+        assert_eq!(actual.len(), segments);
+        assert!(
+            actual
+                .swatches()
+                .windows(2)
+                .all(|pair| pair[0].population() >= pair[1].population())
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "image")]
+    fn test_build_many() {
+        // Arrange
+        let images = [
+            ImageData::load("../../gfx/olympic_logo.png").unwrap(),
+            ImageData::load("../../gfx/flags/za.png").unwrap(),
+        ];
+        let builder = PaletteBuilder::new().algorithm(Algorithm::UniformGrid {
+            bits_per_channel: 4,
+        });
+
+        // Act
+        let actual: Vec<Result<Palette<f32>, Error>> = builder.build_many(&images);
+
+        // Assert
+        assert_eq!(actual.len(), 2);
+        for (result, image_data) in actual.into_iter().zip(&images) {
+            assert_eq!(result.unwrap(), builder.build(image_data).unwrap());
+        }
+    }
+
+    #[rstest]
+    #[case::left_half((0, 0, 16, 32), "#FF0000", 16 * 32)]
+    #[case::right_half((16, 0, 16, 32), "#0000FF", 16 * 32)]
+    #[case::clamped((24, 8, 100, 100), "#0000FF", 8 * 24)]
+    fn test_build_with_region(
+        #[case] region: (u32, u32, u32, u32),
+        #[case] expected_color: &str,
+        #[case] expected_population: usize,
+    ) {
+        // Arrange
+        let pixels: Vec<u8> = (0..32 * 32)
+            .flat_map(|index| {
+                if index % 32 < 16 {
+                    [255, 0, 0, 255]
+                } else {
+                    [0, 0, 255, 255]
+                }
+            })
+            .collect();
+        let image_data = ImageData::new(32, 32, &pixels).unwrap();
+        let (x, y, width, height) = region;
+
+        // Act
+        let actual: Palette<f32> = PaletteBuilder::new()
+            .algorithm(Algorithm::UniformGrid {
+                bits_per_channel: 4,
+            })
+            .region(x, y, width, height)
+            .build(&image_data)
+            .unwrap();
+
+        // Assert
+        assert_eq!(actual.len(), 1);
+        assert_eq!(actual[0].color().to_hex_string(), expected_color);
+        assert_eq!(actual[0].population(), expected_population);
+    }
+
+    #[test]
+    fn test_build_with_region_ratio() {
+        // Arrange
+        let pixels = gradient_pixels();
+        let image_data = ImageData::new(64, 64, &pixels).unwrap();
+
+        // Act
+        let actual: Palette<f32> = PaletteBuilder::new()
+            .algorithm(Algorithm::UniformGrid {
+                bits_per_channel: 4,
+            })
+            .region(16, 16, 32, 100)
+            .build(&image_data)
+            .unwrap();
+
+        // Assert
+        assert!(!actual.is_empty());
+        let population: usize = actual
+            .swatches()
+            .iter()
+            .map(|swatch| swatch.population())
+            .sum();
+        assert_eq!(population, 32 * 48);
+        let ratio: f32 = actual.swatches().iter().map(|swatch| swatch.ratio()).sum();
+        assert!((ratio - 1.0).abs() < 1e-3, "ratio = {}", ratio);
+    }
+
+    #[rstest]
+    #[case::zero_width((8, 8, 0, 8))]
+    #[case::zero_height((8, 8, 8, 0))]
+    #[case::out_of_bounds((64, 64, 8, 8))]
+    fn test_build_with_empty_region(#[case] region: (u32, u32, u32, u32)) {
+        // Arrange
+        let pixels = gradient_pixels();
+        let image_data = ImageData::new(64, 64, &pixels).unwrap();
+        let (x, y, width, height) = region;
+
+        // Act
+        let actual: Palette<f32> = PaletteBuilder::new()
+            .region(x, y, width, height)
+            .build(&image_data)
+            .unwrap();
+
+        // Assert
+        assert!(actual.is_empty());
+    }
+
+    #[test]
+    fn test_build_with_seed() {
+        // Arrange
+        let pixels = gradient_pixels();
+        let image_data = ImageData::new(64, 64, &pixels).unwrap();
+        let builder = PaletteBuilder::new().algorithm(Algorithm::KMeans).seed(42);
+
+        // Act
+        let actual: Vec<Palette<f32>> = (0..3)
+            .map(|_| builder.build(&image_data).unwrap())
+            .collect();
+
+        // Assert
+        assert!(!actual[0].is_empty());
+        assert_eq!(actual[0], actual[1]);
+        assert_eq!(actual[0], actual[2]);
+    }
+
+    #[test]
+    fn test_build_combined() {
+        // Arrange
+        let red = [255, 0, 0, 255].repeat(64 * 64);
+        let half: Vec<u8> = (0..32 * 32)
+            .flat_map(|index| {
+                if index % 32 < 16 {
+                    [255, 0, 0, 255]
+                } else {
+                    [0, 0, 255, 255]
+                }
+            })
+            .collect();
+        let image1 = ImageData::new(64, 64, &red).unwrap();
+        let image2 = ImageData::new(32, 32, &half).unwrap();
+        let empty = ImageData::new(0, 0, &[]).unwrap();
+        let builder = PaletteBuilder::new().algorithm(Algorithm::UniformGrid {
+            bits_per_channel: 4,
+        });
+
+        // Act
+        let actual: Palette<f32> = builder.build_combined(&[&image1, &empty, &image2]).unwrap();
+
+        // Assert
+        assert_eq!(actual.len(), 2);
+        assert_eq!(actual[0].color().to_hex_string(), "#FF0000");
+        assert_eq!(actual[0].population(), 64 * 64 + 32 * 16);
+        assert!((actual[0].ratio() - 4608.0 / 5120.0).abs() < 1e-6);
+        assert_eq!(actual[1].color().to_hex_string(), "#0000FF");
+        assert_eq!(actual[1].population(), 32 * 16);
+        assert!(actual[1].position().0 >= 16 && actual[1].position().0 < 32);
+        assert!(actual[1].position().1 < 32);
+    }
+
+    #[test]
+    fn test_build_combined_single_image_equals_build() {
+        // Arrange
+        let pixels = gradient_pixels();
+        let image_data = ImageData::new(64, 64, &pixels).unwrap();
+        let builder = PaletteBuilder::new();
+
+        // Act
+        let actual: Palette<f32> = builder.build_combined(&[&image_data]).unwrap();
+
+        // Assert
+        assert_eq!(actual, builder.build(&image_data).unwrap());
+    }
+
+    #[rstest]
+    #[case::no_images(vec![])]
+    #[case::empty_images(vec![ImageData::new(0, 0, &[]).unwrap()])]
+    fn test_build_combined_empty(#[case] images: Vec<ImageData<'static>>) {
+        // Arrange
+        let images: Vec<&ImageData> = images.iter().collect();
+
+        // Act
+        let actual = PaletteBuilder::<f32>::new().build_combined(&images);
+
+        // Assert
+        assert!(matches!(actual, Err(Error::EmptyImageData)));
+    }
+
+    #[test]
+    fn test_build_combined_with_empty_region() {
+        // Arrange
+        let pixels = gradient_pixels();
+        let image_data = ImageData::new(64, 64, &pixels).unwrap();
+        let builder = PaletteBuilder::<f32>::new().region(64, 64, 8, 8);
+
+        // Act
+        let actual = builder.build_combined(&[&image_data, &image_data]).unwrap();
+
+        // Assert
+        assert!(actual.is_empty());
+        assert_eq!(actual, builder.build(&image_data).unwrap());
+    }
+
+    #[test]
+    fn test_build_many_with_empty_image() {
+        // Arrange
+        let pixels = gradient_pixels();
+        let images = [
+            ImageData::new(64, 64, &pixels).unwrap(),
+            ImageData::new(0, 0, &[]).unwrap(),
+        ];
+
+        // Act
+        let actual: Vec<Result<Palette<f32>, Error>> = PaletteBuilder::new().build_many(&images);
+
+        // Assert
+        assert_eq!(actual.len(), 2);
+        assert!(actual[0].is_ok());
+        assert!(matches!(actual[1], Err(Error::EmptyImageData)));
+    }
+
+    #[rstest]
+    #[case::zero_epsilon(0.0, 1)]
+    #[case::negative_epsilon(-1.0, 1)]
+    #[case::nan_epsilon(f32::NAN, 1)]
+    #[case::zero_min_points(2.5, 0)]
+    fn test_build_invalid_options(#[case] epsilon: f32, #[case] min_points: usize) {
+        // Arrange
+        let pixels = gradient_pixels();
+        let image_data = ImageData::new(64, 64, &pixels).unwrap();
+
+        // Act
+        let actual = PaletteBuilder::new()
+            .merge_epsilon(epsilon)
+            .merge_min_points(min_points)
+            .build(&image_data);
+
+        // Assert
+        assert!(matches!(actual, Err(Error::ExtractionFailure { .. })));
+    }
+
+    #[test]
+    fn test_build_empty_image_data() {
+        // Arrange
+        let image_data = ImageData::new(0, 0, &[]).unwrap();
+
+        // Act
+        let actual = PaletteBuilder::<f32>::new().build(&image_data);
+
+        // Assert
+        assert!(matches!(actual, Err(Error::EmptyImageData)));
+    }
+}
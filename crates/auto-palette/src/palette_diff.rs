@@ -0,0 +1,98 @@
+use crate::{math::FloatNumber, Swatch};
+
+/// The difference report between two palettes.
+///
+/// # Type Parameters
+/// * `T` - The floating point type.
+///
+/// # Examples
+/// ```
+/// use std::str::FromStr;
+///
+/// use auto_palette::{color::Color, Palette, Swatch};
+///
+/// let red: Color<f32> = Color::from_str("#FF0000").unwrap();
+/// let blue: Color<f32> = Color::from_str("#0000FF").unwrap();
+/// let before = Palette::new(vec![Swatch::new(red, (0, 0), 100, 0.5)]);
+/// let after = Palette::new(vec![
+///     Swatch::new(red, (0, 0), 100, 0.5),
+///     Swatch::new(blue, (1, 1), 100, 0.5),
+/// ]);
+///
+/// let diff = before.diff(&after);
+/// assert_eq!(diff.added().len(), 1);
+/// assert!(diff.removed().is_empty());
+/// assert!(diff.shifted().is_empty());
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct PaletteDiff<T>
+where
+    T: FloatNumber,
+{
+    added: Vec<Swatch<T>>,
+    removed: Vec<Swatch<T>>,
+    shifted: Vec<(Swatch<T>, Swatch<T>, T)>,
+}
+
+impl<T> PaletteDiff<T>
+where
+    T: FloatNumber,
+{
+    /// Creates a new `PaletteDiff` instance.
+    ///
+    /// # Arguments
+    /// * `added` - The swatches only in the other palette.
+    /// * `removed` - The swatches only in the original palette.
+    /// * `shifted` - The matched swatches whose colors differ, with their color difference.
+    ///
+    /// # Returns
+    /// A new `PaletteDiff` instance.
+    #[must_use]
+    pub(crate) fn new(
+        added: Vec<Swatch<T>>,
+        removed: Vec<Swatch<T>>,
+        shifted: Vec<(Swatch<T>, Swatch<T>, T)>,
+    ) -> Self {
+        Self {
+            added,
+            removed,
+            shifted,
+        }
+    }
+
+    /// Returns the swatches only in the other palette.
+    ///
+    /// # Returns
+    /// The added swatches.
+    #[must_use]
+    pub fn added(&self) -> &[Swatch<T>] {
+        &self.added
+    }
+
+    /// Returns the swatches only in the original palette.
+    ///
+    /// # Returns
+    /// The removed swatches.
+    #[must_use]
+    pub fn removed(&self) -> &[Swatch<T>] {
+        &self.removed
+    }
+
+    /// Returns the matched swatches whose colors differ.
+    ///
+    /// # Returns
+    /// The tuples of the original swatch, the matched swatch, and their CIE76 color difference.
+    #[must_use]
+    pub fn shifted(&self) -> &[(Swatch<T>, Swatch<T>, T)] {
+        &self.shifted
+    }
+
+    /// Returns whether the two palettes have the same colors.
+    ///
+    /// # Returns
+    /// `true` if no swatch is added, removed, or shifted, otherwise `false`.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.shifted.is_empty()
+    }
+}
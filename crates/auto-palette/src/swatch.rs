@@ -1,4 +1,7 @@
-use crate::{color::Color, math::FloatNumber};
+use crate::{
+    color::Color,
+    math::{normalize, FloatNumber},
+};
 
 /// The swatch representation containing the color, position, and population.
 ///
@@ -95,21 +98,58 @@ where
     pub fn ratio(&self) -> T {
         self.ratio
     }
+
+    /// Returns the prominence score of this swatch.
+    ///
+    /// The score is the square root of the ratio multiplied by the normalized chroma with a small offset,
+    /// so a small but vivid swatch can outrank a large but dull one.
+    ///
+    /// # Returns
+    /// The prominence score of this swatch.
+    #[must_use]
+    pub fn prominence(&self) -> T {
+        let chroma = normalize(
+            self.color.chroma(),
+            Color::<T>::min_chroma(),
+            Color::<T>::max_chroma(),
+        );
+        self.ratio.sqrt() * (T::from_f32(0.1) + chroma)
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use std::str::FromStr;
+
+    use rstest::rstest;
+
     use super::*;
 
     #[test]
     fn test_new_swatch() {
         // Act
         let color = Color::new(80.0, 0.0, 0.0);
-        let swatch = Swatch::new(color.clone(), (5, 10), 384, 0.25);
+        let swatch = Swatch::new(color, (5, 10), 384, 0.25);
 
         // Assert
         assert_eq!(swatch.color(), &color);
         assert_eq!(swatch.position(), (5, 10));
         assert_eq!(swatch.population(), 384);
     }
+
+    #[rstest]
+    #[case::gray("#808080", 0.9, 0.095)]
+    #[case::neon("#39FF14", 0.05, 0.166)]
+    #[case::empty("#39FF14", 0.0, 0.0)]
+    fn test_prominence(#[case] hex: &str, #[case] ratio: f32, #[case] expected: f32) {
+        // Arrange
+        let color: Color<f32> = Color::from_str(hex).unwrap();
+        let swatch = Swatch::new(color, (5, 10), 384, ratio);
+
+        // Act
+        let actual = swatch.prominence();
+
+        // Assert
+        assert!((actual - expected).abs() < 1e-3, "{}", actual);
+    }
 }
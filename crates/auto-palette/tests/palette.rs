@@ -45,10 +45,93 @@ fn test_extract_multiple_colors() {
     assert_eq!(palette.len(), 6);
 }
 
+#[test]
+fn test_extract_with_octree() {
+    // Arrange
+    let image_data = ImageData::load("../../gfx/olympic_logo.png").unwrap();
+
+    // Act
+    let palette: Palette<f32> =
+        Palette::extract_with_algorithm(&image_data, Algorithm::Octree { max_colors: 256 })
+            .unwrap();
+
+    // Assert
+    let expected: Palette<f32> =
+        Palette::extract_with_algorithm(&image_data, Algorithm::DBSCAN).unwrap();
+    assert!(palette.len() >= expected.len());
+    assert_eq!(
+        palette.swatches()[0].color().to_hex_string(),
+        expected.swatches()[0].color().to_hex_string()
+    );
+
+    let swatches = palette.find_swatches(6);
+    let colors: Vec<String> = swatches
+        .iter()
+        .map(|swatch| swatch.color().to_hex_string())
+        .collect();
+    assert_eq!(colors.len(), 6);
+    assert!(colors.contains(&"#EE334E".to_string()));
+}
+
+#[test]
+fn test_extract_with_median_cut() {
+    // Act
+    let image_data = ImageData::load("../../gfx/olympic_logo.png").unwrap();
+    let palette: Palette<f32> =
+        Palette::extract_with_algorithm(&image_data, Algorithm::MedianCut { max_colors: 32 })
+            .unwrap();
+
+    // Assert
+    let swatches = palette.find_swatches(6);
+    let colors: Vec<String> = swatches
+        .iter()
+        .map(|swatch| swatch.color().to_hex_string())
+        .collect();
+    assert_eq!(palette.len(), 14);
+    assert!(colors.contains(&"#FFFFFF".to_string()));
+    assert!(colors.contains(&"#EE334E".to_string()));
+    assert!(colors.contains(&"#FCB131".to_string()));
+    assert!(colors.contains(&"#0081C8".to_string()));
+}
+
+#[rstest]
+#[case::one(1)]
+#[case::six(6)]
+#[case::sixteen(16)]
+fn test_extract_with_median_cut_max_colors(#[case] max_colors: usize) {
+    // Arrange
+    let image_data = ImageData::load("../../gfx/olympic_logo.png").unwrap();
+
+    // Act
+    let palette: Palette<f32> = Palette::builder()
+        .algorithm(Algorithm::MedianCut { max_colors })
+        .merge(false)
+        .build(&image_data)
+        .unwrap();
+
+    // Assert
+    assert_eq!(palette.len(), max_colors);
+}
+
+#[test]
+fn test_extract_with_median_cut_zero_colors() {
+    // Arrange
+    let image_data = ImageData::load("../../gfx/olympic_logo.png").unwrap();
+
+    // Act
+    let actual: Result<Palette<f32>, _> =
+        Palette::extract_with_algorithm(&image_data, Algorithm::MedianCut { max_colors: 0 });
+
+    // Assert
+    assert!(actual.is_err());
+}
+
 #[rstest]
 #[case::kmeans("kmeans")]
 #[case::dbscan("dbscan")]
 #[case::dbscanpp("dbscan++")]
+#[case::median_cut("mediancut")]
+#[case::octree("octree")]
 fn test_extract_with_algorithm(#[case] name: &str) {
     // Arrange
     let image_data = ImageData::load("../../gfx/holly-booth-hLZWGXy5akM-unsplash.jpg").unwrap();